@@ -0,0 +1,266 @@
+// Copyright (C) Parity Technologies and the various Polkadot contributors, see Contributions.md
+// for a list of specific contributors.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Replacing the authorities of a chain spec by an arbitrary number of deterministic ones.
+
+use crate::{patch, system_parachains_specs::Extensions, ChainSpec};
+use serde_json::{json, Value};
+use sp_core::{ecdsa, ed25519, sr25519, ByteArray, Pair};
+use std::path::{Path, PathBuf};
+
+/// The number of validators assigned to a core, used to size the host configuration.
+const VALIDATORS_PER_CORE: u32 = 5;
+
+#[derive(clap::Args)]
+pub struct AuthoritiesArgs {
+	/// Replace the validators of a relay chain spec by this many `//Validator{i}` validators.
+	#[arg(long)]
+	validators: Option<u32>,
+
+	/// Replace the collators of a parachain chain spec by this many `//Collator{i}` collators.
+	#[arg(long)]
+	collators: Option<u32>,
+
+	/// Write a keystore with the session keys of every validator or collator into a sub
+	/// directory of this directory.
+	#[arg(long)]
+	keystore_path: Option<PathBuf>,
+}
+
+/// A session key of a validator or collator.
+struct SessionKey {
+	/// The name of the key in the runtime's `SessionKeys`.
+	name: &'static str,
+	key_type: [u8; 4],
+	public: Vec<u8>,
+	json: Value,
+}
+
+impl SessionKey {
+	fn sr25519(name: &'static str, key_type: &[u8; 4], seed: &str) -> Self {
+		let public = sr25519::Pair::from_string(seed, None).expect("valid seed; qed").public();
+		SessionKey { name, key_type: *key_type, public: public.to_raw_vec(), json: json!(public) }
+	}
+
+	fn ed25519(name: &'static str, key_type: &[u8; 4], seed: &str) -> Self {
+		let public = ed25519::Pair::from_string(seed, None).expect("valid seed; qed").public();
+		SessionKey { name, key_type: *key_type, public: public.to_raw_vec(), json: json!(public) }
+	}
+
+	fn ecdsa(name: &'static str, key_type: &[u8; 4], seed: &str) -> Self {
+		let public = ecdsa::Pair::from_string(seed, None).expect("valid seed; qed").public();
+		SessionKey { name, key_type: *key_type, public: public.to_raw_vec(), json: json!(public) }
+	}
+}
+
+/// The keys of one validator or collator.
+struct Authority {
+	seed: String,
+	account: Value,
+	keys: Vec<SessionKey>,
+}
+
+impl Authority {
+	fn validator(index: u32) -> Self {
+		let seed = format!("//Validator{index}");
+		let stash = sr25519::Pair::from_string(&format!("{seed}//stash"), None)
+			.expect("valid seed; qed")
+			.public();
+		let keys = Vec::from([
+			SessionKey::sr25519("babe", b"babe", &seed),
+			SessionKey::ed25519("grandpa", b"gran", &seed),
+			SessionKey::sr25519("para_validator", b"para", &seed),
+			SessionKey::sr25519("para_assignment", b"asgn", &seed),
+			SessionKey::sr25519("authority_discovery", b"audi", &seed),
+			SessionKey::ecdsa("beefy", b"beef", &seed),
+		]);
+		Authority { account: json!(stash), keys, seed }
+	}
+
+	fn collator(index: u32, ed25519_aura: bool) -> Self {
+		let seed = format!("//Collator{index}");
+		let account = sr25519::Pair::from_string(&seed, None).expect("valid seed; qed").public();
+		let aura = if ed25519_aura {
+			SessionKey::ed25519("aura", b"aura", &seed)
+		} else {
+			SessionKey::sr25519("aura", b"aura", &seed)
+		};
+		Authority { account: json!(account), keys: Vec::from([aura]), seed }
+	}
+
+	/// The `(account, validator id, keys)` entry of the session genesis config.
+	fn session_keys(&self) -> Value {
+		let keys = self
+			.keys
+			.iter()
+			.map(|key| (key.name.to_string(), key.json.clone()))
+			.collect::<serde_json::Map<_, _>>();
+		json!([self.account, self.account, keys])
+	}
+
+	/// Writes the session keys into a keystore in the format of `sc-keystore`.
+	fn write_keystore(&self, path: &Path) -> Result<(), String> {
+		std::fs::create_dir_all(path)
+			.map_err(|e| format!("Failed to create {}: {e}", path.display()))?;
+		let hex = |bytes: &[u8]| bytes.iter().map(|b| format!("{b:02x}")).collect::<String>();
+		for key in &self.keys {
+			let file = path.join(hex(&key.key_type) + &hex(&key.public));
+			std::fs::write(&file, json!(self.seed).to_string())
+				.map_err(|e| format!("Failed to write {}: {e}", file.display()))?;
+		}
+		Ok(())
+	}
+}
+
+/// Replaces the validators or collators of `chain_spec` as requested by `args`.
+pub fn apply(
+	chain_spec: Box<dyn ChainSpec>,
+	args: &AuthoritiesArgs,
+) -> Result<Box<dyn ChainSpec>, String> {
+	let is_parachain = Extensions::try_get(&*chain_spec).is_some();
+	let (authorities, patch) = match (args.validators, args.collators) {
+		(None, None) => return Ok(chain_spec),
+		(Some(_), Some(_)) =>
+			return Err("`--validators` and `--collators` can't be used together".into()),
+		(Some(_), None) if is_parachain =>
+			return Err("`--validators` can only be used with relay chain specs".into()),
+		(None, Some(_)) if !is_parachain =>
+			return Err("`--collators` can only be used with parachain chain specs".into()),
+		(Some(count), None) => {
+			let validators = (0..count).map(Authority::validator).collect::<Vec<_>>();
+			let patch = validators_patch(&patch::genesis(&*chain_spec)?, &validators)?;
+			(validators, patch)
+		},
+		(None, Some(count)) => {
+			// Asset Hub Polkadot is the only chain using ed25519 Aura keys.
+			let ed25519_aura = chain_spec.id().starts_with("asset-hub-polkadot");
+			let collators =
+				(0..count).map(|i| Authority::collator(i, ed25519_aura)).collect::<Vec<_>>();
+			let patch = collators_patch(&patch::genesis(&*chain_spec)?, &collators)?;
+			(collators, patch)
+		},
+	};
+
+	if let Some(path) = &args.keystore_path {
+		for authority in &authorities {
+			authority.write_keystore(&path.join(authority.seed.trim_start_matches('/')))?;
+		}
+	}
+
+	patch::apply_patch(chain_spec, patch)
+}
+
+/// Returns the amount of the first entry of the genesis config list at `pointer`.
+///
+/// The new authorities are funded and staked like the ones of the preset they replace.
+fn preset_amount(genesis: &Value, pointer: &str, index: usize) -> Result<Value, String> {
+	genesis
+		.pointer(pointer)
+		.and_then(|list| list.get(0))
+		.and_then(|entry| entry.get(index))
+		.cloned()
+		.ok_or_else(|| format!("The genesis preset has no `{pointer}` to take the amounts from"))
+}
+
+/// The endowed accounts of `genesis` together with the new `accounts`.
+fn balances(genesis: &Value, accounts: impl Iterator<Item = Value>) -> Result<Value, String> {
+	let endowment = preset_amount(genesis, "/balances/balances", 1)?;
+	let mut balances = genesis["balances"]["balances"].as_array().cloned().unwrap_or_default();
+	balances.extend(accounts.map(|account| json!([account, endowment])));
+	Ok(balances.into())
+}
+
+fn validators_patch(genesis: &Value, validators: &[Authority]) -> Result<Value, String> {
+	let stake = preset_amount(genesis, "/staking/stakers", 2)?;
+	let count = validators.len() as u32;
+
+	Ok(json!({
+		"balances": {
+			"balances": balances(genesis, validators.iter().map(|v| v.account.clone()))?,
+		},
+		"session": {
+			"keys": validators.iter().map(Authority::session_keys).collect::<Vec<_>>(),
+		},
+		"staking": {
+			"validatorCount": count,
+			"stakers": validators
+				.iter()
+				.map(|v| json!([v.account, v.account, stake, "Validator"]))
+				.collect::<Vec<_>>(),
+			"invulnerables": validators.iter().map(|v| v.account.clone()).collect::<Vec<_>>(),
+		},
+		"configuration": {
+			"config": {
+				"scheduler_params": {
+					"num_cores": count.div_ceil(VALIDATORS_PER_CORE),
+					"max_validators_per_core": VALIDATORS_PER_CORE,
+				},
+			},
+		},
+	}))
+}
+
+fn collators_patch(genesis: &Value, collators: &[Authority]) -> Result<Value, String> {
+	Ok(json!({
+		"balances": {
+			"balances": balances(genesis, collators.iter().map(|c| c.account.clone()))?,
+		},
+		"collatorSelection": {
+			"invulnerables": collators.iter().map(|c| c.account.clone()).collect::<Vec<_>>(),
+		},
+		"session": {
+			"keys": collators.iter().map(Authority::session_keys).collect::<Vec<_>>(),
+		},
+	}))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn validators_patch_works() {
+		let genesis = json!({
+			"balances": { "balances": [["alice", 1_000], ["bob", 1_000]] },
+			"staking": { "stakers": [["alice", "alice", 100, "Validator"]] },
+		});
+		let validators = (0..7).map(Authority::validator).collect::<Vec<_>>();
+		let patch = validators_patch(&genesis, &validators).unwrap();
+
+		assert_eq!(patch["balances"]["balances"].as_array().unwrap().len(), 9);
+		assert_eq!(patch["balances"]["balances"][8], json!([validators[6].account, 1_000]));
+		assert_eq!(patch["session"]["keys"].as_array().unwrap().len(), 7);
+		assert_eq!(patch["staking"]["validatorCount"], 7);
+		assert_eq!(patch["staking"]["stakers"][0][2], 100);
+		assert_eq!(patch["configuration"]["config"]["scheduler_params"]["num_cores"], 2);
+
+		// Keys are deterministic and unique per validator.
+		assert_eq!(Authority::validator(3).session_keys(), validators[3].session_keys());
+		assert_ne!(validators[0].session_keys(), validators[1].session_keys());
+	}
+
+	#[test]
+	fn collators_patch_needs_endowed_accounts() {
+		let collators = (0..3).map(|i| Authority::collator(i, false)).collect::<Vec<_>>();
+		assert!(collators_patch(&json!({}), &collators).is_err());
+
+		let genesis = json!({ "balances": { "balances": [["alice", 1_000]] } });
+		let patch = collators_patch(&genesis, &collators).unwrap();
+		assert_eq!(patch["collatorSelection"]["invulnerables"].as_array().unwrap().len(), 3);
+		assert!(patch["session"]["keys"][0][2]["aura"].is_string());
+	}
+}
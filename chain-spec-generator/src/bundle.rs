@@ -0,0 +1,228 @@
+// Copyright (C) Parity Technologies and the various Polkadot contributors, see Contributions.md
+// for a list of specific contributors.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Generating a relay chain together with all of its system parachains.
+
+use crate::{genesis, patch, presets, system_parachains_specs::Extensions, ChainSpec};
+use serde_json::json;
+use std::path::PathBuf;
+
+/// The capacity of the HRMP channels opened between the system parachains at genesis.
+const HRMP_CHANNEL_MAX_CAPACITY: u32 = 8;
+
+/// The maximum message size of the HRMP channels opened between the system parachains at genesis.
+const HRMP_CHANNEL_MAX_MESSAGE_SIZE: u32 = 8 * 1024;
+
+/// The relay chain of a network bundle.
+#[derive(Clone, Copy, clap::ValueEnum)]
+pub enum Network {
+	Polkadot,
+	Kusama,
+}
+
+#[derive(clap::Args)]
+pub struct BundleCmd {
+	/// The relay chain of the network.
+	network: Network,
+
+	/// The directory to write the chain specs and the zombienet network file to.
+	#[arg(long, default_value = ".")]
+	output: PathBuf,
+
+	/// Generate the chain specs as raw?
+	#[arg(long)]
+	raw: bool,
+}
+
+/// The genesis preset of all chains of a network bundle.
+const PRESET: &str = "local_testnet";
+
+/// A system parachain that is part of a network bundle.
+struct BundledParachain {
+	/// The runtime of the parachain.
+	runtime: &'static str,
+	/// Whether to open HRMP channels to all other system parachains of the bundle.
+	hrmp: bool,
+}
+
+impl Network {
+	fn relay(self) -> Result<Box<dyn ChainSpec>, String> {
+		let runtime = match self {
+			Network::Polkadot => "polkadot",
+			Network::Kusama => "kusama",
+		};
+		Ok(presets::runtime(runtime)?.chain_spec(PRESET)?)
+	}
+
+	fn parachains(self) -> Vec<BundledParachain> {
+		match self {
+			Network::Polkadot => Vec::from([
+				BundledParachain { runtime: "asset-hub-polkadot", hrmp: true },
+				BundledParachain { runtime: "collectives-polkadot", hrmp: true },
+				BundledParachain { runtime: "bridge-hub-polkadot", hrmp: true },
+				BundledParachain { runtime: "people-polkadot", hrmp: true },
+				BundledParachain { runtime: "coretime-polkadot", hrmp: true },
+			]),
+			Network::Kusama => Vec::from([
+				BundledParachain { runtime: "asset-hub-kusama", hrmp: true },
+				BundledParachain { runtime: "encointer-kusama", hrmp: true },
+				BundledParachain { runtime: "bridge-hub-kusama", hrmp: true },
+				BundledParachain { runtime: "people-kusama", hrmp: true },
+				BundledParachain { runtime: "coretime-kusama", hrmp: true },
+				// The glutton only burns resources, it doesn't send or receive any messages.
+				BundledParachain { runtime: "glutton-kusama", hrmp: false },
+			]),
+		}
+	}
+}
+
+/// A parachain whose genesis has been computed and which gets registered at the relay genesis.
+struct RegisteredParachain {
+	spec: Box<dyn ChainSpec>,
+	para_id: u32,
+	hrmp: bool,
+	genesis_head: Vec<u8>,
+	validation_code: Vec<u8>,
+}
+
+pub fn run(cmd: BundleCmd) -> Result<(), String> {
+	let relay = cmd.network.relay()?;
+
+	let mut parachains: Vec<RegisteredParachain> = Vec::new();
+	for parachain in cmd.network.parachains() {
+		let spec = presets::runtime(parachain.runtime)?.chain_spec(PRESET)?;
+		let Extensions { relay_chain, para_id } = Extensions::try_get(&*spec)
+			.cloned()
+			.ok_or_else(|| format!("`{}` is not a parachain chain spec", spec.id()))?;
+		if relay_chain != relay.id() {
+			return Err(format!(
+				"`{}` expects the relay chain `{relay_chain}`, but the bundle is for `{}`",
+				spec.id(),
+				relay.id()
+			))
+		}
+		if let Some(other) = parachains.iter().find(|p| p.para_id == para_id) {
+			return Err(format!(
+				"`{}` and `{}` both use the para id {para_id}",
+				other.spec.id(),
+				spec.id()
+			))
+		}
+
+		let storage = genesis::genesis_storage(&*spec)?;
+		parachains.push(RegisteredParachain {
+			genesis_head: genesis::genesis_head(&storage, genesis::state_version(&*spec)?),
+			validation_code: genesis::validation_code(&storage)?,
+			spec,
+			para_id,
+			hrmp: parachain.hrmp,
+		});
+	}
+
+	let relay = patch::apply_patch(relay, relay_genesis_patch(&parachains))?;
+
+	std::fs::create_dir_all(&cmd.output)
+		.map_err(|e| format!("Failed to create {}: {e}", cmd.output.display()))?;
+	for spec in std::iter::once(&relay).chain(parachains.iter().map(|p| &p.spec)) {
+		let path = cmd.output.join(format!("{}.json", spec.id()));
+		std::fs::write(&path, spec.as_json(cmd.raw)?)
+			.map_err(|e| format!("Failed to write {}: {e}", path.display()))?;
+	}
+
+	let path = cmd.output.join("network.toml");
+	std::fs::write(&path, zombienet_network(&*relay, &parachains)?)
+		.map_err(|e| format!("Failed to write {}: {e}", path.display()))?;
+
+	Ok(())
+}
+
+/// Registers `parachains` at the relay genesis and opens the system HRMP channels between them.
+fn relay_genesis_patch(parachains: &[RegisteredParachain]) -> serde_json::Value {
+	let paras = parachains
+		.iter()
+		.map(|p| {
+			json!([p.para_id, {
+				"genesis_head": sp_core::bytes::to_hex(&p.genesis_head, false),
+				"validation_code": sp_core::bytes::to_hex(&p.validation_code, false),
+				"parachain": true,
+			}])
+		})
+		.collect::<Vec<_>>();
+
+	let hrmp = parachains.iter().filter(|p| p.hrmp).collect::<Vec<_>>();
+	let channels = hrmp
+		.iter()
+		.flat_map(|sender| {
+			hrmp.iter().filter(move |recipient| recipient.para_id != sender.para_id).map(
+				move |recipient| {
+					json!([
+						sender.para_id,
+						recipient.para_id,
+						HRMP_CHANNEL_MAX_CAPACITY,
+						HRMP_CHANNEL_MAX_MESSAGE_SIZE
+					])
+				},
+			)
+		})
+		.collect::<Vec<_>>();
+
+	json!({
+		"paras": { "paras": paras },
+		"hrmp": { "preopenHrmpChannels": channels },
+	})
+}
+
+/// Returns a zombienet network definition using the chain specs of the bundle.
+///
+/// The parachains are already registered at the relay genesis, so zombienet must not add them
+/// again.
+fn zombienet_network(
+	relay: &dyn ChainSpec,
+	parachains: &[RegisteredParachain],
+) -> Result<String, String> {
+	let parachains = parachains
+		.iter()
+		.map(|p| {
+			json!({
+				"id": p.para_id,
+				"chain": p.spec.id(),
+				"chain_spec_path": format!("{}.json", p.spec.id()),
+				"cumulus_based": true,
+				"add_to_genesis": false,
+				"collators": [{
+					"name": format!("{}-collator", p.spec.id()),
+					"command": "polkadot-parachain",
+				}],
+			})
+		})
+		.collect::<Vec<_>>();
+
+	let network = json!({
+		"relaychain": {
+			"chain": relay.id(),
+			"chain_spec_path": format!("{}.json", relay.id()),
+			"default_command": "polkadot",
+			"nodes": [
+				{ "name": "alice", "validator": true },
+				{ "name": "bob", "validator": true },
+			],
+		},
+		"parachains": parachains,
+	});
+
+	toml::to_string(&network).map_err(|e| format!("Failed to generate the zombienet network: {e}"))
+}
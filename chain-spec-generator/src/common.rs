@@ -20,50 +20,101 @@ use crate::{
 	system_parachains_specs::{
 		AssetHubKusamaChainSpec, AssetHubPolkadotChainSpec, BridgeHubKusamaChainSpec,
 		BridgeHubPolkadotChainSpec, CollectivesPolkadotChainSpec, CoretimeKusamaChainSpec,
-		EncointerKusamaChainSpec, GluttonKusamaChainSpec, PeopleKusamaChainSpec,
-		PeoplePolkadotChainSpec,
+		CoretimePolkadotChainSpec, EncointerKusamaChainSpec, GluttonKusamaChainSpec,
+		PeopleKusamaChainSpec, PeoplePolkadotChainSpec,
 	},
 	ChainSpec,
 };
 
+use std::{fmt, path::PathBuf};
+
+/// Errors of loading or generating a chain spec.
+#[derive(Debug)]
+pub enum Error {
+	/// The chain spec file could not be read.
+	Io { path: PathBuf, error: std::io::Error },
+	/// The chain spec file is not valid json or has no `id`.
+	InvalidJson { path: PathBuf, error: serde_json::Error },
+	/// The `id` of the chain spec file doesn't belong to any supported chain.
+	UnknownChainId { id: String, supported: String },
+	/// There is no embedded runtime of the given name.
+	UnknownRuntime { runtime: String, available: Vec<String> },
+	/// The runtime doesn't provide a genesis preset of the given name.
+	UnknownPreset { runtime: String, preset: String, available: Vec<String> },
+	/// The wasm of the runtime was not built.
+	WasmNotAvailable(&'static str),
+	/// An error reported while building the chain spec or calling into the runtime.
+	ChainSpec(String),
+}
+
+impl fmt::Display for Error {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match self {
+			Error::Io { path, error } => write!(f, "Failed to read {}: {error}", path.display()),
+			Error::InvalidJson { path, error } =>
+				write!(f, "{} is not a valid chain spec: {error}", path.display()),
+			Error::UnknownChainId { id, supported } =>
+				write!(f, "Unknown chain 'id' {id} in json file. Only supported: {supported}"),
+			Error::UnknownRuntime { runtime, available } =>
+				write!(f, "Unknown runtime {runtime}, available: {}", available.join(", ")),
+			Error::UnknownPreset { runtime, preset, available } => write!(
+				f,
+				"Runtime {runtime} has no preset {preset}, available: {}",
+				available.join(", ")
+			),
+			Error::WasmNotAvailable(runtime) => write!(f, "{runtime} wasm not available"),
+			Error::ChainSpec(error) => f.write_str(error),
+		}
+	}
+}
+
+impl From<Error> for String {
+	fn from(error: Error) -> String {
+		error.to_string()
+	}
+}
+
 #[derive(Debug, serde::Deserialize)]
 struct EmptyChainSpecWithId {
 	id: String,
 }
 
-pub fn from_json_file(filepath: &str, supported: String) -> Result<Box<dyn ChainSpec>, String> {
-	let path = std::path::PathBuf::from(&filepath);
-	let file = std::fs::File::open(filepath).expect("Failed to open file");
+pub fn from_json_file(filepath: &str, supported: String) -> Result<Box<dyn ChainSpec>, Error> {
+	let path = PathBuf::from(&filepath);
+	let file =
+		std::fs::File::open(filepath).map_err(|error| Error::Io { path: path.clone(), error })?;
 	let reader = std::io::BufReader::new(file);
 	let chain_spec: EmptyChainSpecWithId = serde_json::from_reader(reader)
-		.expect("Failed to read 'json' file with ChainSpec configuration");
+		.map_err(|error| Error::InvalidJson { path: path.clone(), error })?;
 	match &chain_spec.id {
 		x if x.starts_with("polkadot") | x.starts_with("dot") =>
-			Ok(Box::new(PolkadotChainSpec::from_json_file(path)?)),
+			Ok(Box::new(PolkadotChainSpec::from_json_file(path).map_err(Error::ChainSpec)?)),
 		x if x.starts_with("kusama") | x.starts_with("ksm") =>
-			Ok(Box::new(KusamaChainSpec::from_json_file(path)?)),
+			Ok(Box::new(KusamaChainSpec::from_json_file(path).map_err(Error::ChainSpec)?)),
 		x if x.starts_with("asset-hub-polkadot") =>
-			Ok(Box::new(AssetHubPolkadotChainSpec::from_json_file(path)?)),
+			Ok(Box::new(AssetHubPolkadotChainSpec::from_json_file(path).map_err(Error::ChainSpec)?)),
 		x if x.starts_with("asset-hub-kusama") =>
-			Ok(Box::new(AssetHubKusamaChainSpec::from_json_file(path)?)),
-		x if x.starts_with("collectives-polkadot") =>
-			Ok(Box::new(CollectivesPolkadotChainSpec::from_json_file(path)?)),
-		x if x.starts_with("bridge-hub-polkadot") =>
-			Ok(Box::new(BridgeHubPolkadotChainSpec::from_json_file(path)?)),
+			Ok(Box::new(AssetHubKusamaChainSpec::from_json_file(path).map_err(Error::ChainSpec)?)),
+		x if x.starts_with("collectives-polkadot") => Ok(Box::new(
+			CollectivesPolkadotChainSpec::from_json_file(path).map_err(Error::ChainSpec)?,
+		)),
+		x if x.starts_with("bridge-hub-polkadot") => Ok(Box::new(
+			BridgeHubPolkadotChainSpec::from_json_file(path).map_err(Error::ChainSpec)?,
+		)),
 		x if x.starts_with("bridge-hub-kusama") =>
-			Ok(Box::new(BridgeHubKusamaChainSpec::from_json_file(path)?)),
+			Ok(Box::new(BridgeHubKusamaChainSpec::from_json_file(path).map_err(Error::ChainSpec)?)),
 		x if x.starts_with("coretime-kusama") =>
-			Ok(Box::new(CoretimeKusamaChainSpec::from_json_file(path)?)),
+			Ok(Box::new(CoretimeKusamaChainSpec::from_json_file(path).map_err(Error::ChainSpec)?)),
 		x if x.starts_with("coretime-polkadot") =>
-			Ok(Box::new(CoretimeKusamaChainSpec::from_json_file(path)?)),
+			Ok(Box::new(CoretimePolkadotChainSpec::from_json_file(path).map_err(Error::ChainSpec)?)),
 		x if x.starts_with("glutton-kusama") =>
-			Ok(Box::new(GluttonKusamaChainSpec::from_json_file(path)?)),
+			Ok(Box::new(GluttonKusamaChainSpec::from_json_file(path).map_err(Error::ChainSpec)?)),
 		x if x.starts_with("encointer-kusama") =>
-			Ok(Box::new(EncointerKusamaChainSpec::from_json_file(path)?)),
+			Ok(Box::new(EncointerKusamaChainSpec::from_json_file(path).map_err(Error::ChainSpec)?)),
 		x if x.starts_with("people-kusama") =>
-			Ok(Box::new(PeopleKusamaChainSpec::from_json_file(path)?)),
+			Ok(Box::new(PeopleKusamaChainSpec::from_json_file(path).map_err(Error::ChainSpec)?)),
 		x if x.starts_with("people-polkadot") =>
-			Ok(Box::new(PeoplePolkadotChainSpec::from_json_file(path)?)),
-		_ => Err(format!("Unknown chain 'id' in json file. Only supported: {supported}'")),
+			Ok(Box::new(PeoplePolkadotChainSpec::from_json_file(path).map_err(Error::ChainSpec)?)),
+		id => Err(Error::UnknownChainId { id: id.clone(), supported }),
 	}
 }
@@ -0,0 +1,342 @@
+// Copyright (C) Parity Technologies and the various Polkadot contributors, see Contributions.md
+// for a list of specific contributors.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Comparing two chain specs, e.g. the ones of two releases.
+
+use codec::Decode;
+use frame_metadata::{RuntimeMetadata, RuntimeMetadataPrefixed};
+use sc_executor::WasmExecutor;
+use sc_executor_common::runtime_blob::RuntimeBlob;
+use serde::Serialize;
+use serde_json::{Map, Value};
+use sp_core::{storage::well_known_keys, twox_128};
+use std::collections::{BTreeMap, BTreeSet};
+
+#[derive(clap::Args)]
+pub struct DiffCmd {
+	/// The old chain spec, plain or raw.
+	old: String,
+
+	/// The new chain spec, plain or raw.
+	new: String,
+
+	/// Print the differences as json, e.g. for reviews in CI.
+	#[arg(long)]
+	json: bool,
+}
+
+/// The differences between two chain specs.
+#[derive(Debug, Default, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Diff {
+	/// The old and new runtime code hash, if the code changed.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	code_hash: Option<(String, String)>,
+	boot_nodes_added: Vec<Value>,
+	boot_nodes_removed: Vec<Value>,
+	/// The properties whose value changed, with their old and new value.
+	properties: BTreeMap<String, (Value, Value)>,
+	storage_added: Vec<StorageEntry>,
+	storage_removed: Vec<StorageEntry>,
+	storage_changed: Vec<StorageEntry>,
+}
+
+/// A storage entry present in only one of the chain specs, or whose value changed.
+#[derive(Debug, PartialEq, Serialize)]
+pub struct StorageEntry {
+	/// The child trie the entry belongs to, if any.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	child: Option<String>,
+	/// The pallet and storage item of the key, if known.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	item: Option<String>,
+	key: String,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	old: Option<String>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	new: Option<String>,
+}
+
+pub fn run(cmd: DiffCmd) -> Result<(), String> {
+	let old = load_raw(&cmd.old)?;
+	let new = load_raw(&cmd.new)?;
+
+	let mut names = storage_names(code(&old).as_deref())?;
+	names.extend(storage_names(code(&new).as_deref())?);
+	let diff = diff(&old, &new, &names);
+
+	if cmd.json {
+		let json = serde_json::to_string_pretty(&diff).map_err(|e| e.to_string())?;
+		println!("{json}");
+	} else {
+		print_diff(&diff);
+	}
+	Ok(())
+}
+
+/// Loads a chain spec and returns its raw json representation.
+fn load_raw(chain: &str) -> Result<Value, String> {
+	let chain_spec = crate::load_chain_spec(chain)?;
+	serde_json::from_str(&chain_spec.as_json(true)?)
+		.map_err(|e| format!("Failed to parse chain spec {chain}: {e}"))
+}
+
+/// Returns the runtime code of a raw chain spec.
+fn code(chain_spec: &Value) -> Option<Vec<u8>> {
+	storage(&chain_spec["genesis"]["raw"]["top"]).remove(well_known_keys::CODE)
+}
+
+/// Decodes the metadata of the runtime `code` by calling into it.
+fn metadata(code: &[u8]) -> Result<RuntimeMetadataPrefixed, String> {
+	let blob = RuntimeBlob::uncompress_if_needed(code)
+		.map_err(|e| format!("Invalid runtime code: {e}"))?;
+	let executor = WasmExecutor::<sp_io::SubstrateHostFunctions>::builder().build();
+	let mut ext = sp_io::TestExternalities::default();
+	let encoded = executor
+		.uncached_call(blob, &mut ext.ext(), true, "Metadata_metadata", &[])
+		.map_err(|e| format!("Failed to call `Metadata_metadata`: {e}"))?;
+	let metadata = Vec::<u8>::decode(&mut &encoded[..])
+		.map_err(|e| format!("Failed to decode the metadata: {e}"))?;
+	RuntimeMetadataPrefixed::decode(&mut &metadata[..])
+		.map_err(|e| format!("Failed to decode the metadata: {e}"))
+}
+
+/// Maps the storage prefixes of all pallets and storage items to their names.
+///
+/// The names are taken from the metadata of the runtime `code` of the chain spec itself, so each
+/// chain spec is named after its own runtime version. The 16 byte pallet prefixes are included as
+/// well, to name keys which are not part of the metadata, like the storage version of a pallet.
+fn storage_names(code: Option<&[u8]>) -> Result<BTreeMap<Vec<u8>, String>, String> {
+	let mut names = BTreeMap::from([
+		(well_known_keys::CODE.to_vec(), ":code".to_string()),
+		(well_known_keys::HEAP_PAGES.to_vec(), ":heappages".to_string()),
+	]);
+
+	let Some(code) = code else { return Ok(names) };
+	let RuntimeMetadataPrefixed(_, RuntimeMetadata::V14(metadata)) = metadata(code)? else {
+		return Ok(names)
+	};
+	for storage in metadata.pallets.iter().filter_map(|pallet| pallet.storage.as_ref()) {
+		let pallet_prefix = twox_128(storage.prefix.as_bytes());
+		names.insert(pallet_prefix.to_vec(), storage.prefix.clone());
+		for entry in &storage.entries {
+			let prefix = [pallet_prefix, twox_128(entry.name.as_bytes())].concat();
+			names.insert(prefix, format!("{}::{}", storage.prefix, entry.name));
+		}
+	}
+	Ok(names)
+}
+
+/// Returns the name of the longest prefix of `key` in `names`.
+fn item_name(key: &[u8], names: &BTreeMap<Vec<u8>, String>) -> Option<String> {
+	[key.len(), 32, 16]
+		.into_iter()
+		.find_map(|len| names.get(key.get(..len)?))
+		.cloned()
+}
+
+fn diff(old: &Value, new: &Value, names: &BTreeMap<Vec<u8>, String>) -> Diff {
+	let mut diff = Diff::default();
+
+	let old_code = code(old);
+	let new_code = code(new);
+	if old_code != new_code {
+		let hash = |code: Option<Vec<u8>>| {
+			code.map_or("none".into(), |c| sp_core::bytes::to_hex(&sp_core::blake2_256(&c), false))
+		};
+		diff.code_hash = Some((hash(old_code), hash(new_code)));
+	}
+
+	let old_boot_nodes = array(&old["bootNodes"]);
+	let new_boot_nodes = array(&new["bootNodes"]);
+	diff.boot_nodes_added =
+		new_boot_nodes.iter().filter(|n| !old_boot_nodes.contains(n)).cloned().collect();
+	diff.boot_nodes_removed =
+		old_boot_nodes.iter().filter(|n| !new_boot_nodes.contains(n)).cloned().collect();
+
+	let empty = Map::new();
+	let old_properties = old["properties"].as_object().unwrap_or(&empty);
+	let new_properties = new["properties"].as_object().unwrap_or(&empty);
+	for key in old_properties.keys().chain(new_properties.keys()).collect::<BTreeSet<_>>() {
+		let old = old_properties.get(key).cloned().unwrap_or_default();
+		let new = new_properties.get(key).cloned().unwrap_or_default();
+		if old != new {
+			diff.properties.insert(key.clone(), (old, new));
+		}
+	}
+
+	let (old, new) = (&old["genesis"]["raw"], &new["genesis"]["raw"]);
+	diff_storage(&mut diff, None, &old["top"], &new["top"], names);
+	let old_children = old["childrenDefault"].as_object().unwrap_or(&empty);
+	let new_children = new["childrenDefault"].as_object().unwrap_or(&empty);
+	for child in old_children.keys().chain(new_children.keys()).collect::<BTreeSet<_>>() {
+		let old = old_children.get(child).cloned().unwrap_or_default();
+		let new = new_children.get(child).cloned().unwrap_or_default();
+		diff_storage(&mut diff, Some(child), &old, &new, names);
+	}
+
+	diff
+}
+
+fn diff_storage(
+	diff: &mut Diff,
+	child: Option<&String>,
+	old: &Value,
+	new: &Value,
+	names: &BTreeMap<Vec<u8>, String>,
+) {
+	let mut old = storage(old);
+	let mut new = storage(new);
+	if child.is_none() {
+		// Reported as code hash.
+		old.remove(well_known_keys::CODE);
+		new.remove(well_known_keys::CODE);
+	}
+
+	let to_hex = |bytes: &Vec<u8>| sp_core::bytes::to_hex(bytes, false);
+	let entry = |key: &Vec<u8>, old: Option<&Vec<u8>>, new: Option<&Vec<u8>>| StorageEntry {
+		child: child.cloned(),
+		item: child.is_none().then(|| item_name(key, names)).flatten(),
+		key: to_hex(key),
+		old: old.map(to_hex),
+		new: new.map(to_hex),
+	};
+
+	for (key, value) in &new {
+		match old.get(key) {
+			None => diff.storage_added.push(entry(key, None, Some(value))),
+			Some(old_value) if old_value != value =>
+				diff.storage_changed.push(entry(key, Some(old_value), Some(value))),
+			Some(_) => {},
+		}
+	}
+	for (key, value) in &old {
+		if !new.contains_key(key) {
+			diff.storage_removed.push(entry(key, Some(value), None));
+		}
+	}
+}
+
+/// Decodes a raw storage map of a chain spec.
+fn storage(raw: &Value) -> BTreeMap<Vec<u8>, Vec<u8>> {
+	raw.as_object()
+		.into_iter()
+		.flatten()
+		.filter_map(|(key, value)| {
+			let key = sp_core::bytes::from_hex(key).ok()?;
+			let value = sp_core::bytes::from_hex(value.as_str()?).ok()?;
+			Some((key, value))
+		})
+		.collect()
+}
+
+fn array(value: &Value) -> Vec<Value> {
+	value.as_array().cloned().unwrap_or_default()
+}
+
+fn print_diff(diff: &Diff) {
+	if let Some((old, new)) = &diff.code_hash {
+		println!("Code hash: {old} -> {new}");
+	}
+	for node in &diff.boot_nodes_added {
+		println!("Boot node added: {node}");
+	}
+	for node in &diff.boot_nodes_removed {
+		println!("Boot node removed: {node}");
+	}
+	for (key, (old, new)) in &diff.properties {
+		println!("Property {key}: {old} -> {new}");
+	}
+
+	for (sign, entries) in
+		[("+", &diff.storage_added), ("-", &diff.storage_removed), ("~", &diff.storage_changed)]
+	{
+		let mut items = BTreeMap::<String, usize>::new();
+		for entry in entries {
+			let item = match (&entry.child, &entry.item) {
+				(Some(child), _) => format!("child trie {child}"),
+				(None, Some(item)) => item.clone(),
+				(None, None) => "unknown".into(),
+			};
+			*items.entry(item).or_default() += 1;
+		}
+		for (item, count) in items {
+			println!("{sign} {item}: {count} key(s)");
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use serde_json::json;
+
+	#[test]
+	fn diff_works() {
+		let account = [twox_128(b"System"), twox_128(b"Account")].concat();
+		let names = BTreeMap::from([(account.clone(), "System::Account".to_string())]);
+		let key = |suffix: &[u8]| sp_core::bytes::to_hex(&[&account[..], suffix].concat(), false);
+
+		let old = json!({
+			"bootNodes": ["/dns/a", "/dns/b"],
+			"properties": { "tokenSymbol": "DOT", "tokenDecimals": 10 },
+			"genesis": { "raw": {
+				"top": { "0x3a636f6465": "0x01", key(&[1]): "0x01", key(&[2]): "0x02" },
+				"childrenDefault": {},
+			}},
+		});
+		let new = json!({
+			"bootNodes": ["/dns/b", "/dns/c"],
+			"properties": { "tokenSymbol": "DOT", "tokenDecimals": 12 },
+			"genesis": { "raw": {
+				"top": { "0x3a636f6465": "0x01", key(&[1]): "0x03", key(&[3]): "0x03", "0x00": "0x00" },
+				"childrenDefault": {},
+			}},
+		});
+
+		let diff = diff(&old, &new, &names);
+		assert_eq!(diff.code_hash, None);
+		assert_eq!(diff.boot_nodes_added, vec![json!("/dns/c")]);
+		assert_eq!(diff.boot_nodes_removed, vec![json!("/dns/a")]);
+		assert_eq!(
+			diff.properties,
+			BTreeMap::from([("tokenDecimals".to_string(), (json!(10), json!(12)))])
+		);
+		assert_eq!(
+			diff.storage_added.iter().map(|e| e.item.clone()).collect::<Vec<_>>(),
+			vec![None, Some("System::Account".to_string())]
+		);
+		assert_eq!(diff.storage_removed.len(), 1);
+		assert_eq!(diff.storage_removed[0].key, key(&[2]));
+		assert_eq!(diff.storage_changed.len(), 1);
+		assert_eq!(diff.storage_changed[0].old, Some("0x01".into()));
+		assert_eq!(diff.storage_changed[0].new, Some("0x03".into()));
+	}
+
+	#[test]
+	fn storage_names_are_decoded_from_the_code() {
+		let Some(code) = polkadot_runtime::WASM_BINARY else { return };
+
+		let names = storage_names(Some(code)).unwrap();
+		let account = [twox_128(b"System"), twox_128(b"Account")].concat();
+		assert_eq!(names.get(&account), Some(&"System::Account".to_string()));
+		assert_eq!(names.get(twox_128(b"Staking").as_slice()), Some(&"Staking".to_string()));
+
+		assert_eq!(storage_names(None).unwrap().len(), 2);
+		assert!(storage_names(Some(b"not a runtime")).is_err());
+	}
+}
@@ -0,0 +1,70 @@
+// Copyright (C) Parity Technologies and the various Polkadot contributors, see Contributions.md
+// for a list of specific contributors.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Exporting the genesis head data and validation code of parachains.
+
+use crate::{genesis, system_parachains_specs::Extensions};
+use std::path::PathBuf;
+
+#[derive(clap::Args)]
+pub struct ExportCmd {
+	/// The parachain chain spec, one of the supported chains or a json file.
+	chain: String,
+
+	/// Output file name, or stdout if not given.
+	#[arg(long)]
+	output: Option<PathBuf>,
+
+	/// Write the output as binary instead of hex.
+	#[arg(long)]
+	raw: bool,
+}
+
+pub fn genesis_head(cmd: ExportCmd) -> Result<(), String> {
+	let chain_spec = load_parachain_spec(&cmd.chain)?;
+	let storage = genesis::genesis_storage(&*chain_spec)?;
+	let head = genesis::genesis_head(&storage, genesis::state_version(&*chain_spec)?);
+	write_output(&cmd, &head)
+}
+
+pub fn genesis_code(cmd: ExportCmd) -> Result<(), String> {
+	let chain_spec = load_parachain_spec(&cmd.chain)?;
+	let storage = genesis::genesis_storage(&*chain_spec)?;
+	write_output(&cmd, &genesis::validation_code(&storage)?)
+}
+
+fn load_parachain_spec(chain: &str) -> Result<Box<dyn crate::ChainSpec>, String> {
+	let chain_spec = crate::load_chain_spec(chain)?;
+	if Extensions::try_get(&*chain_spec).is_none() {
+		return Err(format!("`{chain}` is not a parachain chain spec"))
+	}
+	Ok(chain_spec)
+}
+
+fn write_output(cmd: &ExportCmd, data: &[u8]) -> Result<(), String> {
+	let output =
+		if cmd.raw { data.to_vec() } else { sp_core::bytes::to_hex(data, false).into_bytes() };
+
+	match &cmd.output {
+		Some(path) => std::fs::write(path, output)
+			.map_err(|e| format!("Failed to write {}: {e}", path.display())),
+		None => {
+			use std::io::Write;
+			std::io::stdout().write_all(&output).map_err(|e| e.to_string())
+		},
+	}
+}
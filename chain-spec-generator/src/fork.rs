@@ -0,0 +1,336 @@
+// Copyright (C) Parity Technologies and the various Polkadot contributors, see Contributions.md
+// for a list of specific contributors.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Forking off a local chain from a state snapshot of a live chain.
+//!
+//! Root calls are scheduled for the first block of the fork with `--root-call`. Injecting a
+//! sudo-like root account is not supported: none of the runtimes has a sudo pallet, and there is
+//! no other way of giving an account the root origin through the state alone.
+//!
+//! Shortened governance track periods come with the runtime: a `fast-runtime` build scales the
+//! OpenGov periods from days down to minutes, and boots the fork when passed with `--code`.
+
+use crate::{genesis, system_parachains_specs::Extensions};
+use codec::{Compact, Encode};
+use remote_externalities::{Builder, Mode, OfflineConfig, SnapshotConfig};
+use sp_core::{
+	hashing::{blake2_256, twox_128, twox_64},
+	storage::{well_known_keys, ChildInfo, Storage, StorageChild},
+};
+use sp_runtime::{generic, traits::BlakeTwo256, OpaqueExtrinsic};
+use std::{collections::BTreeMap, path::PathBuf};
+
+/// Block type used to load the snapshot, all supported chains share the same header.
+type Block = generic::Block<generic::Header<u32, BlakeTwo256>, OpaqueExtrinsic>;
+
+/// Pallets of the relay chains whose state is taken from the dev genesis instead of the snapshot.
+const RELAY_CONSENSUS_PALLETS: &[&str] = &[
+	"Babe",
+	"Grandpa",
+	"Beefy",
+	"Mmr",
+	"BeefyMmrLeaf",
+	"Session",
+	"Historical",
+	"AuthorityDiscovery",
+	"ParasShared",
+	"ParaScheduler",
+	"ParaSessionInfo",
+];
+
+/// Pallets of the parachains whose state is taken from the dev genesis instead of the snapshot.
+///
+/// `ParachainSystem` is included because the forked parachain runs against a new relay chain, so
+/// the relay chain block number and state it tracks need to start over as well.
+const PARACHAIN_CONSENSUS_PALLETS: &[&str] =
+	&["Aura", "AuraExt", "Session", "CollatorSelection", "ParachainSystem"];
+
+/// `pallet_staking::Forcing::ForceNone`.
+const FORCE_NONE: u8 = 2;
+
+/// The longest call the scheduler stores inline, longer calls are stored as preimages.
+const MAX_INLINE_CALL_LEN: usize = 128;
+
+/// The block at which calls passed with `--root-call` are dispatched.
+const ROOT_CALL_BLOCK: u32 = 1;
+
+#[derive(clap::Args)]
+pub struct ForkCmd {
+	/// The local chain spec to fork off, its authorities replace the ones of the snapshot.
+	chain: String,
+
+	/// The try-runtime state snapshot of the live chain.
+	#[arg(long)]
+	snapshot: PathBuf,
+
+	/// Hex encoded call to dispatch with the root origin in the first block of the fork.
+	///
+	/// Can be given multiple times, e.g. to authorize an upgrade and to enact a referendum.
+	#[arg(long = "root-call", value_parser = parse_hex)]
+	root_calls: Vec<Vec<u8>>,
+
+	/// Runtime wasm to boot the fork with instead of the runtime of the local chain spec.
+	///
+	/// E.g. a `fast-runtime` build, to go through referenda with shortened track periods.
+	#[arg(long)]
+	code: Option<PathBuf>,
+}
+
+fn parse_hex(s: &str) -> Result<Vec<u8>, String> {
+	sp_core::bytes::from_hex(s).map_err(|e| format!("Invalid hex: {e:?}"))
+}
+
+pub fn run(cmd: ForkCmd) -> Result<(), String> {
+	let chain_spec = crate::load_chain_spec(&cmd.chain)?;
+	let dev = genesis::genesis_storage(&*chain_spec)?;
+	let mut forked = load_snapshot(cmd.snapshot)?;
+
+	// Boot with the runtime of this repository.
+	for key in [well_known_keys::CODE, well_known_keys::HEAP_PAGES] {
+		match dev.top.get(key) {
+			Some(value) => forked.top.insert(key.to_vec(), value.clone()),
+			None => forked.top.remove(key),
+		};
+	}
+	if let Some(path) = cmd.code {
+		let code = std::fs::read(&path)
+			.map_err(|e| format!("Failed to read the runtime code {}: {e}", path.display()))?;
+		forked.top.insert(well_known_keys::CODE.to_vec(), code);
+	}
+
+	let is_parachain = Extensions::try_get(&*chain_spec).is_some();
+	let consensus_pallets =
+		if is_parachain { PARACHAIN_CONSENSUS_PALLETS } else { RELAY_CONSENSUS_PALLETS };
+	for pallet in consensus_pallets {
+		let prefix = twox_128(pallet.as_bytes());
+		forked.top.retain(|key, _| !key.starts_with(&prefix));
+		forked.top.extend(
+			dev.top
+				.iter()
+				.filter(|(key, _)| key.starts_with(&prefix))
+				.map(|(k, v)| (k.clone(), v.clone())),
+		);
+	}
+
+	if !is_parachain && has_pallet(&forked, "Staking") {
+		// The validators elected by the live chain have no keys on the fork, so the current dev
+		// validator set is kept by not starting any new era.
+		forked.top.insert(storage_value_key("Staking", "ForceEra"), FORCE_NONE.encode());
+	}
+
+	if !cmd.root_calls.is_empty() {
+		schedule_root_calls(&mut forked, cmd.root_calls)?;
+	}
+
+	let mut json = serde_json::from_str::<serde_json::Value>(&chain_spec.as_json(true)?)
+		.map_err(|e| format!("Failed to parse chain spec: {e}"))?;
+	let name = format!("{} Fork", chain_spec.name());
+	json["name"] = name.into();
+	json["genesis"]["raw"] = raw_genesis(&forked);
+
+	print!(
+		"{}",
+		serde_json::to_string_pretty(&json)
+			.map_err(|e| format!("Failed to write chain spec: {e}"))?
+	);
+	Ok(())
+}
+
+/// Reads the full state, including child tries, from a snapshot file.
+fn load_snapshot(path: PathBuf) -> Result<Storage, String> {
+	let runtime = tokio::runtime::Builder::new_current_thread()
+		.enable_all()
+		.build()
+		.map_err(|e| format!("Failed to start the async runtime: {e}"))?;
+	let mut ext = runtime
+		.block_on(
+			Builder::<Block>::default()
+				.mode(Mode::Offline(OfflineConfig { state_snapshot: SnapshotConfig::new(path) }))
+				.build(),
+		)
+		.map_err(|e| format!("Failed to load the snapshot: {e}"))?;
+
+	Ok(ext.execute_with(|| {
+		let mut storage = Storage::default();
+		let mut key = Vec::new();
+		while let Some(next) = sp_io::storage::next_key(&key) {
+			key = next;
+			if let Some(child_key) =
+				key.strip_prefix(well_known_keys::DEFAULT_CHILD_STORAGE_KEY_PREFIX)
+			{
+				let child_info = ChildInfo::new_default(child_key);
+				let mut child = StorageChild { data: Default::default(), child_info };
+				let mut child_entry = Vec::new();
+				while let Some(next) =
+					sp_io::default_child_storage::next_key(child_key, &child_entry)
+				{
+					child_entry = next;
+					if let Some(value) = sp_io::default_child_storage::get(child_key, &child_entry)
+					{
+						child.data.insert(child_entry.clone(), value.to_vec());
+					}
+				}
+				storage.children_default.insert(child_key.to_vec(), child);
+			} else if let Some(value) = sp_io::storage::get(&key) {
+				storage.top.insert(key.clone(), value.to_vec());
+			}
+		}
+		storage
+	}))
+}
+
+/// Schedules `calls` to be dispatched with the root origin at [`ROOT_CALL_BLOCK`].
+///
+/// Calls longer than [`MAX_INLINE_CALL_LEN`] are noted as requested preimages, just like the
+/// referenda pallet does it for its proposals.
+fn schedule_root_calls(storage: &mut Storage, calls: Vec<Vec<u8>>) -> Result<(), String> {
+	if !has_pallet(storage, "Scheduler") {
+		return Err("`--root-call` needs the scheduler pallet, which the chain doesn't have".into())
+	}
+
+	let mut agenda = Vec::new();
+	for call in calls {
+		// `Option<Scheduled>` with no id, the highest priority and no period.
+		let mut task = vec![1u8];
+		None::<[u8; 32]>.encode_to(&mut task);
+		0u8.encode_to(&mut task);
+		if call.len() <= MAX_INLINE_CALL_LEN {
+			// `Bounded::Inline`.
+			1u8.encode_to(&mut task);
+			call.encode_to(&mut task);
+		} else {
+			if !has_pallet(storage, "Preimage") {
+				return Err(format!(
+					"Calls longer than {MAX_INLINE_CALL_LEN} bytes need the preimage pallet, \
+					which the chain doesn't have"
+				))
+			}
+			let hash = blake2_256(&call);
+			let len = call.len() as u32;
+			// `Bounded::Lookup`.
+			2u8.encode_to(&mut task);
+			(hash, len).encode_to(&mut task);
+
+			// `RequestStatus::Requested` without a ticket, requested once, with its length.
+			let status = (1u8, None::<()>, 1u32, Some(len)).encode();
+			storage
+				.top
+				.insert(map_key("Preimage", "RequestStatusFor", &hash.encode(), false), status);
+			storage.top.insert(
+				map_key("Preimage", "PreimageFor", &(hash, len).encode(), false),
+				call.encode(),
+			);
+		}
+		None::<(u32, u32)>.encode_to(&mut task);
+		// `OriginCaller::system(RawOrigin::Root)`, `System` has index 0 in all our runtimes.
+		(0u8, 0u8).encode_to(&mut task);
+		agenda.push(task);
+	}
+
+	let mut value = Compact(agenda.len() as u32).encode();
+	agenda.into_iter().for_each(|task| value.extend(task));
+	storage
+		.top
+		.insert(map_key("Scheduler", "Agenda", &ROOT_CALL_BLOCK.encode(), true), value);
+	// The snapshot may point the scheduler to a block far ahead of the fork.
+	storage.top.remove(&storage_value_key("Scheduler", "IncompleteSince"));
+
+	Ok(())
+}
+
+fn has_pallet(storage: &Storage, pallet: &str) -> bool {
+	storage.top.contains_key(&storage_value_key(pallet, ":__STORAGE_VERSION__:"))
+}
+
+fn storage_value_key(pallet: &str, item: &str) -> Vec<u8> {
+	[twox_128(pallet.as_bytes()), twox_128(item.as_bytes())].concat()
+}
+
+/// Storage key of `key` in a map using either the `Twox64Concat` or the `Identity` hasher.
+fn map_key(pallet: &str, item: &str, key: &[u8], twox_64_concat: bool) -> Vec<u8> {
+	let mut storage_key = storage_value_key(pallet, item);
+	if twox_64_concat {
+		storage_key.extend(twox_64(key));
+	}
+	storage_key.extend(key);
+	storage_key
+}
+
+fn raw_genesis(storage: &Storage) -> serde_json::Value {
+	let to_json = |data: &BTreeMap<Vec<u8>, Vec<u8>>| {
+		data.iter()
+			.map(|(k, v)| {
+				(sp_core::bytes::to_hex(k, false), sp_core::bytes::to_hex(v, false).into())
+			})
+			.collect::<serde_json::Map<_, _>>()
+	};
+
+	serde_json::json!({
+		"top": to_json(&storage.top),
+		"childrenDefault": storage
+			.children_default
+			.iter()
+			.map(|(k, child)| (sp_core::bytes::to_hex(k, false), to_json(&child.data).into()))
+			.collect::<serde_json::Map<_, _>>(),
+	})
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn root_calls_are_scheduled() {
+		let mut storage = Storage::default();
+		assert!(schedule_root_calls(&mut storage, vec![vec![0, 1]]).is_err());
+
+		for pallet in ["Scheduler", "Preimage"] {
+			storage
+				.top
+				.insert(storage_value_key(pallet, ":__STORAGE_VERSION__:"), 4u16.encode());
+		}
+		storage
+			.top
+			.insert(storage_value_key("Scheduler", "IncompleteSince"), 100u32.encode());
+
+		let long_call = vec![7u8; MAX_INLINE_CALL_LEN + 1];
+		assert!(schedule_root_calls(&mut storage, vec![vec![0, 1], long_call.clone()]).is_ok());
+
+		let hash = blake2_256(&long_call);
+		let agenda = storage
+			.top
+			.get(&map_key("Scheduler", "Agenda", &ROOT_CALL_BLOCK.encode(), true))
+			.unwrap();
+		let mut expected = Compact(2u32).encode();
+		expected.extend([1, 0, 0, 1, 8, 0, 1, 0, 0, 0]);
+		expected.extend([1, 0, 0, 2]);
+		expected.extend((hash, long_call.len() as u32).encode());
+		expected.extend([0, 0, 0]);
+		assert_eq!(agenda, &expected);
+
+		assert_eq!(
+			storage.top.get(&map_key(
+				"Preimage",
+				"PreimageFor",
+				&(hash, long_call.len() as u32).encode(),
+				false
+			)),
+			Some(&long_call.encode())
+		);
+		assert!(!storage.top.contains_key(&storage_value_key("Scheduler", "IncompleteSince")));
+	}
+}
@@ -0,0 +1,85 @@
+// Copyright (C) Parity Technologies and the various Polkadot contributors, see Contributions.md
+// for a list of specific contributors.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Genesis head data and validation code of parachain chain specs.
+
+use crate::ChainSpec;
+use codec::Encode;
+use sp_core::storage::{well_known_keys, Storage};
+use sp_runtime::{
+	generic::Header,
+	traits::{BlakeTwo256, Hash, Header as _},
+	StateVersion,
+};
+
+/// Returns the state version of the runtime of the parachain `chain_spec`.
+pub fn state_version(chain_spec: &dyn ChainSpec) -> Result<StateVersion, String> {
+	let version = match chain_spec.id() {
+		x if x.starts_with("asset-hub-polkadot") => asset_hub_polkadot_runtime::VERSION,
+		x if x.starts_with("asset-hub-kusama") => asset_hub_kusama_runtime::VERSION,
+		x if x.starts_with("collectives-polkadot") => collectives_polkadot_runtime::VERSION,
+		x if x.starts_with("bridge-hub-polkadot") => bridge_hub_polkadot_runtime::VERSION,
+		x if x.starts_with("bridge-hub-kusama") => bridge_hub_kusama_runtime::VERSION,
+		x if x.starts_with("coretime-kusama") => coretime_kusama_runtime::VERSION,
+		x if x.starts_with("coretime-polkadot") => coretime_polkadot_runtime::VERSION,
+		x if x.starts_with("glutton-kusama") => glutton_kusama_runtime::VERSION,
+		x if x.starts_with("encointer-kusama") => encointer_kusama_runtime::VERSION,
+		x if x.starts_with("people-kusama") => people_kusama_runtime::VERSION,
+		x if x.starts_with("people-polkadot") => people_polkadot_runtime::VERSION,
+		x => return Err(format!("`{x}` is not a supported parachain")),
+	};
+	Ok(version.state_version())
+}
+
+/// Builds the genesis storage of `chain_spec`.
+pub fn genesis_storage(chain_spec: &dyn ChainSpec) -> Result<Storage, String> {
+	chain_spec.as_storage_builder().build_storage()
+}
+
+/// Returns the SCALE encoded genesis header of a parachain, i.e. its genesis head data.
+///
+/// This mirrors how the client constructs the genesis block, so the result matches what
+/// `export-genesis-state` of a collator reports for the same chain spec.
+pub fn genesis_head(storage: &Storage, state_version: StateVersion) -> Vec<u8> {
+	let child_roots = storage.children_default.values().map(|child| {
+		let root = BlakeTwo256::trie_root(child.data.clone().into_iter().collect(), state_version);
+		(child.child_info.prefixed_storage_key().into_inner(), root.encode())
+	});
+	let state_root = BlakeTwo256::trie_root(
+		storage.top.clone().into_iter().chain(child_roots).collect(),
+		state_version,
+	);
+	let extrinsics_root = BlakeTwo256::trie_root(Vec::new(), state_version);
+
+	Header::<u32, BlakeTwo256>::new(
+		0,
+		extrinsics_root,
+		state_root,
+		Default::default(),
+		Default::default(),
+	)
+	.encode()
+}
+
+/// Returns the validation code of a parachain, i.e. the runtime code stored at genesis.
+pub fn validation_code(storage: &Storage) -> Result<Vec<u8>, String> {
+	storage
+		.top
+		.get(well_known_keys::CODE)
+		.cloned()
+		.ok_or_else(|| "Genesis storage does not contain the runtime code".into())
+}
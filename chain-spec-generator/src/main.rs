@@ -15,108 +15,106 @@
 // You should have received a copy of the GNU General Public License
 // along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
 
-use clap::Parser;
+use clap::{Parser, Subcommand};
 use sc_chain_spec::ChainSpec;
-use std::collections::HashMap;
+use std::path::PathBuf;
 
+mod authorities;
+mod bundle;
 mod common;
+mod diff;
+mod export;
+mod fork;
+mod genesis;
+mod patch;
+mod presets;
 mod relay_chain_specs;
 mod system_parachains_specs;
 
 #[derive(Parser)]
+#[command(args_conflicts_with_subcommands = true, subcommand_negates_reqs = true)]
 struct Cli {
-	/// The chain spec to generate.
-	chain: String,
+	#[command(subcommand)]
+	command: Option<Command>,
+
+	/// The chain spec to generate, a built-in chain, a `<runtime>:<preset>` pair as shown by
+	/// `list` or a json file.
+	#[arg(required = true)]
+	chain: Option<String>,
 
 	/// Generate the chain spec as raw?
 	#[arg(long)]
 	raw: bool,
+
+	/// A `.json` or `.toml` genesis config fragment to deep-merge into the chain's genesis
+	/// preset.
+	#[arg(long)]
+	patch: Option<PathBuf>,
+
+	#[command(flatten)]
+	authorities: authorities::AuthoritiesArgs,
+}
+
+#[derive(Subcommand)]
+enum Command {
+	/// Generate the chain specs of a relay chain and all of its system parachains, with the
+	/// parachains registered and the system HRMP channels opened at the relay genesis.
+	Bundle(bundle::BundleCmd),
+	/// Compare two chain specs, naming the changed storage items after the runtime metadata.
+	Diff(diff::DiffCmd),
+	/// Export the genesis head data of a parachain, as needed to register it on the relay chain.
+	ExportGenesisHead(export::ExportCmd),
+	/// Export the validation code of a parachain, as needed to register it on the relay chain.
+	ExportGenesisCode(export::ExportCmd),
+	/// Generate a raw chain spec from a state snapshot of a live chain, with its authorities
+	/// replaced by the ones of a local chain spec and the runtime of this repository.
+	Fork(fork::ForkCmd),
+	/// List the genesis presets of all embedded runtimes, together with their genesis config.
+	List(presets::ListCmd),
 }
 
 fn main() -> Result<(), String> {
 	let cli = Cli::parse();
 
-	let supported_chains =
-		HashMap::<_, Box<dyn Fn() -> Result<Box<dyn ChainSpec>, String>>>::from([
-			("polkadot-dev", Box::new(relay_chain_specs::polkadot_development_config) as Box<_>),
-			(
-				"polkadot-local",
-				Box::new(relay_chain_specs::polkadot_local_testnet_config) as Box<_>,
-			),
-			("kusama-dev", Box::new(relay_chain_specs::kusama_development_config) as Box<_>),
-			("kusama-local", Box::new(relay_chain_specs::kusama_local_testnet_config) as Box<_>),
-			(
-				"asset-hub-kusama-local",
-				Box::new(system_parachains_specs::asset_hub_kusama_local_testnet_config) as Box<_>,
-			),
-			(
-				"asset-hub-polkadot-local",
-				Box::new(system_parachains_specs::asset_hub_polkadot_local_testnet_config)
-					as Box<_>,
-			),
-			(
-				"collectives-polkadot-local",
-				Box::new(system_parachains_specs::collectives_polkadot_local_testnet_config)
-					as Box<_>,
-			),
-			(
-				"bridge-hub-polkadot-local",
-				Box::new(system_parachains_specs::bridge_hub_polkadot_local_testnet_config)
-					as Box<_>,
-			),
-			(
-				"bridge-hub-kusama-local",
-				Box::new(system_parachains_specs::bridge_hub_kusama_local_testnet_config) as Box<_>,
-			),
-			(
-				"glutton-kusama-local",
-				Box::new(system_parachains_specs::glutton_kusama_local_testnet_config) as Box<_>,
-			),
-			(
-				"encointer-kusama-local",
-				Box::new(system_parachains_specs::encointer_kusama_local_testnet_config) as Box<_>,
-			),
-			(
-				"coretime-kusama",
-				Box::new(system_parachains_specs::coretime_kusama_config) as Box<_>,
-			),
-			(
-				"coretime-kusama-local",
-				Box::new(system_parachains_specs::coretime_kusama_local_testnet_config) as Box<_>,
-			),
-			(
-				"coretime-polkadot",
-				Box::new(system_parachains_specs::coretime_polkadot_config) as Box<_>,
-			),
-			(
-				"coretime-polkadot-local",
-				Box::new(system_parachains_specs::coretime_polkadot_local_testnet_config) as Box<_>,
-			),
-			(
-				"people-kusama-local",
-				Box::new(system_parachains_specs::people_kusama_local_testnet_config) as Box<_>,
-			),
-			(
-				"people-polkadot-local",
-				Box::new(system_parachains_specs::people_polkadot_local_testnet_config) as Box<_>,
-			),
-		]);
-
-	if let Some(function) = supported_chains.get(&*cli.chain) {
-		let chain_spec = (*function)()?.as_json(cli.raw)?;
-		print!("{chain_spec}");
-		Ok(())
-	} else {
-		let supported = supported_chains.keys().enumerate().fold(String::new(), |c, (n, k)| {
-			let extra = if n + 1 < supported_chains.len() { ", " } else { "" };
-			format!("{c}{k}{extra}")
-		});
-		if cli.chain.ends_with(".json") {
-			let chain_spec = common::from_json_file(&cli.chain, supported)?.as_json(cli.raw)?;
-			print!("{chain_spec}");
+	match cli.command {
+		Some(Command::Bundle(cmd)) => bundle::run(cmd),
+		Some(Command::Diff(cmd)) => diff::run(cmd),
+		Some(Command::ExportGenesisHead(cmd)) => export::genesis_head(cmd),
+		Some(Command::ExportGenesisCode(cmd)) => export::genesis_code(cmd),
+		Some(Command::Fork(cmd)) => fork::run(cmd),
+		Some(Command::List(cmd)) => Ok(presets::list(cmd)?),
+		None => {
+			let chain = cli.chain.expect("`chain` is required without a subcommand; qed");
+			let chain_spec = match cli.patch {
+				Some(path) =>
+					patch::apply_patch(load_chain_spec(&chain)?, patch::load_patch(&path)?)?,
+				None => load_chain_spec(&chain)?,
+			};
+			let chain_spec = authorities::apply(chain_spec, &cli.authorities)?;
+
+			print!("{}", chain_spec.as_json(cli.raw)?);
 			Ok(())
-		} else {
-			Err(format!("Unknown chain, only supported: {supported} or a json file"))
-		}
+		},
+	}
+}
+
+/// Returns the chain spec of one of the built-in chains or runtime presets, or loads it from a
+/// json file.
+fn load_chain_spec(chain: &str) -> Result<Box<dyn ChainSpec>, String> {
+	if let Some((runtime, preset)) = chain.split_once(':') {
+		return Ok(presets::runtime(runtime)?.chain_spec(preset)?)
+	}
+
+	let supported = presets::runtimes().iter().map(|r| r.name).collect::<Vec<_>>().join(", ");
+
+	if let Some((runtime, preset)) = presets::resolve_chain_id(chain) {
+		Ok(runtime.chain_spec(preset)?)
+	} else if chain.ends_with(".json") {
+		Ok(common::from_json_file(chain, supported)?)
+	} else {
+		Err(format!(
+			"Unknown chain, only supported: `<runtime>-dev`, `<runtime>-local` or `<runtime>` \
+			 for the runtimes {supported}, a `<runtime>:<preset>` pair or a json file"
+		))
 	}
 }
@@ -0,0 +1,154 @@
+// Copyright (C) Parity Technologies and the various Polkadot contributors, see Contributions.md
+// for a list of specific contributors.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Applying user supplied genesis patches on top of the built-in presets.
+
+use crate::{system_parachains_specs::Extensions, ChainSpec};
+use sc_chain_spec::{GenericChainSpec, GenesisConfigBuilderRuntimeCaller, NoExtension};
+use serde_json::Value;
+use std::path::Path;
+
+/// Reads a genesis patch from a `.json` or `.toml` file.
+pub fn load_patch(path: &Path) -> Result<Value, String> {
+	let content = std::fs::read_to_string(path)
+		.map_err(|e| format!("Failed to read patch file {}: {e}", path.display()))?;
+
+	let patch = match path.extension().and_then(|e| e.to_str()) {
+		Some("json") => serde_json::from_str::<Value>(&content)
+			.map_err(|e| format!("Invalid json in patch file {}: {e}", path.display()))?,
+		Some("toml") => {
+			let value = toml::from_str::<toml::Value>(&content)
+				.map_err(|e| format!("Invalid toml in patch file {}: {e}", path.display()))?;
+			serde_json::to_value(value).map_err(|e| e.to_string())?
+		},
+		_ => return Err(format!("Patch file {} must be a .json or .toml file", path.display())),
+	};
+
+	if !patch.is_object() {
+		return Err(format!("Patch file {} must contain an object at its root", path.display()))
+	}
+
+	Ok(patch)
+}
+
+/// Checks that every key of `patch` is also present in `config`.
+///
+/// Only objects are descended into, everything else (arrays, numbers, `null` placeholders for
+/// optional fields) is replaced as a whole by the merge and thus not checked any further.
+pub fn ensure_known_keys(config: &Value, patch: &Value, path: &str) -> Result<(), String> {
+	let (Value::Object(config), Value::Object(patch)) = (config, patch) else { return Ok(()) };
+
+	for (key, value) in patch {
+		let key_path = if path.is_empty() { key.clone() } else { format!("{path}.{key}") };
+		let Some(config_value) = config.get(key) else {
+			let known = config.keys().cloned().collect::<Vec<_>>().join(", ");
+			return Err(format!(
+				"`{key_path}` does not exist in the runtime genesis config, expected one of: {known}"
+			))
+		};
+		ensure_known_keys(config_value, value, &key_path)?;
+	}
+
+	Ok(())
+}
+
+/// Returns the genesis config patch, or the full genesis config, of `chain_spec`.
+pub fn genesis(chain_spec: &dyn ChainSpec) -> Result<Value, String> {
+	let mut json = serde_json::from_str::<Value>(&chain_spec.as_json(false)?)
+		.map_err(|e| format!("Failed to parse chain spec: {e}"))?;
+	["/genesis/runtimeGenesis/patch", "/genesis/runtimeGenesis/config"]
+		.into_iter()
+		.find_map(|pointer| json.pointer_mut(pointer).map(Value::take))
+		.ok_or_else(|| "Chain spec genesis contains neither a `patch` nor a `config`".into())
+}
+
+/// Deep-merges `patch` into the genesis of `chain_spec`.
+///
+/// The keys of `patch` are checked against the runtime's default genesis config, so typos are
+/// reported instead of being silently dropped (or rejected later with a less helpful message).
+pub fn apply_patch(
+	chain_spec: Box<dyn ChainSpec>,
+	patch: Value,
+) -> Result<Box<dyn ChainSpec>, String> {
+	let mut json = serde_json::from_str::<Value>(&chain_spec.as_json(false)?)
+		.map_err(|e| format!("Failed to parse chain spec: {e}"))?;
+
+	let runtime_genesis = json
+		.pointer_mut("/genesis/runtimeGenesis")
+		.and_then(Value::as_object_mut)
+		.ok_or(
+			"Only chain specs with a runtime genesis can be patched, raw specs are not supported",
+		)?;
+
+	let code = runtime_genesis
+		.get("code")
+		.and_then(Value::as_str)
+		.ok_or("Chain spec does not contain the runtime code")?;
+	let code =
+		sp_core::bytes::from_hex(code).map_err(|e| format!("Invalid runtime code: {e:?}"))?;
+
+	let caller: GenesisConfigBuilderRuntimeCaller = GenesisConfigBuilderRuntimeCaller::new(&code);
+	ensure_known_keys(&caller.get_default_config()?, &patch, "")?;
+
+	let key = if runtime_genesis.contains_key("patch") { "patch" } else { "config" };
+	let genesis = runtime_genesis
+		.get_mut(key)
+		.ok_or("Chain spec genesis contains neither a `patch` nor a `config`")?;
+	sc_chain_spec::json_merge(genesis, patch);
+
+	let json = json.to_string().into_bytes();
+	if Extensions::try_get(&*chain_spec).is_some() {
+		Ok(Box::new(GenericChainSpec::<Extensions>::from_json_bytes(json)?))
+	} else {
+		Ok(Box::new(GenericChainSpec::<NoExtension>::from_json_bytes(json)?))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use serde_json::json;
+
+	#[test]
+	fn ensure_known_keys_works() {
+		let config = json!({
+			"balances": { "balances": [] },
+			"sudo": { "key": null },
+			"parachainInfo": { "parachainId": 1000 },
+		});
+
+		assert!(ensure_known_keys(&config, &json!({}), "").is_ok());
+		assert!(ensure_known_keys(
+			&config,
+			&json!({
+				"balances": { "balances": [["5Grw", 1]] },
+				"sudo": { "key": "5Grw" },
+				"parachainInfo": { "parachainId": 2000 },
+			}),
+			""
+		)
+		.is_ok());
+
+		assert_eq!(
+			ensure_known_keys(&config, &json!({ "parachainInfo": { "paraId": 2000 } }), ""),
+			Err("`parachainInfo.paraId` does not exist in the runtime genesis config, expected \
+				one of: parachainId"
+				.into())
+		);
+		assert!(ensure_known_keys(&config, &json!({ "system": {} }), "").is_err());
+	}
+}
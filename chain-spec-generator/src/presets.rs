@@ -0,0 +1,402 @@
+// Copyright (C) Parity Technologies and the various Polkadot contributors, see Contributions.md
+// for a list of specific contributors.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Discovering the genesis presets of the embedded runtimes through their `GenesisBuilder`.
+
+use crate::{common::Error, system_parachains_specs::Extensions, ChainSpec};
+use sc_chain_spec::{ChainType, GenericChainSpec, GenesisConfigBuilderRuntimeCaller, NoExtension};
+use sc_network::config::MultiaddrWithPeerId;
+use std::str::FromStr;
+
+/// The presets with a well-known chain id, together with the suffixes of their chain id and name,
+/// e.g. `polkadot-local` for the `local_testnet` preset of `polkadot`.
+const WELL_KNOWN_PRESETS: [(&str, &str, &str); 3] = [
+	("development", "-dev", " Development"),
+	("local_testnet", "-local", " Local"),
+	("live", "", ""),
+];
+
+const CORETIME_POLKADOT_BOOT_NODES: &[&str] = &[
+	"/dns/polkadot-coretime-connect-a-0.polkadot.io/tcp/30334/p2p/12D3KooWKjnixAHbKMsPTJwGx8SrBeGEJLHA8KmKcEDYMp3YmWgR",
+	"/dns/polkadot-coretime-connect-a-1.polkadot.io/tcp/30334/p2p/12D3KooWQ7B7p4DFv1jWqaKfhrZBcMmi5g8bWFnmskguLaGEmT6n",
+	"/dns/polkadot-coretime-connect-a-0.polkadot.io/tcp/443/wss/p2p/12D3KooWKjnixAHbKMsPTJwGx8SrBeGEJLHA8KmKcEDYMp3YmWgR",
+	"/dns/polkadot-coretime-connect-a-1.polkadot.io/tcp/443/wss/p2p/12D3KooWQ7B7p4DFv1jWqaKfhrZBcMmi5g8bWFnmskguLaGEmT6n",
+	"/dns4/coretime-polkadot.boot.stake.plus/tcp/30332/wss/p2p/12D3KooWFJ2yBTKFKYwgKUjfY3F7XfaxHV8hY6fbJu5oMkpP7wZ9",
+	"/dns4/coretime-polkadot.boot.stake.plus/tcp/31332/wss/p2p/12D3KooWCy5pToLafcQzPHn5kadxAftmF6Eh8ZJGPXhSeXSUDfjv",
+];
+
+const CORETIME_KUSAMA_BOOT_NODES: &[&str] = &[
+	"/dns/kusama-coretime-connect-a-0.polkadot.io/tcp/30334/p2p/12D3KooWR7Biy6nPgQFhk2eYP62pAkcFA6he9RUFURTDh7ewTjpo",
+	"/dns/kusama-coretime-connect-a-1.polkadot.io/tcp/30334/p2p/12D3KooWAGFiMZDF9RxdacrkenzGdo8nhfSe9EXofHc5mHeJ9vGX",
+	"/dns/kusama-coretime-connect-a-0.polkadot.io/tcp/443/wss/p2p/12D3KooWR7Biy6nPgQFhk2eYP62pAkcFA6he9RUFURTDh7ewTjpo",
+	"/dns/kusama-coretime-connect-a-1.polkadot.io/tcp/443/wss/p2p/12D3KooWAGFiMZDF9RxdacrkenzGdo8nhfSe9EXofHc5mHeJ9vGX",
+];
+
+/// Returns the chain id of the chain spec of the runtime `runtime` with the preset `preset`.
+fn chain_id(runtime: &str, preset: &str) -> String {
+	match WELL_KNOWN_PRESETS.iter().find(|(p, ..)| *p == preset) {
+		Some((_, suffix, _)) => format!("{runtime}{suffix}"),
+		None => format!("{runtime}-{preset}"),
+	}
+}
+
+/// The properties of the chain specs of a runtime, each only set if given.
+struct Properties {
+	ss58_format: Option<u16>,
+	token_symbol: Option<&'static str>,
+	token_decimals: Option<u8>,
+}
+
+impl Properties {
+	const POLKADOT: Self =
+		Self { ss58_format: Some(0), token_symbol: Some("DOT"), token_decimals: Some(10) };
+	const KUSAMA: Self =
+		Self { ss58_format: Some(2), token_symbol: Some("KSM"), token_decimals: Some(12) };
+
+	fn to_properties(&self) -> sc_chain_spec::Properties {
+		let mut properties = sc_chain_spec::Properties::new();
+		if let Some(ss58_format) = self.ss58_format {
+			properties.insert("ss58Format".into(), ss58_format.into());
+		}
+		if let Some(symbol) = self.token_symbol {
+			properties.insert("tokenSymbol".into(), symbol.into());
+		}
+		if let Some(decimals) = self.token_decimals {
+			properties.insert("tokenDecimals".into(), decimals.into());
+		}
+		properties
+	}
+}
+
+/// A runtime embedded into the generator.
+pub struct Runtime {
+	/// The name of the runtime, as used on the command line.
+	pub name: &'static str,
+	/// The human readable name of the chain.
+	display_name: &'static str,
+	wasm: Option<&'static [u8]>,
+	/// The relay chain and para id, if this is a parachain runtime.
+	parachain: Option<(&'static str, u32)>,
+	properties: Properties,
+	/// The names of the chain specs of the presets not named `<display_name><suffix>`.
+	names: &'static [(&'static str, &'static str)],
+	protocol_id: Option<&'static str>,
+	/// The boot nodes of the `live` preset.
+	boot_nodes: &'static [&'static str],
+}
+
+impl Runtime {
+	fn wasm(&self) -> Result<&'static [u8], Error> {
+		self.wasm.ok_or(Error::WasmNotAvailable(self.name))
+	}
+
+	/// Returns the names of the genesis presets the runtime provides.
+	pub fn preset_names(&self) -> Result<Vec<String>, Error> {
+		let caller: GenesisConfigBuilderRuntimeCaller =
+			GenesisConfigBuilderRuntimeCaller::new(self.wasm()?);
+		Ok(caller
+			.preset_names()
+			.map_err(Error::ChainSpec)?
+			.iter()
+			.map(|preset| {
+				TryInto::<&str>::try_into(preset).unwrap_or("<non utf-8 preset id>").to_string()
+			})
+			.collect())
+	}
+
+	/// Returns the genesis config patch of the preset `preset`.
+	///
+	/// The runtime may provide presets it doesn't list, e.g. the `live` preset of
+	/// `coretime-kusama`.
+	pub fn preset(&self, preset: &str) -> Result<serde_json::Value, Error> {
+		let caller: GenesisConfigBuilderRuntimeCaller =
+			GenesisConfigBuilderRuntimeCaller::new(self.wasm()?);
+		match caller.get_named_preset(Some(&preset.to_string())) {
+			Ok(patch) => Ok(patch),
+			Err(_) => Err(Error::UnknownPreset {
+				runtime: self.name.into(),
+				preset: preset.into(),
+				available: self.preset_names()?,
+			}),
+		}
+	}
+
+	/// Returns a chain spec using the genesis preset `preset`.
+	///
+	/// The chain spec of a parachain names the relay chain spec of the same preset, e.g.
+	/// `kusama-dev` for the `development` preset of `asset-hub-kusama`.
+	pub fn chain_spec(&self, preset: &str) -> Result<Box<dyn ChainSpec>, Error> {
+		let patch = self.preset(preset)?;
+
+		let chain_type = match preset {
+			"development" => ChainType::Development,
+			"live" => ChainType::Live,
+			_ => ChainType::Local,
+		};
+		let properties = self.properties.to_properties();
+		let name = match self.names.iter().find(|(p, _)| *p == preset) {
+			Some((_, name)) => name.to_string(),
+			None => match WELL_KNOWN_PRESETS.iter().find(|(p, ..)| *p == preset) {
+				Some((.., suffix)) => format!("{}{suffix}", self.display_name),
+				None => format!("{} {preset}", self.display_name),
+			},
+		};
+		let id = chain_id(self.name, preset);
+		let boot_nodes = if preset == "live" {
+			self.boot_nodes
+				.iter()
+				.map(|addr| {
+					MultiaddrWithPeerId::from_str(addr).expect("Boot node address is incorrect.")
+				})
+				.collect()
+		} else {
+			Vec::new()
+		};
+
+		Ok(match self.parachain {
+			Some((relay, para_id)) => {
+				let relay_chain = chain_id(relay, preset);
+				Box::new(
+					GenericChainSpec::<Extensions>::builder(
+						self.wasm()?,
+						Extensions { relay_chain, para_id },
+					)
+					.with_name(&name)
+					.with_id(&id)
+					.with_chain_type(chain_type)
+					.with_genesis_config_patch(patch)
+					.with_properties(properties)
+					.with_boot_nodes(boot_nodes)
+					.build(),
+				)
+			},
+			None => {
+				let mut builder =
+					GenericChainSpec::<NoExtension>::builder(self.wasm()?, Default::default())
+						.with_name(&name)
+						.with_id(&id)
+						.with_chain_type(chain_type)
+						.with_genesis_config_patch(patch)
+						.with_properties(properties)
+						.with_boot_nodes(boot_nodes);
+				if let Some(protocol_id) = self.protocol_id {
+					builder = builder.with_protocol_id(protocol_id);
+				}
+				Box::new(builder.build())
+			},
+		})
+	}
+}
+
+/// All runtimes embedded into the generator.
+pub fn runtimes() -> Vec<Runtime> {
+	Vec::from([
+		Runtime {
+			name: "polkadot",
+			display_name: "Polkadot",
+			wasm: polkadot_runtime::WASM_BINARY,
+			parachain: None,
+			properties: Properties {
+				ss58_format: None,
+				token_symbol: None,
+				token_decimals: Some(10),
+			},
+			// The names of the relay chain specs from before the presets, typo included.
+			names: &[
+				("development", "Polakdot Development"),
+				("local_testnet", "Polkadot Local Testnet"),
+			],
+			protocol_id: Some("dot"),
+			boot_nodes: &[],
+		},
+		Runtime {
+			name: "kusama",
+			display_name: "Kusama",
+			wasm: kusama_runtime::WASM_BINARY,
+			parachain: None,
+			properties: Properties { ss58_format: None, token_symbol: None, token_decimals: None },
+			names: &[("local_testnet", "Kusama Local Testnet")],
+			protocol_id: Some("dot"),
+			boot_nodes: &[],
+		},
+		Runtime {
+			name: "asset-hub-polkadot",
+			display_name: "Polkadot Asset Hub",
+			wasm: asset_hub_polkadot_runtime::WASM_BINARY,
+			parachain: Some(("polkadot", 1000)),
+			properties: Properties::POLKADOT,
+			names: &[],
+			protocol_id: None,
+			boot_nodes: &[],
+		},
+		Runtime {
+			name: "asset-hub-kusama",
+			display_name: "Kusama Asset Hub",
+			wasm: asset_hub_kusama_runtime::WASM_BINARY,
+			parachain: Some(("kusama", 1000)),
+			properties: Properties::KUSAMA,
+			names: &[],
+			protocol_id: None,
+			boot_nodes: &[],
+		},
+		Runtime {
+			name: "collectives-polkadot",
+			display_name: "Polkadot Collectives",
+			wasm: collectives_polkadot_runtime::WASM_BINARY,
+			parachain: Some(("polkadot", 1001)),
+			properties: Properties::POLKADOT,
+			names: &[],
+			protocol_id: None,
+			boot_nodes: &[],
+		},
+		Runtime {
+			name: "bridge-hub-polkadot",
+			display_name: "Polkadot Bridge Hub",
+			wasm: bridge_hub_polkadot_runtime::WASM_BINARY,
+			parachain: Some(("polkadot", 1002)),
+			properties: Properties::POLKADOT,
+			names: &[],
+			protocol_id: None,
+			boot_nodes: &[],
+		},
+		Runtime {
+			name: "bridge-hub-kusama",
+			display_name: "Kusama Bridge Hub",
+			wasm: bridge_hub_kusama_runtime::WASM_BINARY,
+			parachain: Some(("kusama", 1002)),
+			properties: Properties::KUSAMA,
+			names: &[],
+			protocol_id: None,
+			boot_nodes: &[],
+		},
+		Runtime {
+			name: "encointer-kusama",
+			display_name: "Kusama Encointer",
+			wasm: encointer_kusama_runtime::WASM_BINARY,
+			parachain: Some(("kusama", 1001)),
+			properties: Properties::KUSAMA,
+			names: &[],
+			protocol_id: None,
+			boot_nodes: &[],
+		},
+		Runtime {
+			name: "glutton-kusama",
+			display_name: "Kusama Glutton",
+			wasm: glutton_kusama_runtime::WASM_BINARY,
+			parachain: Some(("kusama", 1300)),
+			properties: Properties {
+				ss58_format: Some(2),
+				token_symbol: None,
+				token_decimals: None,
+			},
+			names: &[],
+			protocol_id: None,
+			boot_nodes: &[],
+		},
+		Runtime {
+			name: "coretime-polkadot",
+			display_name: "Polkadot Coretime",
+			wasm: coretime_polkadot_runtime::WASM_BINARY,
+			parachain: Some(("polkadot", 1005)),
+			properties: Properties::POLKADOT,
+			names: &[],
+			protocol_id: None,
+			boot_nodes: CORETIME_POLKADOT_BOOT_NODES,
+		},
+		Runtime {
+			name: "coretime-kusama",
+			display_name: "Kusama Coretime",
+			wasm: coretime_kusama_runtime::WASM_BINARY,
+			parachain: Some(("kusama", 1005)),
+			properties: Properties::KUSAMA,
+			names: &[],
+			protocol_id: None,
+			boot_nodes: CORETIME_KUSAMA_BOOT_NODES,
+		},
+		Runtime {
+			name: "people-polkadot",
+			display_name: "Polkadot People",
+			wasm: people_polkadot_runtime::WASM_BINARY,
+			parachain: Some(("polkadot", 1004)),
+			properties: Properties::POLKADOT,
+			names: &[],
+			protocol_id: None,
+			boot_nodes: &[],
+		},
+		Runtime {
+			name: "people-kusama",
+			display_name: "Kusama People",
+			wasm: people_kusama_runtime::WASM_BINARY,
+			parachain: Some(("kusama", 1004)),
+			properties: Properties::KUSAMA,
+			names: &[],
+			protocol_id: None,
+			boot_nodes: &[],
+		},
+	])
+}
+
+/// Returns the runtime and preset of the well-known chain id `id`, e.g. `asset-hub-kusama-local`.
+pub fn resolve_chain_id(id: &str) -> Option<(Runtime, &'static str)> {
+	runtimes().into_iter().find_map(|runtime| {
+		WELL_KNOWN_PRESETS
+			.iter()
+			.find(|(preset, ..)| chain_id(runtime.name, preset) == id)
+			.map(|(preset, ..)| (runtime, *preset))
+	})
+}
+
+/// Returns the runtime called `name`.
+pub fn runtime(name: &str) -> Result<Runtime, Error> {
+	let runtimes = runtimes();
+	let available = runtimes.iter().map(|r| r.name.to_string()).collect::<Vec<_>>();
+	runtimes
+		.into_iter()
+		.find(|r| r.name == name)
+		.ok_or(Error::UnknownRuntime { runtime: name.into(), available })
+}
+
+#[derive(clap::Args)]
+pub struct ListCmd {
+	/// Only list the presets of this runtime.
+	#[arg(long)]
+	runtime: Option<String>,
+}
+
+/// Prints the genesis config patches of all presets, keyed by runtime and preset name.
+pub fn list(cmd: ListCmd) -> Result<(), Error> {
+	let runtimes = match cmd.runtime {
+		Some(name) => Vec::from([runtime(&name)?]),
+		None => runtimes(),
+	};
+
+	let mut presets = serde_json::Map::new();
+	for runtime in runtimes {
+		let patches = runtime
+			.preset_names()?
+			.into_iter()
+			.map(|preset| Ok((preset.clone(), runtime.preset(&preset)?)))
+			.collect::<Result<serde_json::Map<_, _>, Error>>()?;
+		presets.insert(runtime.name.into(), patches.into());
+	}
+
+	print!(
+		"{}",
+		serde_json::to_string_pretty(&presets).map_err(|e| Error::ChainSpec(e.to_string()))?
+	);
+	Ok(())
+}
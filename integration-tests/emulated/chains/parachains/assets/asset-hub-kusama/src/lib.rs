@@ -48,6 +48,7 @@ decl_test_parachains! {
 			PoolAssets: asset_hub_kusama_runtime::PoolAssets,
 			AssetConversion: asset_hub_kusama_runtime::AssetConversion,
 			Balances: asset_hub_kusama_runtime::Balances,
+			RelayCalls: asset_hub_kusama_runtime::RelayCalls,
 		}
 	},
 }
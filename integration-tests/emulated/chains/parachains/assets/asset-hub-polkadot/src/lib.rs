@@ -48,6 +48,7 @@ decl_test_parachains! {
 			ForeignAssets: asset_hub_polkadot_runtime::ForeignAssets,
 			PoolAssets: asset_hub_polkadot_runtime::PoolAssets,
 			AssetConversion: asset_hub_polkadot_runtime::AssetConversion,
+			RelayCalls: asset_hub_polkadot_runtime::RelayCalls,
 		}
 	},
 }
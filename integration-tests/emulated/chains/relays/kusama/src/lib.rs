@@ -38,6 +38,13 @@ decl_test_relay_chains! {
 			Hrmp: kusama_runtime::Hrmp,
 			Treasury: kusama_runtime::Treasury,
 			AssetRate: kusama_runtime::AssetRate,
+			Nis: kusama_runtime::Nis,
+			NisCounterpartBalances: kusama_runtime::NisCounterpartBalances,
+			Society: kusama_runtime::Society,
+			Preimage: kusama_runtime::Preimage,
+			Paras: kusama_runtime::Paras,
+			Registrar: kusama_runtime::Registrar,
+			Initializer: kusama_runtime::Initializer,
 		}
 	},
 }
@@ -126,6 +126,10 @@ pub fn genesis() -> Storage {
 			..Default::default()
 		},
 		configuration: polkadot_runtime::ConfigurationConfig { config: get_host_config() },
+		registrar: polkadot_runtime::RegistrarConfig {
+			next_free_para_id: polkadot_primitives::LOWEST_PUBLIC_ID,
+			..Default::default()
+		},
 		..Default::default()
 	};
 
@@ -38,6 +38,10 @@ decl_test_relay_chains! {
 			Treasury: polkadot_runtime::Treasury,
 			AssetRate: polkadot_runtime::AssetRate,
 			Hrmp: polkadot_runtime::Hrmp,
+			Preimage: polkadot_runtime::Preimage,
+			Paras: polkadot_runtime::Paras,
+			Registrar: polkadot_runtime::Registrar,
+			Initializer: polkadot_runtime::Initializer,
 		}
 	},
 }
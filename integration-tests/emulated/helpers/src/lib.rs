@@ -16,18 +16,32 @@
 pub use paste;
 
 // Substrate
+pub use frame_support;
 pub use pallet_balances;
 pub use pallet_message_queue;
+pub use pallet_session;
+pub use sp_runtime;
 
 // Polkadot
 pub use pallet_xcm;
+pub use polkadot_parachain_primitives;
+pub use polkadot_runtime_common;
+pub use runtime_parachains;
 pub use xcm::prelude::{AccountId32, VersionedAssets, Weight, WeightLimit};
+pub use xcm_runtime_apis;
 
 // Cumulus
 pub use asset_test_utils;
+pub use cumulus_pallet_parachain_system;
 pub use cumulus_pallet_xcmp_queue;
+pub use emulated_integration_tests_common;
 pub use xcm_emulator::Chain;
 
+// Local
+pub use pallet_para_channels;
+pub use pallet_para_onboarding;
+pub use pallet_relay_calls;
+
 /// TODO: when bumping to polkadot-sdk v1.8.0,
 /// remove this crate altogether and get the macros from `emulated-integration-tests-common`.
 /// TODO: backport this macros to polkadot-sdk
@@ -404,3 +418,928 @@ macro_rules! test_chain_can_claim_assets {
 		}
 	};
 }
+
+/// The balances locked by `pallet-xcm` for `who` on `$para`.
+#[macro_export]
+macro_rules! xcm_locks {
+	( $para:ty, $who:expr ) => {
+		<$para>::execute_with(|| {
+			// The identifier of the balances lock set by `pallet-xcm`.
+			const XCM_LOCK_ID: [u8; 8] = *b"py/xcmlk";
+
+			$crate::pallet_balances::Locks::<<$para as $crate::Chain>::Runtime>::get($who)
+				.into_iter()
+				.filter(|lock| lock.id == XCM_LOCK_ID)
+				.map(|lock| lock.amount)
+				.collect::<Vec<_>>()
+		})
+	};
+}
+
+/// The Relay Chain token held on `$para` can be locked in favour of `$relay`, which records the
+/// lock, and `$relay` can release it again on request of its owner.
+#[macro_export]
+macro_rules! test_relay_token_can_be_locked_in_favour_of_relay {
+	( $para:ty, $relay:ty, $para_ed:expr, $relay_ed:expr ) => {
+		$crate::paste::paste! {
+			let sender = [<$para Sender>]::get();
+			let lock_amount = $para_ed * 1_000;
+			let fees = $para_ed * 100;
+
+			// The sender as seen from the Relay Chain, which pays for the unlock request from there.
+			let owner_on_relay = Location::new(
+				0,
+				[
+					Parachain(<$para>::para_id().into()),
+					$crate::AccountId32 { network: None, id: sender.clone().into() },
+				],
+			);
+			<$relay>::fund_accounts(vec![(
+				<$relay>::sovereign_account_id_of(owner_on_relay.clone()),
+				$relay_ed * 1_000,
+			)]);
+
+			// Lock the token on `$para` in favour of the Relay Chain, paying for the
+			// `NoteUnlockable`.
+			<$para>::execute_with(|| {
+				type RuntimeEvent = <$para as $crate::Chain>::RuntimeEvent;
+
+				let xcm = Xcm::<<$para as $crate::Chain>::RuntimeCall>(vec![
+					WithdrawAsset((Parent, fees).into()),
+					LockAsset { asset: (Parent, lock_amount).into(), unlocker: Parent.into() },
+					DepositAsset {
+						assets: Wild(AllCounted(1)),
+						beneficiary: $crate::AccountId32 { network: None, id: sender.clone().into() }
+							.into(),
+					},
+				]);
+				assert_ok!(<$para as [<$para Pallet>]>::PolkadotXcm::execute(
+					<$para as $crate::Chain>::RuntimeOrigin::signed(sender.clone()),
+					bx!(VersionedXcm::from(xcm)),
+					$crate::Weight::from_parts(10_000_000_000, 500_000),
+				));
+
+				assert_expected_events!(
+					$para,
+					vec![
+						RuntimeEvent::PolkadotXcm(
+							$crate::pallet_xcm::Event::Attempted { outcome: Outcome::Complete { .. } }
+						) => {},
+						RuntimeEvent::ParachainSystem(
+							$crate::cumulus_pallet_parachain_system::Event::UpwardMessageSent { .. }
+						) => {},
+					]
+				);
+			});
+			assert_eq!($crate::xcm_locks!($para, &sender), vec![lock_amount]);
+
+			// The Relay Chain records the lock.
+			<$relay>::execute_with(|| {
+				type RuntimeEvent = <$relay as $crate::Chain>::RuntimeEvent;
+
+				assert_expected_events!(
+					$relay,
+					vec![
+						RuntimeEvent::MessageQueue(
+							$crate::pallet_message_queue::Event::Processed { success: true, .. }
+						) => {},
+					]
+				);
+			});
+
+			// The owner asks the Relay Chain to release the lock, which only works for a recorded
+			// lock.
+			<$para>::execute_with(|| {
+				let relay_fees: Asset = (Here, $relay_ed * 100).into();
+				let xcm = Xcm::<()>(vec![
+					WithdrawAsset(relay_fees.clone().into()),
+					BuyExecution { fees: relay_fees, weight_limit: Unlimited },
+					RequestUnlock {
+						asset: (Here, lock_amount).into(),
+						locker: Parachain(<$para>::para_id().into()).into(),
+					},
+					RefundSurplus,
+					DepositAsset { assets: Wild(AllCounted(1)), beneficiary: owner_on_relay.clone() },
+				]);
+				assert_ok!(<$para as [<$para Pallet>]>::PolkadotXcm::send(
+					<$para as $crate::Chain>::RuntimeOrigin::signed(sender.clone()),
+					bx!(VersionedLocation::from(Location::parent())),
+					bx!(VersionedXcm::from(xcm)),
+				));
+			});
+
+			<$relay>::execute_with(|| {
+				type RuntimeEvent = <$relay as $crate::Chain>::RuntimeEvent;
+
+				assert_expected_events!(
+					$relay,
+					vec![
+						RuntimeEvent::MessageQueue(
+							$crate::pallet_message_queue::Event::Processed { success: true, .. }
+						) => {},
+						RuntimeEvent::XcmPallet($crate::pallet_xcm::Event::Sent { .. }) => {},
+					]
+				);
+			});
+
+			// `$para` releases the lock on the Relay Chain's request.
+			<$para>::execute_with(|| {
+				type RuntimeEvent = <$para as $crate::Chain>::RuntimeEvent;
+
+				assert_expected_events!(
+					$para,
+					vec![
+						RuntimeEvent::MessageQueue(
+							$crate::pallet_message_queue::Event::Processed { success: true, .. }
+						) => {},
+					]
+				);
+			});
+			assert!($crate::xcm_locks!($para, &sender).is_empty());
+		}
+	};
+}
+
+/// `$relay` does not accept unlock requests from `$para` for locks it was never notified of.
+#[macro_export]
+macro_rules! test_relay_rejects_unlock_request_without_lock {
+	( $para:ty, $relay:ty, $para_ed:expr, $relay_ed:expr ) => {
+		$crate::paste::paste! {
+			let sender = [<$para Sender>]::get();
+			let owner_on_relay = Location::new(
+				0,
+				[
+					Parachain(<$para>::para_id().into()),
+					$crate::AccountId32 { network: None, id: sender.clone().into() },
+				],
+			);
+			<$relay>::fund_accounts(vec![(
+				<$relay>::sovereign_account_id_of(owner_on_relay.clone()),
+				$relay_ed * 1_000,
+			)]);
+
+			<$para>::execute_with(|| {
+				let relay_fees: Asset = (Here, $relay_ed * 100).into();
+				let xcm = Xcm::<()>(vec![
+					WithdrawAsset(relay_fees.clone().into()),
+					BuyExecution { fees: relay_fees, weight_limit: Unlimited },
+					RequestUnlock {
+						asset: (Here, $para_ed * 1_000).into(),
+						locker: Parachain(<$para>::para_id().into()).into(),
+					},
+				]);
+				assert_ok!(<$para as [<$para Pallet>]>::PolkadotXcm::send(
+					<$para as $crate::Chain>::RuntimeOrigin::signed(sender.clone()),
+					bx!(VersionedLocation::from(Location::parent())),
+					bx!(VersionedXcm::from(xcm)),
+				));
+			});
+
+			<$relay>::execute_with(|| {
+				type RuntimeEvent = <$relay as $crate::Chain>::RuntimeEvent;
+
+				assert_expected_events!(
+					$relay,
+					vec![
+						RuntimeEvent::MessageQueue(
+							$crate::pallet_message_queue::Event::Processed { success: false, .. }
+						) => {},
+					]
+				);
+			});
+		}
+	};
+}
+
+/// Go through the sessions it takes `$relay` to onboard the paras registered before, with the same
+/// validators.
+#[macro_export]
+macro_rules! onboard_registered_paras {
+	( $relay:ty ) => {
+		$crate::paste::paste! {
+			<$relay>::execute_with(|| {
+				use $crate::frame_support::traits::{OnFinalize, OneSessionHandler};
+				use $crate::runtime_parachains::shared;
+				type Runtime = <$relay as $crate::Chain>::Runtime;
+				type Initializer = <$relay as [<$relay Pallet>]>::Initializer;
+
+				let account = $crate::sp_runtime::AccountId32::new([0; 32]);
+				let keys = shared::ActiveValidatorKeys::<Runtime>::get();
+				let validators = || keys.iter().map(|key| (&account, key.clone()));
+				let onboarded = shared::Pallet::<Runtime>::scheduled_session();
+				while shared::CurrentSessionIndex::<Runtime>::get() < onboarded {
+					$crate::pallet_session::CurrentIndex::<Runtime>::mutate(|index| *index += 1);
+					Initializer::on_new_session(true, validators(), validators());
+					// Hooks don't run in emulated tests - the session change is applied when the
+					// block is finalized.
+					Initializer::on_finalize(<$relay as $crate::Chain>::System::block_number());
+				}
+			});
+		}
+	};
+}
+
+/// Register the paras `$para_ids` on `$relay` as on-demand parachains.
+///
+/// Their validation code is trusted, so that it needs no pre-checking by the validators.
+#[macro_export]
+macro_rules! register_on_demand_paras {
+	( $relay:ty, $para_ids:expr ) => {
+		$crate::paste::paste! {
+			let validation_code =
+				$crate::polkadot_parachain_primitives::primitives::ValidationCode(vec![1; 1024]);
+			<$relay>::execute_with(|| {
+				use $crate::polkadot_parachain_primitives::primitives::HeadData;
+
+				let root = <$relay as $crate::Chain>::RuntimeOrigin::root();
+
+				assert_ok!(<$relay as [<$relay Pallet>]>::Paras::add_trusted_validation_code(
+					root.clone(),
+					validation_code.clone(),
+				));
+				for para_id in $para_ids {
+					assert_ok!(<$relay as [<$relay Pallet>]>::Registrar::force_register(
+						root.clone(),
+						[<$relay Sender>]::get(),
+						0,
+						para_id,
+						HeadData(vec![0; 32]),
+						validation_code.clone(),
+					));
+				}
+			});
+			$crate::onboard_registered_paras!($relay);
+			<$relay>::execute_with(|| {
+				type Runtime = <$relay as $crate::Chain>::Runtime;
+
+				for para_id in $para_ids {
+					assert!($crate::runtime_parachains::paras::Pallet::<Runtime>::is_parathread(
+						para_id
+					));
+				}
+			});
+		}
+	};
+}
+
+/// An account on `$asset_hub` can place an on-demand order on `$relay` for `$para` with the Relay
+/// Chain token it holds there, with the fees estimated beforehand by dry-running the order.
+///
+/// `$on_demand` is the name of the on-demand pallet in the runtime of `$relay`.
+#[macro_export]
+macro_rules! test_on_demand_order_can_be_placed_from_asset_hub {
+	( $asset_hub:ty, $relay:ty, $para:ty, $network:expr, $on_demand:ident, $units:expr ) => {
+		$crate::paste::paste! {
+			use $crate::frame_support::{
+				storage::{with_transaction, TransactionOutcome},
+				traits::fungible::Inspect as _,
+			};
+			use $crate::xcm_runtime_apis::{
+				dry_run::runtime_decl_for_dry_run_api::DryRunApiV1,
+				fees::runtime_decl_for_xcm_payment_api::XcmPaymentApiV1,
+			};
+
+			let buyer = <$asset_hub>::account_id_of("OnDemandBuyer");
+			let origin: Location =
+				$crate::AccountId32 { network: Some($network), id: buyer.clone().into() }.into();
+			let para_id = <$para>::para_id();
+			let max_amount = 10 * $units;
+			<$asset_hub>::fund_accounts(vec![(buyer.clone(), 100 * $units)]);
+			<$relay>::fund_accounts(vec![(
+				<$relay as [<$relay Pallet>]>::XcmPallet::check_account(),
+				100 * $units,
+			)]);
+
+			// `$para` is not known to the Relay Chain, make it an on-demand parachain.
+			$crate::register_on_demand_paras!($relay, [para_id]);
+
+			// The message sent to the Relay Chain when ordering with `fees`, from a dry-run of the
+			// order.
+			let order_xcm = |fees| {
+				<$asset_hub>::execute_with(|| {
+					type Runtime = <$asset_hub as $crate::Chain>::Runtime;
+					type OriginCaller = <$asset_hub as $crate::Chain>::OriginCaller;
+
+					let call = <$asset_hub as $crate::Chain>::RuntimeCall::RelayCalls(
+						$crate::pallet_relay_calls::Call::place_on_demand_order {
+							para_id,
+							max_amount,
+							fees,
+						},
+					);
+					let origin = OriginCaller::system(RawOrigin::Signed(buyer.clone()));
+					// Clients dry-run on a state they discard, the effects of the dry-run are
+					// rolled back.
+					let result = with_transaction(|| {
+						TransactionOutcome::Rollback(Ok::<_, DispatchError>(Runtime::dry_run_call(
+							origin, call,
+						)))
+					})
+					.unwrap()
+					.unwrap();
+					assert_ok!(result.execution_result);
+					let (_, messages) = result
+						.forwarded_xcms
+						.into_iter()
+						.find(|(destination, _)| {
+							*destination == VersionedLocation::from(Location::parent())
+						})
+						.unwrap();
+					assert_eq!(messages.len(), 1);
+					messages[0].clone()
+				})
+			};
+
+			// The weight of the message does not depend on the fees it carries.
+			let message = order_xcm(0);
+			let execution_fees = <$relay>::execute_with(|| {
+				type Runtime = <$relay as $crate::Chain>::Runtime;
+
+				let weight = Runtime::query_xcm_weight(message).unwrap();
+				Runtime::query_weight_to_asset_fee(
+					weight,
+					VersionedAssetId::from(AssetId(Here.into())),
+				)
+				.unwrap()
+			});
+			let message = order_xcm(execution_fees);
+			let delivery_fees = <$asset_hub>::execute_with(|| {
+				type Runtime = <$asset_hub as $crate::Chain>::Runtime;
+
+				let fees = Runtime::query_delivery_fees(
+					VersionedLocation::from(Location::parent()),
+					message,
+				)
+				.unwrap();
+				let fees: Assets = fees.try_into().unwrap();
+				let Fungible(amount) = fees.inner()[0].fun else {
+					unreachable!("asset is non-fungible");
+				};
+				amount
+			});
+
+			<$asset_hub>::execute_with(|| {
+				type RuntimeEvent = <$asset_hub as $crate::Chain>::RuntimeEvent;
+				type Balances = <$asset_hub as [<$asset_hub Pallet>]>::Balances;
+
+				let balance_before = Balances::balance(&buyer);
+				assert_ok!(<$asset_hub as [<$asset_hub Pallet>]>::RelayCalls::place_on_demand_order(
+					<$asset_hub as $crate::Chain>::RuntimeOrigin::signed(buyer.clone()),
+					para_id,
+					max_amount,
+					execution_fees,
+				));
+
+				assert_expected_events!(
+					$asset_hub,
+					vec![
+						RuntimeEvent::RelayCalls(
+							$crate::pallet_relay_calls::Event::Sent { origin: o, amount, .. }
+						) => {
+							o: *o == origin,
+							amount: *amount == max_amount + execution_fees,
+						},
+					]
+				);
+				assert_eq!(
+					Balances::balance(&buyer),
+					balance_before - max_amount - execution_fees - delivery_fees
+				);
+			});
+
+			<$relay>::execute_with(|| {
+				type RuntimeEvent = <$relay as $crate::Chain>::RuntimeEvent;
+				type Balances = <$relay as [<$relay Pallet>]>::Balances;
+
+				assert_expected_events!(
+					$relay,
+					vec![
+						RuntimeEvent::$on_demand(
+							$crate::runtime_parachains::on_demand::Event::OnDemandOrderPlaced {
+								para_id: p,
+								..
+							}
+						) => {
+							p: *p == para_id,
+						},
+						RuntimeEvent::MessageQueue(
+							$crate::pallet_message_queue::Event::Processed { success: true, .. }
+						) => {},
+					]
+				);
+				// The rest of the funds stays with the buyer on the Relay Chain.
+				let balance = Balances::balance(&buyer);
+				assert!(balance > 0 && balance < max_amount + execution_fees);
+			});
+		}
+	};
+}
+
+/// The weights `$asset_hub` buys on `$relay` for the calls it dispatches there on behalf of its
+/// accounts and sibling paras cover the weights of these calls on `$relay`.
+#[macro_export]
+macro_rules! test_relay_call_weights_cover_relay_dispatch_weights {
+	( $asset_hub:ty, $relay:ty ) => {
+		$crate::paste::paste! {
+			use $crate::frame_support::{dispatch::GetDispatchInfo, traits::Get};
+			use $crate::pallet_relay_calls::Config;
+			use $crate::polkadot_parachain_primitives::primitives::{HeadData, Id as ParaId};
+			use $crate::runtime_parachains::{
+				configuration::ActiveConfig,
+				on_demand::{self, WeightInfo as _},
+			};
+			type AssetHubRuntime = <$asset_hub as $crate::Chain>::Runtime;
+			type RelayRuntime = <$relay as $crate::Chain>::Runtime;
+			type RelayCall = <$relay as $crate::Chain>::RuntimeCall;
+
+			let (para, other) = (ParaId::from(2000), ParaId::from(2001));
+			let assert_covers = |bought: $crate::Weight, call: RelayCall| {
+				let weight = call.get_dispatch_info().weight;
+				assert!(
+					weight.all_lte(bought),
+					"{call:?} weighs {weight:?} on the Relay Chain, more than the {bought:?} bought",
+				);
+			};
+
+			<$relay>::execute_with(|| {
+				let config = ActiveConfig::<RelayRuntime>::get();
+
+				// The weight of an order grows with the queue, which is at most full.
+				let bought = <AssetHubRuntime as Config>::OnDemandOrderWeight::get();
+				let weight = <RelayRuntime as on_demand::Config>::WeightInfo::place_order_allow_death(
+					config.scheduler_params.on_demand_queue_max_size,
+				);
+				assert!(weight.all_lte(bought), "an order weighs {weight:?}, more than {bought:?}");
+				// The proof of the validation code is bought on top of the registration.
+				for code_len in [0, config.max_code_size] {
+					assert_covers(
+						<AssetHubRuntime as Config>::RegisterParaWeight::get()
+							.saturating_add($crate::Weight::from_parts(0, code_len.into())),
+						RelayCall::ParaOnboarding($crate::pallet_para_onboarding::Call::register {
+							genesis_head: HeadData(vec![]),
+							code_hash: Default::default(),
+							code_len,
+						}),
+					);
+				}
+				assert_covers(
+					<AssetHubRuntime as Config>::DeregisterParaWeight::get(),
+					RelayCall::Registrar(
+						$crate::polkadot_runtime_common::paras_registrar::Call::deregister {
+							id: para,
+						},
+					),
+				);
+				assert_covers(
+					<AssetHubRuntime as Config>::InitOpenHrmpChannelWeight::get(),
+					RelayCall::ParaChannels($crate::pallet_para_channels::Call::init_open_channel {
+						para,
+						recipient: other,
+						proposed_max_capacity: config.hrmp_channel_max_capacity,
+						proposed_max_message_size: config.hrmp_channel_max_message_size,
+					}),
+				);
+				assert_covers(
+					<AssetHubRuntime as Config>::AcceptOpenHrmpChannelWeight::get(),
+					RelayCall::ParaChannels(
+						$crate::pallet_para_channels::Call::accept_open_channel {
+							para,
+							sender: other,
+						},
+					),
+				);
+			});
+		}
+	};
+}
+
+/// A team holding the Relay Chain token on `$asset_hub` can register a para on `$relay`, and get
+/// the deposits back on `$asset_hub` once it deregisters it.
+#[macro_export]
+macro_rules! test_para_can_be_registered_and_deregistered_from_asset_hub {
+	( $asset_hub:ty, $relay:ty, $units:expr ) => {
+		$crate::paste::paste! {
+			use $crate::frame_support::traits::{fungible::Inspect as _, Get};
+			use $crate::polkadot_parachain_primitives::primitives::{HeadData, ValidationCode};
+			use $crate::polkadot_runtime_common::paras_registrar;
+			use $crate::sp_runtime::traits::{BlakeTwo256, Hash};
+
+			// The amount paying for the execution of the XCMs on the Relay Chain.
+			let fees = $units;
+			// The amount paying for noting the validation code on the Relay Chain.
+			let preimage_funds = 10 * $units;
+			let manager = <$asset_hub>::account_id_of("ParaManager");
+			let genesis_head = HeadData(vec![1; 32]);
+			let code = vec![1u8; 1024];
+			let code_hash = BlakeTwo256::hash(&code);
+			<$asset_hub>::fund_accounts(vec![(manager.clone(), 10_000 * $units)]);
+			<$relay>::fund_accounts(vec![(
+				<$relay as [<$relay Pallet>]>::XcmPallet::check_account(),
+				10_000 * $units,
+			)]);
+
+			// The validation code is too large for an XCM. The team notes it as a preimage on the
+			// Relay Chain, with the Relay Chain token teleported there from Asset Hub beforehand.
+			<$asset_hub>::execute_with(|| {
+				let assets: Assets = (Parent, preimage_funds).into();
+				let beneficiary: Location =
+					$crate::AccountId32 { network: None, id: manager.clone().into() }.into();
+				assert_ok!(
+					<$asset_hub as [<$asset_hub Pallet>]>::PolkadotXcm::limited_teleport_assets(
+						<$asset_hub as $crate::Chain>::RuntimeOrigin::signed(manager.clone()),
+						bx!(Location::parent().into()),
+						bx!(beneficiary.into()),
+						bx!(assets.into()),
+						0,
+						WeightLimit::Unlimited,
+					)
+				);
+			});
+			let deposit = <$relay>::execute_with(|| {
+				type Runtime = <$relay as $crate::Chain>::Runtime;
+
+				assert_ok!(<$relay as [<$relay Pallet>]>::Preimage::note_preimage(
+					<$relay as $crate::Chain>::RuntimeOrigin::signed(manager.clone()),
+					code.clone(),
+				));
+				// The validators are not running, the code is trusted so that it needs no
+				// pre-checking.
+				assert_ok!(<$relay as [<$relay Pallet>]>::Paras::add_trusted_validation_code(
+					<$relay as $crate::Chain>::RuntimeOrigin::root(),
+					ValidationCode(code.clone()),
+				));
+
+				let max_code_size =
+					$crate::runtime_parachains::configuration::ActiveConfig::<Runtime>::get()
+						.max_code_size;
+				<Runtime as paras_registrar::Config>::ParaDeposit::get() +
+					<Runtime as paras_registrar::Config>::DataDepositPerByte::get() *
+						(genesis_head.0.len() as u128 + max_code_size as u128)
+			});
+
+			<$asset_hub>::execute_with(|| {
+				assert_ok!(<$asset_hub as [<$asset_hub Pallet>]>::RelayCalls::register_para(
+					<$asset_hub as $crate::Chain>::RuntimeOrigin::signed(manager.clone()),
+					genesis_head.clone(),
+					code_hash,
+					code.len() as u32,
+					deposit,
+					fees,
+				));
+			});
+
+			let para_id = <$relay>::execute_with(|| {
+				type RuntimeEvent = <$relay as $crate::Chain>::RuntimeEvent;
+				type Balances = <$relay as [<$relay Pallet>]>::Balances;
+
+				assert_expected_events!(
+					$relay,
+					vec![
+						RuntimeEvent::Registrar(paras_registrar::Event::Reserved { who, .. }) => {
+							who: *who == manager,
+						},
+						RuntimeEvent::Registrar(
+							paras_registrar::Event::Registered { manager: m, .. }
+						) => {
+							m: *m == manager,
+						},
+						RuntimeEvent::MessageQueue(
+							$crate::pallet_message_queue::Event::Processed { success: true, .. }
+						) => {},
+					]
+				);
+				// The preimage is not needed once the para is registered.
+				assert_ok!(<$relay as [<$relay Pallet>]>::Preimage::unnote_preimage(
+					<$relay as $crate::Chain>::RuntimeOrigin::signed(manager.clone()),
+					code_hash,
+				));
+				assert_eq!(Balances::reserved_balance(&manager), deposit);
+
+				<$relay as $crate::Chain>::events()
+					.into_iter()
+					.find_map(|event| match event {
+						RuntimeEvent::Registrar(paras_registrar::Event::Registered {
+							para_id,
+							..
+						}) => Some(para_id),
+						_ => None,
+					})
+					.expect("the para is registered")
+			});
+
+			// Onboarding takes a couple of sessions.
+			$crate::onboard_registered_paras!($relay);
+			<$relay>::execute_with(|| {
+				assert!($crate::runtime_parachains::paras::Pallet::<
+					<$relay as $crate::Chain>::Runtime,
+				>::is_parathread(para_id));
+			});
+
+			let balance_before = <$asset_hub>::execute_with(|| {
+				<$asset_hub as [<$asset_hub Pallet>]>::Balances::balance(&manager)
+			});
+			<$asset_hub>::execute_with(|| {
+				assert_ok!(<$asset_hub as [<$asset_hub Pallet>]>::RelayCalls::deregister_para(
+					<$asset_hub as $crate::Chain>::RuntimeOrigin::signed(manager.clone()),
+					para_id,
+					deposit,
+					fees,
+					fees,
+				));
+			});
+
+			<$relay>::execute_with(|| {
+				type RuntimeEvent = <$relay as $crate::Chain>::RuntimeEvent;
+				type Balances = <$relay as [<$relay Pallet>]>::Balances;
+
+				assert_expected_events!(
+					$relay,
+					vec![
+						RuntimeEvent::Registrar(
+							paras_registrar::Event::Deregistered { para_id: p }
+						) => {
+							p: *p == para_id,
+						},
+						RuntimeEvent::MessageQueue(
+							$crate::pallet_message_queue::Event::Processed { success: true, .. }
+						) => {},
+					]
+				);
+				assert_eq!(Balances::reserved_balance(&manager), 0);
+			});
+
+			<$asset_hub>::execute_with(|| {
+				type RuntimeEvent = <$asset_hub as $crate::Chain>::RuntimeEvent;
+
+				assert_expected_events!(
+					$asset_hub,
+					vec![
+						RuntimeEvent::MessageQueue(
+							$crate::pallet_message_queue::Event::Processed { success: true, .. }
+						) => {},
+					]
+				);
+				// The deposit is back on Asset Hub, less the fees.
+				let balance_after =
+					<$asset_hub as [<$asset_hub Pallet>]>::Balances::balance(&manager);
+				assert!(balance_after > balance_before + deposit - 2 * fees);
+				assert!(balance_after < balance_before + deposit);
+			});
+		}
+	};
+}
+
+/// A refund from `$relay` has to cover its fees and the existential deposit `$asset_hub_ed` on
+/// `$asset_hub`, and is only made if the para is deregistered.
+#[macro_export]
+macro_rules! test_para_deposits_are_only_refunded_once_deregistered {
+	( $asset_hub:ty, $relay:ty, $asset_hub_ed:expr, $units:expr ) => {
+		$crate::paste::paste! {
+			let fees = $units;
+			let relay_funds = 10 * $units;
+			let manager = <$asset_hub>::account_id_of("ParaManager");
+			<$asset_hub>::fund_accounts(vec![(manager.clone(), 10_000 * $units)]);
+			<$relay>::fund_accounts(vec![
+				(<$relay as [<$relay Pallet>]>::XcmPallet::check_account(), 10_000 * $units),
+				(manager.clone(), relay_funds),
+			]);
+
+			<$asset_hub>::execute_with(|| {
+				type RelayCalls = <$asset_hub as [<$asset_hub Pallet>]>::RelayCalls;
+				let origin = <$asset_hub as $crate::Chain>::RuntimeOrigin::signed(manager.clone());
+
+				for refund in [fees - 1, fees + $asset_hub_ed - 1] {
+					assert_err!(
+						RelayCalls::deregister_para(origin.clone(), 2000.into(), refund, fees, fees),
+						$crate::pallet_relay_calls::Error::<
+							<$asset_hub as $crate::Chain>::Runtime,
+						>::RefundTooLow
+					);
+				}
+				// The para is not registered, the deposits to refund are not released.
+				assert_ok!(RelayCalls::deregister_para(
+					origin,
+					2000.into(),
+					relay_funds,
+					fees,
+					fees
+				));
+			});
+
+			<$relay>::execute_with(|| {
+				type RuntimeEvent = <$relay as $crate::Chain>::RuntimeEvent;
+				type Balances = <$relay as [<$relay Pallet>]>::Balances;
+
+				assert_expected_events!(
+					$relay,
+					vec![
+						RuntimeEvent::MessageQueue(
+							$crate::pallet_message_queue::Event::Processed { success: false, .. }
+						) => {},
+					]
+				);
+				// The funds of the manager on the Relay Chain are not taken for the refund.
+				assert_eq!(Balances::free_balance(&manager), relay_funds);
+			});
+		}
+	};
+}
+
+/// Dispatch `$call` on `$asset_hub` from its sibling `$para`, paying `$fees` of the Relay Chain
+/// token of its sovereign account there for the execution, and check it was sent on to the Relay
+/// Chain.
+#[macro_export]
+macro_rules! dispatch_relay_call_on_asset_hub_from {
+	( $asset_hub:ty, $para:ty, $call:expr, $fees:expr ) => {
+		$crate::paste::paste! {
+			let sovereign_account = <$asset_hub>::sovereign_account_id_of(
+				<$asset_hub>::sibling_location_of(<$para>::para_id()),
+			);
+			let xcm = $crate::emulated_integration_tests_common::xcm_helpers::xcm_transact_paid_execution(
+				$call.encode().into(),
+				OriginKind::Xcm,
+				(Parent, $fees).into(),
+				sovereign_account,
+			);
+
+			<$para>::execute_with(|| {
+				assert_ok!(<$para as [<$para Pallet>]>::PolkadotXcm::send(
+					<$para as $crate::Chain>::RuntimeOrigin::root(),
+					bx!(<$para>::sibling_location_of(<$asset_hub>::para_id()).into()),
+					bx!(xcm),
+				));
+			});
+
+			<$asset_hub>::execute_with(|| {
+				type RuntimeEvent = <$asset_hub as $crate::Chain>::RuntimeEvent;
+
+				assert_expected_events!(
+					$asset_hub,
+					vec![
+						RuntimeEvent::RelayCalls(
+							$crate::pallet_relay_calls::Event::Sent { origin, .. }
+						) => {
+							origin: *origin == <$asset_hub>::sibling_location_of(<$para>::para_id()),
+						},
+					]
+				);
+			});
+		}
+	};
+}
+
+/// `$para_a` and `$para_b` open a channel in each direction on `$relay`, the deposits being paid
+/// with the Relay Chain token of their sovereign accounts on `$asset_hub`, without any of theirs on
+/// `$relay`.
+#[macro_export]
+macro_rules! test_paras_can_open_hrmp_channels_with_deposits_from_asset_hub {
+	( $asset_hub:ty, $relay:ty, $para_a:ty, $para_b:ty, $units:expr ) => {
+		$crate::paste::paste! {
+			use $crate::frame_support::traits::fungible::Inspect as _;
+			use $crate::runtime_parachains::{configuration, hrmp};
+
+			// The amount paying for the execution of the XCMs on Asset Hub and on the Relay Chain.
+			let fees = $units;
+			let para_a = <$para_a>::para_id();
+			let para_b = <$para_b>::para_id();
+			let sovereign_account_on_relay =
+				|para_id| <$relay>::sovereign_account_id_of(<$relay>::child_location_of(para_id));
+			for para_id in [para_a, para_b] {
+				<$asset_hub>::fund_accounts(vec![(
+					<$asset_hub>::sovereign_account_id_of(<$asset_hub>::sibling_location_of(para_id)),
+					1000 * $units,
+				)]);
+			}
+			<$relay>::fund_accounts(vec![(
+				<$relay as [<$relay Pallet>]>::XcmPallet::check_account(),
+				10_000 * $units,
+			)]);
+			$crate::register_on_demand_paras!($relay, [para_a, para_b]);
+
+			let (sender_deposit, recipient_deposit, max_capacity, max_message_size) =
+				<$relay>::execute_with(|| {
+					type Runtime = <$relay as $crate::Chain>::Runtime;
+					type Balances = <$relay as [<$relay Pallet>]>::Balances;
+
+					// The paras hold nothing on the Relay Chain.
+					for para_id in [para_a, para_b] {
+						assert_eq!(Balances::total_balance(&sovereign_account_on_relay(para_id)), 0);
+					}
+					configuration::ActiveConfig::<Runtime>::mutate(|config| {
+						config.hrmp_sender_deposit = 5 * $units;
+						config.hrmp_recipient_deposit = 5 * $units;
+					});
+
+					let config = configuration::ActiveConfig::<Runtime>::get();
+					(
+						config.hrmp_sender_deposit,
+						config.hrmp_recipient_deposit,
+						config.hrmp_channel_max_capacity,
+						config.hrmp_channel_max_message_size,
+					)
+				});
+			let init_open = |recipient| {
+				<$asset_hub as $crate::Chain>::RuntimeCall::RelayCalls(
+					$crate::pallet_relay_calls::Call::init_open_hrmp_channel {
+						recipient,
+						proposed_max_capacity: max_capacity,
+						proposed_max_message_size: max_message_size,
+						deposit: sender_deposit,
+						fees,
+					},
+				)
+			};
+			let accept_open = |sender| {
+				<$asset_hub as $crate::Chain>::RuntimeCall::RelayCalls(
+					$crate::pallet_relay_calls::Call::accept_open_hrmp_channel {
+						sender,
+						deposit: recipient_deposit,
+						fees,
+					},
+				)
+			};
+			let assert_channel_requested = |sender, recipient| {
+				<$relay>::execute_with(|| {
+					type RuntimeEvent = <$relay as $crate::Chain>::RuntimeEvent;
+
+					assert_expected_events!(
+						$relay,
+						vec![
+							RuntimeEvent::Hrmp(
+								hrmp::Event::OpenChannelRequested { sender: s, recipient: r, .. }
+							) => {
+								s: *s == sender,
+								r: *r == recipient,
+							},
+							RuntimeEvent::MessageQueue(
+								$crate::pallet_message_queue::Event::Processed { success: true, .. }
+							) => {},
+						]
+					);
+				});
+			};
+			let assert_channel_accepted = |sender, recipient| {
+				<$relay>::execute_with(|| {
+					type RuntimeEvent = <$relay as $crate::Chain>::RuntimeEvent;
+
+					assert_expected_events!(
+						$relay,
+						vec![
+							RuntimeEvent::Hrmp(
+								hrmp::Event::OpenChannelAccepted { sender: s, recipient: r }
+							) => {
+								s: *s == sender,
+								r: *r == recipient,
+							},
+							RuntimeEvent::MessageQueue(
+								$crate::pallet_message_queue::Event::Processed { success: true, .. }
+							) => {},
+						]
+					);
+				});
+			};
+
+			$crate::dispatch_relay_call_on_asset_hub_from!(
+				$asset_hub,
+				$para_a,
+				init_open(para_b),
+				fees
+			);
+			assert_channel_requested(para_a, para_b);
+			$crate::dispatch_relay_call_on_asset_hub_from!(
+				$asset_hub,
+				$para_b,
+				accept_open(para_a),
+				fees
+			);
+			assert_channel_accepted(para_a, para_b);
+
+			$crate::dispatch_relay_call_on_asset_hub_from!(
+				$asset_hub,
+				$para_b,
+				init_open(para_a),
+				fees
+			);
+			assert_channel_requested(para_b, para_a);
+			$crate::dispatch_relay_call_on_asset_hub_from!(
+				$asset_hub,
+				$para_a,
+				accept_open(para_b),
+				fees
+			);
+			assert_channel_accepted(para_b, para_a);
+
+			<$relay>::execute_with(|| {
+				type Balances = <$relay as [<$relay Pallet>]>::Balances;
+
+				// Each para holds the deposits of both its channels, teleported from Asset Hub.
+				for para_id in [para_a, para_b] {
+					assert_eq!(
+						Balances::reserved_balance(&sovereign_account_on_relay(para_id)),
+						sender_deposit + recipient_deposit
+					);
+				}
+			});
+		}
+	};
+}
@@ -14,10 +14,16 @@
 // limitations under the License.
 
 mod claim_assets;
+mod hrmp;
 mod hybrid_transfers;
+mod nis;
+mod on_demand;
+mod onboarding;
+mod remote_locking;
 mod reserve_transfer;
 mod send;
 mod set_xcm_versions;
+mod society;
 mod swap;
 mod teleport;
 mod treasury;
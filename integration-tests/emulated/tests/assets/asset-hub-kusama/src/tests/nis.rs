@@ -0,0 +1,224 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Tests concerning the NIS counterpart token of the Kusama Relay Chain.
+
+use crate::*;
+use frame_support::traits::{Hooks, OnRuntimeUpgrade};
+use kusama_runtime_constants::{currency::UNITS, NIS_COUNTERPART_PALLET_ID};
+use sp_runtime::Perquintill;
+
+// The amount of KSM of every NIS bid.
+const BID: Balance = 1_000 * UNITS;
+// The amount of KSM paying for the execution of the transfers.
+const FEE: Balance = UNITS;
+
+// Place a bid on the NIS of the Relay Chain, issue its receipt and make it communal, returning the
+// amount of counterpart tokens minted for it.
+fn mint_communal_receipt(who: &AccountId) -> Balance {
+	Kusama::execute_with(|| {
+		type RuntimeEvent = <Kusama as Chain>::RuntimeEvent;
+		type RuntimeOrigin = <Kusama as Chain>::RuntimeOrigin;
+		type Nis = <Kusama as KusamaPallet>::Nis;
+		type NisCounterpartBalances = <Kusama as KusamaPallet>::NisCounterpartBalances;
+
+		kusama_runtime::NisTarget::set(&Perquintill::from_percent(50));
+		assert_ok!(Nis::place_bid(RuntimeOrigin::signed(who.clone()), BID, 1));
+		<Nis as Hooks<_>>::on_initialize(kusama_runtime::IntakePeriod::get());
+
+		let index = pallet_nis::Summary::<<Kusama as Chain>::Runtime>::get().index - 1;
+		let before = NisCounterpartBalances::free_balance(who);
+		assert_ok!(Nis::communify(RuntimeOrigin::signed(who.clone()), index));
+
+		assert_expected_events!(
+			Kusama,
+			vec![
+				RuntimeEvent::Nis(pallet_nis::Event::Issued { index: i, .. }) => {
+					i: *i == index,
+				},
+			]
+		);
+		NisCounterpartBalances::free_balance(who) - before
+	})
+}
+
+#[test]
+fn nis_counterpart_is_tradable_on_asset_hub() {
+	let alice = KusamaSender::get();
+	let bob = AssetHubKusamaReceiver::get();
+	let ksm_on_asset_hub = asset_hub_kusama_runtime::xcm_config::KsmLocation::get();
+	let counterpart_on_asset_hub: Location =
+		(Parent, PalletInstance(NIS_COUNTERPART_PALLET_ID)).into();
+
+	// Mint the counterpart tokens of two receipts on the Relay Chain.
+	let thawed = mint_communal_receipt(&alice);
+	let pooled = mint_communal_receipt(&alice);
+
+	AssetHubKusama::execute_with(|| {
+		asset_hub_kusama_runtime::migrations::CreateNisCounterpartAsset::on_runtime_upgrade();
+		assert!(<AssetHubKusama as AssetHubKusamaPallet>::ForeignAssets::asset_exists(
+			counterpart_on_asset_hub.clone()
+		));
+	});
+
+	// Move all of them to Asset Hub, the Relay Chain being their reserve.
+	Kusama::execute_with(|| {
+		type RuntimeEvent = <Kusama as Chain>::RuntimeEvent;
+
+		let assets: Assets = vec![
+			(Here, FEE).into(),
+			(PalletInstance(NIS_COUNTERPART_PALLET_ID), thawed + pooled).into(),
+		]
+		.into();
+		let fee_id: AssetId = Here.into();
+		let beneficiary: Location =
+			AccountId32Junction { network: None, id: alice.clone().into() }.into();
+
+		assert_ok!(<Kusama as KusamaPallet>::XcmPallet::transfer_assets_using_type_and_then(
+			<Kusama as Chain>::RuntimeOrigin::signed(alice.clone()),
+			bx!(Location::new(0, Parachain(AssetHubKusama::para_id().into())).into()),
+			bx!(assets.into()),
+			bx!(TransferType::LocalReserve),
+			bx!(fee_id.into()),
+			bx!(TransferType::Teleport),
+			bx!(VersionedXcm::from(Xcm::<()>(vec![DepositAsset {
+				assets: Wild(AllCounted(2)),
+				beneficiary,
+			}]))),
+			WeightLimit::Unlimited,
+		));
+
+		assert_expected_events!(
+			Kusama,
+			vec![
+				RuntimeEvent::XcmPallet(pallet_xcm::Event::Sent { .. }) => {},
+			]
+		);
+	});
+
+	// Pool them against KSM and swap.
+	AssetHubKusama::execute_with(|| {
+		type RuntimeEvent = <AssetHubKusama as Chain>::RuntimeEvent;
+		type RuntimeOrigin = <AssetHubKusama as Chain>::RuntimeOrigin;
+		type AssetConversion = <AssetHubKusama as AssetHubKusamaPallet>::AssetConversion;
+		type ForeignAssets = <AssetHubKusama as AssetHubKusamaPallet>::ForeignAssets;
+
+		assert_expected_events!(
+			AssetHubKusama,
+			vec![
+				RuntimeEvent::ForeignAssets(pallet_assets::Event::Issued { asset_id, owner, amount }) => {
+					asset_id: *asset_id == counterpart_on_asset_hub,
+					owner: *owner == alice,
+					amount: *amount == thawed + pooled,
+				},
+			]
+		);
+
+		assert_ok!(AssetConversion::create_pool(
+			RuntimeOrigin::signed(alice.clone()),
+			bx!(ksm_on_asset_hub.clone()),
+			bx!(counterpart_on_asset_hub.clone()),
+		));
+		assert_ok!(AssetConversion::add_liquidity(
+			RuntimeOrigin::signed(alice.clone()),
+			bx!(ksm_on_asset_hub.clone()),
+			bx!(counterpart_on_asset_hub.clone()),
+			10 * UNITS,
+			pooled,
+			0,
+			0,
+			alice.clone(),
+		));
+		assert_ok!(AssetConversion::swap_exact_tokens_for_tokens(
+			RuntimeOrigin::signed(bob.clone()),
+			vec![bx!(ksm_on_asset_hub.clone()), bx!(counterpart_on_asset_hub.clone())],
+			UNITS,
+			1,
+			bob.clone(),
+			true,
+		));
+
+		assert_expected_events!(
+			AssetHubKusama,
+			vec![
+				RuntimeEvent::AssetConversion(pallet_asset_conversion::Event::SwapExecuted { .. }) => {},
+			]
+		);
+		assert!(ForeignAssets::balance(counterpart_on_asset_hub.clone(), &bob) > 0);
+		assert_eq!(ForeignAssets::balance(counterpart_on_asset_hub.clone(), &alice), thawed);
+	});
+
+	// Return the counterpart tokens of the first receipt to the Relay Chain.
+	AssetHubKusama::execute_with(|| {
+		type RuntimeEvent = <AssetHubKusama as Chain>::RuntimeEvent;
+
+		let assets: Assets =
+			vec![(Parent, FEE).into(), (counterpart_on_asset_hub.clone(), thawed).into()].into();
+		let fee_id: AssetId = Parent.into();
+		let beneficiary: Location =
+			AccountId32Junction { network: None, id: alice.clone().into() }.into();
+
+		assert_ok!(
+			<AssetHubKusama as AssetHubKusamaPallet>::PolkadotXcm::transfer_assets_using_type_and_then(
+				<AssetHubKusama as Chain>::RuntimeOrigin::signed(alice.clone()),
+				bx!(Location::parent().into()),
+				bx!(assets.into()),
+				bx!(TransferType::DestinationReserve),
+				bx!(fee_id.into()),
+				bx!(TransferType::Teleport),
+				bx!(VersionedXcm::from(Xcm::<()>(vec![DepositAsset {
+					assets: Wild(AllCounted(2)),
+					beneficiary,
+				}]))),
+				WeightLimit::Unlimited,
+			)
+		);
+
+		assert_expected_events!(
+			AssetHubKusama,
+			vec![
+				RuntimeEvent::ForeignAssets(pallet_assets::Event::Burned { asset_id, balance, .. }) => {
+					asset_id: *asset_id == counterpart_on_asset_hub,
+					balance: *balance == thawed,
+				},
+			]
+		);
+	});
+
+	// And thaw it once it expires.
+	Kusama::execute_with(|| {
+		type RuntimeEvent = <Kusama as Chain>::RuntimeEvent;
+		type Runtime = <Kusama as Chain>::Runtime;
+		type Nis = <Kusama as KusamaPallet>::Nis;
+		type NisCounterpartBalances = <Kusama as KusamaPallet>::NisCounterpartBalances;
+
+		assert_eq!(NisCounterpartBalances::free_balance(&alice), thawed);
+
+		let receipt = pallet_nis::Receipts::<Runtime>::get(0).expect("the receipt was issued");
+		<Kusama as Chain>::System::set_block_number(receipt.expiry);
+		assert_ok!(Nis::thaw_communal(<Kusama as Chain>::RuntimeOrigin::signed(alice.clone()), 0));
+
+		assert_expected_events!(
+			Kusama,
+			vec![
+				RuntimeEvent::Nis(pallet_nis::Event::Thawed { index, dropped: false, .. }) => {
+					index: *index == 0,
+				},
+			]
+		);
+		assert!(pallet_nis::Receipts::<Runtime>::get(0).is_none());
+		assert_eq!(NisCounterpartBalances::free_balance(&alice), 0);
+	});
+}
@@ -0,0 +1,44 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Tests concerning on-demand coretime of the Kusama Relay Chain, bought with KSM held on Asset
+//! Hub.
+
+use crate::*;
+use kusama_runtime_constants::currency::UNITS;
+
+/// An account on Asset Hub can place an on-demand order for a parachain with KSM it holds there,
+/// with the fees estimated beforehand by dry-running the order.
+#[test]
+fn asset_hub_account_places_on_demand_order() {
+	integration_tests_helpers::test_on_demand_order_can_be_placed_from_asset_hub!(
+		AssetHubKusama,
+		Kusama,
+		PenpalA,
+		KusamaId,
+		OnDemandAssignmentProvider,
+		UNITS
+	);
+}
+
+/// The weights Asset Hub buys for the calls it dispatches on the Relay Chain cover the weights of
+/// these calls there.
+#[test]
+fn relay_call_weights_cover_relay_dispatch_weights() {
+	integration_tests_helpers::test_relay_call_weights_cover_relay_dispatch_weights!(
+		AssetHubKusama,
+		Kusama
+	);
+}
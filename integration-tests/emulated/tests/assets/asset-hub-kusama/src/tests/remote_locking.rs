@@ -0,0 +1,39 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::*;
+
+/// KSM held on Asset Hub can be locked in favour of the Relay Chain, which records the lock, and
+/// the Relay Chain can release it again on request of its owner.
+#[test]
+fn lock_ksm_on_asset_hub_in_favour_of_relay_and_unlock_works() {
+	integration_tests_helpers::test_relay_token_can_be_locked_in_favour_of_relay!(
+		AssetHubKusama,
+		Kusama,
+		ASSET_HUB_KUSAMA_ED,
+		KUSAMA_ED
+	);
+}
+
+/// The Relay Chain does not accept unlock requests for locks it was never notified of.
+#[test]
+fn relay_rejects_unlock_request_without_lock() {
+	integration_tests_helpers::test_relay_rejects_unlock_request_without_lock!(
+		AssetHubKusama,
+		Kusama,
+		ASSET_HUB_KUSAMA_ED,
+		KUSAMA_ED
+	);
+}
@@ -0,0 +1,234 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Tests concerning the Kusama Society, operated with KSM held on Asset Hub.
+
+use crate::*;
+use frame_support::{dispatch::GetDispatchInfo, traits::fungible::Inspect as _};
+use kusama_runtime::governance::pallet_custom_origins::Origin as CustomOrigin;
+use kusama_runtime_constants::currency::UNITS;
+
+// The deposit of every candidate of the Society.
+const CANDIDATE_DEPOSIT: Balance = UNITS;
+// The amount of KSM paying for the execution of the XCMs on the Relay Chain.
+const FEES: Balance = UNITS;
+
+// The location of the account derived from `who` on Asset Hub, as seen by the Relay Chain.
+fn derived_location_on_relay(who: &AccountId) -> Location {
+	Location::new(
+		0,
+		[
+			Parachain(AssetHubKusama::para_id().into()),
+			AccountId32Junction { network: Some(KusamaId), id: who.clone().into() },
+		],
+	)
+}
+
+fn found_society(founder: &AccountId) {
+	Kusama::execute_with(|| {
+		type RuntimeEvent = <Kusama as Chain>::RuntimeEvent;
+
+		assert_ok!(<Kusama as KusamaPallet>::Society::found_society(
+			CustomOrigin::SocietyAdmin.into(),
+			founder.clone().into(),
+			100,
+			10,
+			10,
+			CANDIDATE_DEPOSIT,
+			b"be cool".to_vec(),
+		));
+
+		assert_expected_events!(
+			Kusama,
+			vec![
+				RuntimeEvent::Society(pallet_society::Event::Founded { founder: f }) => {
+					f: f == founder,
+				},
+			]
+		);
+	});
+}
+
+// Teleport `amount` of KSM from `who` on Asset Hub to the account derived from it on the Relay
+// Chain, which pays for the execution of the XCMs `who` sends there.
+fn teleport_to_derived_account(who: &AccountId, amount: Balance) {
+	Kusama::fund_accounts(vec![(<Kusama as KusamaPallet>::XcmPallet::check_account(), amount)]);
+
+	AssetHubKusama::execute_with(|| {
+		let assets: Assets = (Parent, amount).into();
+		assert_ok!(<AssetHubKusama as AssetHubKusamaPallet>::PolkadotXcm::limited_teleport_assets(
+			<AssetHubKusama as Chain>::RuntimeOrigin::signed(who.clone()),
+			bx!(Location::parent().into()),
+			bx!(derived_location_on_relay(who).into()),
+			bx!(assets.into()),
+			0,
+			WeightLimit::Unlimited,
+		));
+	});
+}
+
+// Send `xcm` from `who` on Asset Hub to the Relay Chain, withdrawing `amount` from the account
+// derived from `who` there to pay for its execution, and aliasing into the account `who` on the
+// Relay Chain. The rest of `amount` stays in the holding register for `xcm`.
+fn send_as_alias_to_relay(who: &AccountId, amount: Balance, xcm: Vec<Instruction<()>>) {
+	let mut message = vec![
+		WithdrawAsset((Here, amount).into()),
+		BuyExecution { fees: (Here, FEES).into(), weight_limit: Unlimited },
+		AliasOrigin(AccountId32Junction { network: Some(KusamaId), id: who.clone().into() }.into()),
+	];
+	message.extend(xcm);
+	message.extend([
+		RefundSurplus,
+		DepositAsset { assets: Wild(AllCounted(1)), beneficiary: derived_location_on_relay(who) },
+	]);
+
+	AssetHubKusama::execute_with(|| {
+		assert_ok!(<AssetHubKusama as AssetHubKusamaPallet>::PolkadotXcm::send(
+			<AssetHubKusama as Chain>::RuntimeOrigin::signed(who.clone()),
+			bx!(Location::parent().into()),
+			bx!(VersionedXcm::from(Xcm::<()>(message))),
+		));
+	});
+}
+
+fn transact(call: <Kusama as Chain>::RuntimeCall) -> Instruction<()> {
+	Transact {
+		origin_kind: OriginKind::SovereignAccount,
+		require_weight_at_most: call.get_dispatch_info().weight,
+		call: call.encode().into(),
+	}
+}
+
+/// An account on Asset Hub can bid on the Society with KSM it holds there.
+#[test]
+fn asset_hub_account_bids_on_society() {
+	let bidder = AssetHubKusama::account_id_of("SocietyBidder");
+	let offer = 100 * UNITS;
+	found_society(&KusamaReceiver::get());
+	AssetHubKusama::fund_accounts(vec![(bidder.clone(), 100 * UNITS)]);
+
+	teleport_to_derived_account(&bidder, 10 * UNITS);
+	send_as_alias_to_relay(
+		&bidder,
+		FEES + 5 * UNITS,
+		vec![
+			// Fund the deposit of the bid with KSM from Asset Hub.
+			DepositAsset {
+				assets: Wild(AllCounted(1)),
+				beneficiary: AccountId32Junction { network: None, id: bidder.clone().into() }
+					.into(),
+			},
+			transact(<Kusama as Chain>::RuntimeCall::Society(pallet_society::Call::bid {
+				value: offer,
+			})),
+		],
+	);
+
+	Kusama::execute_with(|| {
+		type RuntimeEvent = <Kusama as Chain>::RuntimeEvent;
+		type Balances = <Kusama as KusamaPallet>::Balances;
+
+		assert_expected_events!(
+			Kusama,
+			vec![
+				RuntimeEvent::Society(pallet_society::Event::Bid { candidate_id, offer: o }) => {
+					candidate_id: *candidate_id == bidder,
+					o: *o == offer,
+				},
+				RuntimeEvent::MessageQueue(pallet_message_queue::Event::Processed { success: true, .. }) => {},
+			]
+		);
+		assert_eq!(Balances::reserved_balance(&bidder), CANDIDATE_DEPOSIT);
+	});
+}
+
+/// A member of the Society can have its payouts settled on Asset Hub.
+#[test]
+fn society_payout_is_teleported_to_asset_hub() {
+	let member = AssetHubKusama::account_id_of("SocietyMember");
+	let payout = 10 * UNITS;
+	found_society(&member);
+	AssetHubKusama::fund_accounts(vec![(member.clone(), 100 * UNITS)]);
+
+	// Payouts are only due once candidates are inducted after a few rotation periods, so record
+	// one directly.
+	Kusama::execute_with(|| {
+		type Runtime = <Kusama as Chain>::Runtime;
+		type Society = <Kusama as KusamaPallet>::Society;
+
+		assert_ok!(<Kusama as KusamaPallet>::Balances::force_set_balance(
+			<Kusama as Chain>::RuntimeOrigin::root(),
+			Society::payouts().into(),
+			payout * 2,
+		));
+		// The fields of the record are private, so its encoding is stored instead: nothing paid
+		// yet and a single payout due now.
+		let record: (Balance, Vec<(u32, Balance)>) =
+			(0, vec![(<Kusama as Chain>::System::block_number(), payout)]);
+		frame_support::storage::unhashed::put(
+			&pallet_society::Payouts::<Runtime>::hashed_key_for(&member),
+			&record,
+		);
+	});
+
+	teleport_to_derived_account(&member, 2 * FEES);
+	let balance_before = AssetHubKusama::execute_with(|| {
+		<AssetHubKusama as AssetHubKusamaPallet>::Balances::balance(&member)
+	});
+	send_as_alias_to_relay(
+		&member,
+		FEES,
+		vec![
+			transact(<Kusama as Chain>::RuntimeCall::Society(pallet_society::Call::payout {})),
+			// Move the payout to Asset Hub, the rest of the holding pays for the delivery.
+			WithdrawAsset((Here, payout).into()),
+			InitiateTeleport {
+				assets: Definite((Here, payout).into()),
+				dest: Location::new(0, [Parachain(AssetHubKusama::para_id().into())]),
+				xcm: Xcm(vec![
+					BuyExecution { fees: (Parent, payout).into(), weight_limit: Unlimited },
+					DepositAsset {
+						assets: Wild(AllCounted(1)),
+						beneficiary: AccountId32Junction {
+							network: None,
+							id: member.clone().into(),
+						}
+						.into(),
+					},
+				]),
+			},
+		],
+	);
+
+	Kusama::execute_with(|| {
+		type RuntimeEvent = <Kusama as Chain>::RuntimeEvent;
+		type Society = <Kusama as KusamaPallet>::Society;
+
+		assert_expected_events!(
+			Kusama,
+			vec![
+				RuntimeEvent::MessageQueue(pallet_message_queue::Event::Processed { success: true, .. }) => {},
+			]
+		);
+		// The payout left the pot of the Society.
+		assert_eq!(<Kusama as KusamaPallet>::Balances::balance(&Society::payouts()), payout);
+	});
+
+	AssetHubKusama::execute_with(|| {
+		let balance_after = <AssetHubKusama as AssetHubKusamaPallet>::Balances::balance(&member);
+		assert!(balance_after > balance_before);
+		assert!(balance_after <= balance_before + payout);
+	});
+}
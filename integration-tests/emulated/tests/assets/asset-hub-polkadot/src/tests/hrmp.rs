@@ -0,0 +1,33 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Tests concerning the HRMP channels of paras on the Polkadot Relay Chain, with the deposits paid
+//! with DOT held by their sovereign accounts on Asset Hub.
+
+use crate::*;
+use polkadot_runtime_constants::currency::UNITS;
+
+/// Penpal and another para open a channel in each direction, the deposits being paid with the DOT
+/// of their sovereign accounts on Asset Hub, without any DOT of theirs on the Relay Chain.
+#[test]
+fn paras_open_bidirectional_hrmp_channel_with_deposits_from_asset_hub() {
+	integration_tests_helpers::test_paras_can_open_hrmp_channels_with_deposits_from_asset_hub!(
+		AssetHubPolkadot,
+		Polkadot,
+		PenpalA,
+		PenpalB,
+		UNITS
+	);
+}
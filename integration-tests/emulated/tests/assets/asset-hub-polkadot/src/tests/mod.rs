@@ -15,7 +15,11 @@
 
 mod claim_assets;
 mod fellowship_treasury;
+mod hrmp;
 mod hybrid_transfers;
+mod on_demand;
+mod onboarding;
+mod remote_locking;
 mod reserve_transfer;
 mod send;
 mod set_xcm_versions;
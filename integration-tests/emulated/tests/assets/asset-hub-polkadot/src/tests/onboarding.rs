@@ -0,0 +1,42 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Tests concerning the onboarding of paras on the Polkadot Relay Chain with DOT held on Asset Hub.
+
+use crate::*;
+use polkadot_runtime_constants::currency::UNITS;
+
+/// A team holding DOT on Asset Hub can register a para on the Relay Chain, and get the deposits
+/// back on Asset Hub once it deregisters it.
+#[test]
+fn asset_hub_account_registers_and_deregisters_para() {
+	integration_tests_helpers::test_para_can_be_registered_and_deregistered_from_asset_hub!(
+		AssetHubPolkadot,
+		Polkadot,
+		UNITS
+	);
+}
+
+/// A refund has to cover its fees and the existential deposit on Asset Hub, and is only made if
+/// the para is deregistered.
+#[test]
+fn asset_hub_account_gets_no_refund_without_deregistering_a_para() {
+	integration_tests_helpers::test_para_deposits_are_only_refunded_once_deregistered!(
+		AssetHubPolkadot,
+		Polkadot,
+		ASSET_HUB_POLKADOT_ED,
+		UNITS
+	);
+}
@@ -16,9 +16,11 @@
 //! Tests for XCM fee estimation in the runtime.
 
 use crate::{
-	assert_expected_events, bx, AssetHubPolkadot, Chain, ParaToParaThroughAHTest, PenpalA,
-	PenpalAPallet, PenpalAReceiver, PenpalAssetOwner, PenpalB, PenpalBPallet, PenpalBSender,
-	TestArgs, TestContext, TransferType,
+	assert_expected_events, assert_ok, bx, AssetHubPolkadot, Balance, Chain,
+	ParaToParaThroughAHTest, PenpalA, PenpalAPallet, PenpalAReceiver, PenpalAssetOwner, PenpalB,
+	PenpalBPallet, PenpalBReceiver, PenpalBSender, Polkadot, PolkadotSender,
+	PolkadotUniversalLocation, Relay, RelayToParaThroughAHTest, TestArgs, TestContext,
+	TransferType, Weight, POLKADOT_ED,
 };
 use emulated_integration_tests_common::impls::{Parachain, TestExt};
 use frame_support::{
@@ -27,6 +29,7 @@ use frame_support::{
 	traits::fungibles::Inspect,
 };
 use xcm::prelude::*;
+use xcm_dry_run_api::runtime_decl_for_detailed_dry_run_api::DetailedDryRunApiV1;
 use xcm_runtime_apis::{
 	dry_run::runtime_decl_for_dry_run_api::DryRunApiV1,
 	fees::runtime_decl_for_xcm_payment_api::XcmPaymentApiV1,
@@ -214,6 +217,96 @@ fn multi_hop_works() {
 	);
 }
 
+/// We are able to dry-run a multi-hop XCM journey with the details of the executed programs.
+/// Scenario: Alice on the relay chain teleports DOTs to Asset Hub, which forwards them to PenpalB.
+/// We want the program executed on the relay chain and the weight of its instructions using the
+/// `DetailedDryRunApi`, and to follow the forwarded messages until PenpalB.
+#[test]
+fn multi_hop_detailed_dry_run_works() {
+	let destination = Polkadot::child_location_of(PenpalB::para_id());
+	let sender = PolkadotSender::get();
+	let receiver = PenpalBReceiver::get();
+	let amount_to_send: Balance = POLKADOT_ED * 1000;
+	let test_args = TestContext {
+		sender: sender.clone(),
+		receiver: receiver.clone(),
+		args: TestArgs::new_relay(destination, receiver.clone(), amount_to_send),
+	};
+	let test = RelayToParaThroughAHTest::new(test_args);
+
+	// We get it from the relay chain closure.
+	let mut remote_message = VersionedXcm::from(Xcm::<()>(Vec::new()));
+	<Polkadot as TestExt>::execute_with(|| {
+		type Runtime = <Polkadot as Chain>::Runtime;
+		type OriginCaller = <Polkadot as Chain>::OriginCaller;
+
+		let call = transfer_assets_relay_to_para_through_ah_call(test.clone());
+		let origin = OriginCaller::system(RawOrigin::Signed(sender.clone()));
+		let result = Runtime::detailed_dry_run_call(origin, call).unwrap();
+		assert_ok!(result.execution_result);
+
+		// Every instruction of the executed program is weighed, adding up to its total weight.
+		let local_xcm = result.local_xcm.unwrap();
+		let instructions = Xcm::<()>::try_from(local_xcm.clone()).unwrap().len();
+		let weights: Vec<Weight> =
+			result.local_xcm_weights.into_iter().map(Option::unwrap).collect();
+		assert_eq!(weights.len(), instructions);
+		assert_eq!(
+			weights.into_iter().fold(Weight::zero(), Weight::saturating_add),
+			Runtime::query_xcm_weight(local_xcm).unwrap()
+		);
+
+		let (_, messages_to_query) = &result
+			.forwarded_xcms
+			.iter()
+			.find(|(destination, _)| {
+				*destination == VersionedLocation::from(Location::new(0, [Parachain(1000)]))
+			})
+			.unwrap();
+		assert_eq!(messages_to_query.len(), 1);
+		remote_message = messages_to_query[0].clone();
+	});
+
+	// We get it from the AssetHub closure.
+	let mut final_message = VersionedXcm::from(Xcm::<()>(Vec::new()));
+	<AssetHubPolkadot as TestExt>::execute_with(|| {
+		type Runtime = <AssetHubPolkadot as Chain>::Runtime;
+		type RuntimeCall = <AssetHubPolkadot as Chain>::RuntimeCall;
+
+		let xcm_program =
+			VersionedXcm::from(Xcm::<RuntimeCall>::from(remote_message.try_into().unwrap()));
+		let result = Runtime::dry_run_xcm(Location::parent().into(), xcm_program).unwrap();
+		assert!(matches!(result.execution_result, Outcome::Complete { .. }));
+
+		let penpal = AssetHubPolkadot::sibling_location_of(PenpalB::para_id());
+		let (_, messages_to_query) = &result
+			.forwarded_xcms
+			.iter()
+			.find(|(destination, _)| *destination == VersionedLocation::from(penpal.clone()))
+			.unwrap();
+		assert_eq!(messages_to_query.len(), 1);
+		final_message = messages_to_query[0].clone();
+	});
+
+	// The assets arrive at the receiver on PenpalB.
+	<PenpalB as TestExt>::execute_with(|| {
+		type Runtime = <PenpalB as Chain>::Runtime;
+		type RuntimeCall = <PenpalB as Chain>::RuntimeCall;
+		type RuntimeEvent = <PenpalB as Chain>::RuntimeEvent;
+
+		let xcm_program =
+			VersionedXcm::from(Xcm::<RuntimeCall>::from(final_message.try_into().unwrap()));
+		let asset_hub = PenpalB::sibling_location_of(AssetHubPolkadot::para_id());
+		let result = Runtime::dry_run_xcm(asset_hub.into(), xcm_program).unwrap();
+		assert!(matches!(result.execution_result, Outcome::Complete { .. }));
+		assert!(result.emitted_events.iter().any(|event| matches!(
+			event,
+			RuntimeEvent::ForeignAssets(pallet_assets::Event::Issued { owner, .. })
+				if *owner == receiver
+		)));
+	});
+}
+
 fn sender_assertions(test: ParaToParaThroughAHTest) {
 	type RuntimeEvent = <PenpalB as Chain>::RuntimeEvent;
 	PenpalB::assert_xcm_pallet_attempted_complete(None);
@@ -303,3 +396,38 @@ fn transfer_assets_para_to_para_through_ah_call(
 		weight_limit: test.args.weight_limit,
 	})
 }
+
+fn transfer_assets_relay_to_para_through_ah_call(
+	test: RelayToParaThroughAHTest,
+) -> <Polkadot as Chain>::RuntimeCall {
+	type RuntimeCall = <Polkadot as Chain>::RuntimeCall;
+
+	let asset_hub_location = Polkadot::child_location_of(AssetHubPolkadot::para_id());
+	let context = PolkadotUniversalLocation::get();
+
+	// We already spent some fees along the way, just use half of what we started with.
+	let remote_fees: Asset = (Location::parent(), test.args.amount / 2).into();
+	let xcm_on_final_dest = Xcm::<()>(vec![
+		BuyExecution { fees: remote_fees, weight_limit: test.args.weight_limit.clone() },
+		DepositAsset {
+			assets: Wild(AllCounted(test.args.assets.len() as u32)),
+			beneficiary: test.args.beneficiary,
+		},
+	]);
+	let mut dest = test.args.dest.clone();
+	dest.reanchor(&asset_hub_location, &context).unwrap();
+	let xcm_on_hop = Xcm::<()>(vec![DepositReserveAsset {
+		assets: Wild(AllCounted(test.args.assets.len() as u32)),
+		dest,
+		xcm: xcm_on_final_dest,
+	}]);
+	RuntimeCall::XcmPallet(pallet_xcm::Call::transfer_assets_using_type_and_then {
+		dest: bx!(asset_hub_location.into()),
+		assets: bx!(test.args.assets.into()),
+		assets_transfer_type: bx!(TransferType::Teleport),
+		remote_fees_id: bx!(VersionedAssetId::from(AssetId(Location::here()))),
+		fees_transfer_type: bx!(TransferType::Teleport),
+		custom_xcm_on_dest: bx!(VersionedXcm::from(xcm_on_hop)),
+		weight_limit: test.args.weight_limit,
+	})
+}
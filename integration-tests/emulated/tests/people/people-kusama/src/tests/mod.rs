@@ -13,6 +13,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod aliasing;
 mod claim_assets;
 mod governance;
 mod teleport;
@@ -42,12 +42,13 @@ pub use emulated_integration_tests_common::{
 pub use parachains_common::{AccountId, Balance};
 pub use people_polkadot_runtime::ExistentialDeposit as PeoplePolkadotExistentialDeposit;
 pub use polkadot_system_emulated_network::{
+	asset_hub_polkadot_emulated_chain::AssetHubPolkadotParaPallet as AssetHubPolkadotPallet,
 	people_polkadot_emulated_chain::{
 		genesis::ED as PEOPLE_KUSAMA_ED, PeoplePolkadotParaPallet as PeoplePolkadotPallet,
 	},
 	polkadot_emulated_chain::{genesis::ED as POLKADOT_ED, PolkadotRelayPallet as PolkadotPallet},
-	PenpalAPara as PenpalA, PeoplePolkadotPara as PeoplePolkadot,
-	PeoplePolkadotParaReceiver as PeoplePolkadotReceiver,
+	AssetHubPolkadotPara as AssetHubPolkadot, PenpalAPara as PenpalA,
+	PeoplePolkadotPara as PeoplePolkadot, PeoplePolkadotParaReceiver as PeoplePolkadotReceiver,
 	PeoplePolkadotParaSender as PeoplePolkadotSender, PolkadotRelay as Polkadot,
 	PolkadotRelayReceiver as PolkadotReceiver, PolkadotRelaySender as PolkadotSender,
 };
@@ -0,0 +1,128 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::*;
+use emulated_integration_tests_common::accounts::{ALICE, BOB};
+use pallet_identity::Data;
+use people_polkadot_runtime::people::IdentityInfo;
+
+/// Sends `Transact(call)` from `sender` on Asset Hub to the People chain, aliasing into the
+/// account `alias` there. The fees are paid by the account derived from `sender` on Asset Hub.
+fn send_transact_as_alias_from_asset_hub(
+	sender: AccountId,
+	alias: AccountId,
+	call: <PeoplePolkadot as Chain>::RuntimeCall,
+) {
+	let fees: Asset = (Parent, POLKADOT_ED * 10).into();
+	let sender_on_people = Location::new(
+		1,
+		[
+			Parachain(AssetHubPolkadot::para_id().into()),
+			AccountId32Junction { network: Some(NetworkId::Polkadot), id: sender.clone().into() },
+		],
+	);
+	PeoplePolkadot::fund_accounts(vec![(
+		PeoplePolkadot::sovereign_account_id_of(sender_on_people.clone()),
+		POLKADOT_ED * 100,
+	)]);
+
+	AssetHubPolkadot::execute_with(|| {
+		type RuntimeEvent = <AssetHubPolkadot as Chain>::RuntimeEvent;
+
+		let xcm = Xcm::<()>(vec![
+			WithdrawAsset(fees.clone().into()),
+			BuyExecution { fees, weight_limit: Unlimited },
+			AliasOrigin(
+				AccountId32Junction { network: Some(NetworkId::Polkadot), id: alias.into() }.into(),
+			),
+			Transact {
+				origin_kind: OriginKind::SovereignAccount,
+				require_weight_at_most: Weight::from_parts(5_000_000_000, 500_000),
+				call: call.encode().into(),
+			},
+			RefundSurplus,
+			DepositAsset { assets: Wild(AllCounted(1)), beneficiary: sender_on_people },
+		]);
+		assert_ok!(<AssetHubPolkadot as AssetHubPolkadotPallet>::PolkadotXcm::send(
+			<AssetHubPolkadot as Chain>::RuntimeOrigin::signed(sender),
+			bx!(VersionedLocation::from(Location::new(
+				1,
+				[Parachain(PeoplePolkadot::para_id().into())]
+			))),
+			bx!(VersionedXcm::from(xcm)),
+		));
+
+		assert_expected_events!(
+			AssetHubPolkadot,
+			vec![
+				RuntimeEvent::PolkadotXcm(pallet_xcm::Event::Sent { .. }) => {},
+			]
+		);
+	});
+}
+
+fn set_identity_call() -> <PeoplePolkadot as Chain>::RuntimeCall {
+	type PeopleRuntime = <PeoplePolkadot as Chain>::Runtime;
+
+	let identity_info = IdentityInfo {
+		display: Data::Raw(b"alice".to_vec().try_into().unwrap()),
+		..Default::default()
+	};
+	<PeoplePolkadot as Chain>::RuntimeCall::Identity(
+		pallet_identity::Call::<PeopleRuntime>::set_identity { info: Box::new(identity_info) },
+	)
+}
+
+/// An account on Asset Hub can manage its identity on the People chain as itself.
+#[test]
+fn asset_hub_account_sets_own_identity_on_people() {
+	let alice = AssetHubPolkadot::account_id_of(ALICE);
+
+	send_transact_as_alias_from_asset_hub(alice.clone(), alice.clone(), set_identity_call());
+
+	PeoplePolkadot::execute_with(|| {
+		type RuntimeEvent = <PeoplePolkadot as Chain>::RuntimeEvent;
+
+		assert_expected_events!(
+			PeoplePolkadot,
+			vec![
+				RuntimeEvent::Identity(pallet_identity::Event::IdentitySet { who }) => {
+					who: *who == alice,
+				},
+				RuntimeEvent::MessageQueue(pallet_message_queue::Event::Processed { success: true, .. }) => {},
+			]
+		);
+	});
+}
+
+/// An account on Asset Hub can not alias into another account on the People chain.
+#[test]
+fn asset_hub_account_cannot_alias_into_other_account_on_people() {
+	let alice = AssetHubPolkadot::account_id_of(ALICE);
+	let bob = PeoplePolkadot::account_id_of(BOB);
+
+	send_transact_as_alias_from_asset_hub(alice, bob, set_identity_call());
+
+	PeoplePolkadot::execute_with(|| {
+		type RuntimeEvent = <PeoplePolkadot as Chain>::RuntimeEvent;
+
+		assert_expected_events!(
+			PeoplePolkadot,
+			vec![
+				RuntimeEvent::MessageQueue(pallet_message_queue::Event::Processed { success: false, .. }) => {},
+			]
+		);
+	});
+}
@@ -0,0 +1,266 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Test harness for the runtime upgrade migrations of the runtimes.
+//!
+//! Runs the migrations of a runtime on its genesis or on a state snapshot and checks that they are
+//! idempotent, leave every pallet at its in-code storage version and are removed once applied.
+
+use frame_support::{
+	migrations::MultiStepMigrator,
+	storage::{storage_prefix, unhashed, with_transaction},
+	traits::{
+		BeforeAllRuntimeMigrations, OnGenesis, OnRuntimeUpgrade, PalletsInfoAccess, StorageVersion,
+		STORAGE_VERSION_STORAGE_KEY_POSTFIX,
+	},
+	weights::Weight,
+};
+use remote_externalities::{Builder, Mode, OfflineConfig};
+use sp_runtime::{
+	traits::Block as BlockT, DeserializeOwned, DispatchError, StateVersion, TransactionOutcome,
+};
+use std::collections::BTreeMap;
+
+const LOG_TARGET: &str = "runtime::migration-tests";
+
+/// Environment variable with the path of the state snapshot to run the migrations on.
+pub const SNAPSHOT_ENV: &str = "SNAP";
+
+/// Maximum number of blocks the multi-block migrations of an upgrade may take.
+const MAX_MULTI_BLOCK_STEPS: u32 = 10_000;
+
+/// A tuple of migrations, which are checked one by one.
+pub trait Migrations {
+	/// Call `f` with the name and the upgrade function of every migration, in order.
+	fn for_each(f: &mut dyn FnMut(&'static str, fn() -> Weight));
+}
+
+#[impl_trait_for_tuples::impl_for_tuples(64)]
+#[tuple_types_custom_trait_bound(OnRuntimeUpgrade)]
+impl Migrations for Tuple {
+	fn for_each(f: &mut dyn FnMut(&'static str, fn() -> Weight)) {
+		for_tuples!( #( f(core::any::type_name::<Tuple>(), Tuple::on_runtime_upgrade); )* );
+	}
+}
+
+/// Implement the test checking the migrations of a runtime with [`check_migrations`], on the
+/// genesis of the given preset if no state snapshot is set.
+///
+/// Expects the runtime crate, e.g. `crate` or `asset_hub_polkadot_runtime`, to have the
+/// `Runtime`, `AllPalletsWithSystem`, `RuntimeGenesisConfig`, `migrations::Unreleased`,
+/// `migrations::Permanent` and `genesis_config_presets::get_preset` items.
+///
+/// ```ignore
+/// migration_test_utils::impl_migration_tests!(crate, "development");
+/// ```
+#[macro_export]
+macro_rules! impl_migration_tests {
+	($runtime:ident, $preset:literal) => {
+		#[test]
+		fn migrations_are_idempotent_and_complete() {
+			$crate::check_migrations::<
+				$runtime::Runtime,
+				$runtime::AllPalletsWithSystem,
+				$runtime::migrations::Unreleased,
+				$runtime::migrations::Permanent,
+			>(|| {
+				let preset = $runtime::genesis_config_presets::get_preset(&$preset.into())
+					.expect(concat!("the `", $preset, "` preset exists"));
+				$crate::build_state::<$runtime::RuntimeGenesisConfig>(preset)
+					.expect("the genesis of the preset can be built");
+			});
+		}
+	};
+}
+
+#[doc(hidden)]
+pub use frame_support::genesis_builder_helper::build_state;
+
+/// Run the migrations of `Runtime` and check that they can be applied safely.
+///
+/// The migrations run on the state snapshot at the path in [`SNAPSHOT_ENV`] if it is set, or else
+/// on the genesis written by `genesis`. The upgrade is run like `frame_executive` does, with the
+/// `Unreleased` migrations one after the other, followed by the `Permanent` ones and the hooks of
+/// `AllPallets`. The multi-block migrations it starts are stepped until they are completed.
+///
+/// Panics if:
+/// - any step of upgrading a second time changes the storage,
+/// - a pallet is not at its in-code storage version after the upgrade,
+/// - a migration of `Unreleased` does not change a snapshot, i.e. it is still listed after its
+///   storage version was reached. Not checked on genesis, which is at the in-code versions already.
+pub fn check_migrations<Runtime, AllPallets, Unreleased, Permanent>(genesis: impl FnOnce())
+where
+	Runtime: frame_system::Config,
+	Runtime::Block: DeserializeOwned,
+	<Runtime::Block as BlockT>::Hash: DeserializeOwned,
+	<Runtime::Block as BlockT>::Header: DeserializeOwned,
+	AllPallets: BeforeAllRuntimeMigrations + OnGenesis + OnRuntimeUpgrade + PalletsInfoAccess,
+	Unreleased: Migrations,
+	Permanent: OnRuntimeUpgrade,
+{
+	let Ok(snapshot) = std::env::var(SNAPSHOT_ENV) else {
+		sp_io::TestExternalities::default().execute_with(|| {
+			genesis();
+			check::<Runtime, AllPallets, Unreleased, Permanent>(false)
+		});
+		return
+	};
+
+	log::info!(target: LOG_TARGET, "Running the migrations on the snapshot {snapshot}");
+	let mut ext = tokio::runtime::Builder::new_current_thread()
+		.build()
+		.expect("the tokio runtime can be built")
+		.block_on(
+			Builder::<Runtime::Block>::default()
+				.mode(Mode::Offline(OfflineConfig { state_snapshot: snapshot.into() }))
+				.build(),
+		)
+		.expect("the state snapshot can be loaded");
+	ext.execute_with(|| check::<Runtime, AllPallets, Unreleased, Permanent>(true));
+}
+
+/// A part of the upgrade, checked on its own.
+struct Step {
+	name: &'static str,
+	unreleased: bool,
+	changed_storage: bool,
+}
+
+fn check<Runtime, AllPallets, Unreleased, Permanent>(on_snapshot: bool)
+where
+	Runtime: frame_system::Config,
+	AllPallets: BeforeAllRuntimeMigrations + OnGenesis + OnRuntimeUpgrade + PalletsInfoAccess,
+	Unreleased: Migrations,
+	Permanent: OnRuntimeUpgrade,
+{
+	let in_code_versions = in_code_storage_versions::<AllPallets>();
+
+	let first = upgrade::<Runtime, AllPallets, Unreleased, Permanent>();
+
+	let mismatched_versions: Vec<_> = AllPallets::infos()
+		.into_iter()
+		.filter_map(|info| {
+			let on_chain = on_chain_storage_version(info.name);
+			let in_code = in_code_versions[info.name];
+			(on_chain != in_code)
+				.then(|| format!("{}: on chain {on_chain:?}, in code {in_code:?}", info.name))
+		})
+		.collect();
+	assert!(
+		mismatched_versions.is_empty(),
+		"pallets not at their in-code storage version after the upgrade: {mismatched_versions:#?}"
+	);
+
+	if on_snapshot {
+		let stale: Vec<_> = first
+			.iter()
+			.filter(|step| step.unreleased && !step.changed_storage)
+			.map(|step| step.name)
+			.collect();
+		assert!(stale.is_empty(), "migrations without any effect, remove them: {stale:#?}");
+	}
+
+	// Every step of the upgrade has to find its work done already, so that a migration which is
+	// applied again is caught even if another step happens to undo its writes.
+	let second = upgrade::<Runtime, AllPallets, Unreleased, Permanent>();
+	let changed: Vec<_> = second
+		.iter()
+		.filter(|step| step.changed_storage)
+		.map(|step| step.name)
+		.collect();
+	assert!(changed.is_empty(), "steps changing the storage when run again: {changed:#?}");
+}
+
+/// Run the upgrade, returning the outcome of all of its steps in order.
+fn upgrade<Runtime, AllPallets, Unreleased, Permanent>() -> Vec<Step>
+where
+	Runtime: frame_system::Config,
+	AllPallets: BeforeAllRuntimeMigrations + OnRuntimeUpgrade,
+	Unreleased: Migrations,
+	Permanent: OnRuntimeUpgrade,
+{
+	let mut steps = Vec::new();
+	let mut run = |name, unreleased, step: fn() -> Weight| {
+		let root_before = storage_root::<Runtime>();
+		let weight = step();
+		let changed_storage = storage_root::<Runtime>() != root_before;
+		log::debug!(target: LOG_TARGET, "{name}: changed storage {changed_storage}, {weight:?}");
+		steps.push(Step { name, unreleased, changed_storage });
+	};
+
+	run("BeforeAllRuntimeMigrations", false, AllPallets::before_all_runtime_migrations);
+	Unreleased::for_each(&mut |name, migration| run(name, true, migration));
+	run(core::any::type_name::<Permanent>(), false, Permanent::on_runtime_upgrade);
+	run(
+		"SingleBlockMigrations",
+		false,
+		<Runtime as frame_system::Config>::SingleBlockMigrations::on_runtime_upgrade,
+	);
+	run(
+		"AllPalletsWithSystem and MultiBlockMigrations",
+		false,
+		run_multi_block_migrations::<Runtime, AllPallets>,
+	);
+	steps
+}
+
+/// Run the hooks of `AllPallets` and step the multi-block migrations they start until they are
+/// completed.
+///
+/// Starting the multi-block migrations sets the cursor of `pallet_migrations`, which is only
+/// cleared by their last step, so both are one step of the upgrade.
+fn run_multi_block_migrations<Runtime, AllPallets>() -> Weight
+where
+	Runtime: frame_system::Config,
+	AllPallets: OnRuntimeUpgrade,
+{
+	let mut weight = AllPallets::on_runtime_upgrade();
+	for _ in 0..MAX_MULTI_BLOCK_STEPS {
+		if !Runtime::MultiBlockMigrator::ongoing() {
+			return weight
+		}
+		weight.saturating_accrue(Runtime::MultiBlockMigrator::step());
+	}
+	panic!("multi-block migrations not completed after {MAX_MULTI_BLOCK_STEPS} blocks");
+}
+
+fn storage_root<Runtime: frame_system::Config>() -> Vec<u8> {
+	// The events of the upgrade are not part of its outcome, they are cleared in the next block.
+	frame_system::Pallet::<Runtime>::reset_events();
+	sp_io::storage::root(StateVersion::V1)
+}
+
+/// The in-code storage versions of all pallets, by pallet name.
+fn in_code_storage_versions<AllPallets: OnGenesis + PalletsInfoAccess>(
+) -> BTreeMap<&'static str, StorageVersion> {
+	// `on_genesis` puts the in-code storage version of every pallet, which is rolled back again.
+	with_transaction(|| {
+		AllPallets::on_genesis();
+		let versions = AllPallets::infos()
+			.into_iter()
+			.map(|info| (info.name, on_chain_storage_version(info.name)))
+			.collect();
+		TransactionOutcome::Rollback(Ok::<_, DispatchError>(versions))
+	})
+	.expect("reading the storage versions can not fail")
+}
+
+fn on_chain_storage_version(pallet: &str) -> StorageVersion {
+	unhashed::get_or_default(&storage_prefix(
+		pallet.as_bytes(),
+		STORAGE_VERSION_STORAGE_KEY_POSTFIX,
+	))
+}
@@ -0,0 +1,107 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Pallet for a system parachain, e.g. Asset Hub, to operate the HRMP channels of paras on their
+//! behalf.
+//!
+//! The deposits of HRMP channels are held by the sovereign accounts of the paras on the Relay
+//! Chain. A para holding the Relay Chain token on Asset Hub instead asks Asset Hub to teleport the
+//! deposits to its sovereign account here and to request or accept the channel for it, in the same
+//! message. Only the paras configured as operators are trusted to act on behalf of other paras,
+//! and only on behalf of the paras which asked them to. An operator opens its own channels through
+//! the HRMP pallet directly.
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub use pallet::*;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use frame_support::{pallet_prelude::*, traits::Contains};
+	use frame_system::pallet_prelude::*;
+	use polkadot_parachain_primitives::primitives::Id as ParaId;
+	use runtime_parachains::{
+		ensure_parachain,
+		hrmp::{self, WeightInfo as _},
+		origin as parachains_origin,
+	};
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config + hrmp::Config {
+		/// The origin of a para, on behalf of which its channels are operated.
+		type ParaOrigin: From<parachains_origin::Origin> + Into<OriginFor<Self>>;
+
+		/// The paras operating the channels of other paras on their behalf, e.g. Asset Hub.
+		type Operators: Contains<ParaId>;
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Request to open a channel from `para` to `recipient`, on behalf of `para`.
+		///
+		/// The origin must be one of the [`Config::Operators`], other than `para`. The sovereign
+		/// account of `para` holds the deposit of the request.
+		#[pallet::call_index(0)]
+		#[pallet::weight(<T as hrmp::Config>::WeightInfo::hrmp_init_open_channel())]
+		pub fn init_open_channel(
+			origin: OriginFor<T>,
+			para: ParaId,
+			recipient: ParaId,
+			proposed_max_capacity: u32,
+			proposed_max_message_size: u32,
+		) -> DispatchResult {
+			Self::ensure_operator(origin, para)?;
+			hrmp::Pallet::<T>::hrmp_init_open_channel(
+				Self::para_origin(para),
+				recipient,
+				proposed_max_capacity,
+				proposed_max_message_size,
+			)
+		}
+
+		/// Accept the request to open a channel from `sender` to `para`, on behalf of `para`.
+		///
+		/// The origin must be one of the [`Config::Operators`], other than `para`. The sovereign
+		/// account of `para` holds the deposit of the channel.
+		#[pallet::call_index(1)]
+		#[pallet::weight(<T as hrmp::Config>::WeightInfo::hrmp_accept_open_channel())]
+		pub fn accept_open_channel(
+			origin: OriginFor<T>,
+			para: ParaId,
+			sender: ParaId,
+		) -> DispatchResult {
+			Self::ensure_operator(origin, para)?;
+			hrmp::Pallet::<T>::hrmp_accept_open_channel(Self::para_origin(para), sender)
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		fn ensure_operator(origin: OriginFor<T>, para: ParaId) -> DispatchResult {
+			let operator = ensure_parachain(<T as hrmp::Config>::RuntimeOrigin::from(origin))?;
+			ensure!(
+				T::Operators::contains(&operator) && operator != para,
+				DispatchError::BadOrigin
+			);
+			Ok(())
+		}
+
+		fn para_origin(para: ParaId) -> OriginFor<T> {
+			T::ParaOrigin::from(parachains_origin::Origin::Parachain(para)).into()
+		}
+	}
+}
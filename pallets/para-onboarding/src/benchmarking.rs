@@ -0,0 +1,56 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Benchmarks for `pallet_para_onboarding`.
+
+use super::*;
+use frame_benchmarking::v2::*;
+use frame_support::traits::{Currency, StorePreimage};
+use frame_system::RawOrigin;
+use polkadot_primitives::{HeadData, MAX_CODE_SIZE, MIN_CODE_SIZE};
+use polkadot_runtime_common::paras_registrar;
+use runtime_parachains::{configuration, paras};
+use sp_runtime::traits::Bounded;
+
+type BalanceOf<T> = <<T as paras_registrar::Config>::Currency as Currency<
+	<T as frame_system::Config>::AccountId,
+>>::Balance;
+
+#[benchmarks]
+mod benchmarks {
+	use super::*;
+
+	#[benchmark]
+	fn register(c: Linear<MIN_CODE_SIZE, MAX_CODE_SIZE>) -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = whitelisted_caller();
+		<T as paras_registrar::Config>::Currency::make_free_balance_be(
+			&caller,
+			BalanceOf::<T>::max_value(),
+		);
+		let config = configuration::ActiveConfig::<T>::get();
+		let code_len = c.min(config.max_code_size);
+		let code_hash = T::Preimages::note(alloc::vec![0; code_len as usize].into())
+			.map_err(|_| BenchmarkError::Stop("cannot note the validation code"))?;
+		let genesis_head = HeadData(alloc::vec![0; config.max_head_data_size as usize]);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), genesis_head, code_hash, code_len);
+
+		let id = u32::from(paras_registrar::NextFreeParaId::<T>::get()).saturating_sub(1);
+		assert!(paras::Pallet::<T>::lifecycle(id.into()).is_some());
+		Ok(())
+	}
+}
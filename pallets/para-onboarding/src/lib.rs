@@ -0,0 +1,95 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Pallet to register parachains with validation code noted as a preimage.
+//!
+//! The validation code of a parachain is too large to be sent in an XCM, e.g. from Asset Hub. It is
+//! noted as a preimage on the Relay Chain instead, by anyone, and [`Pallet::register`] reserves a
+//! para id and registers the para with it on behalf of the caller, who holds the deposits of the
+//! registrar. The noter of the preimage can unnote it once the para is registered.
+//!
+//! Noting the preimage is a transaction on the Relay Chain, the noter paying for it and holding
+//! the deposit of the preimage there. A team holding the Relay Chain token on Asset Hub only
+//! teleports some of it to its account on the Relay Chain to do so.
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+pub use pallet::*;
+pub use weights::WeightInfo;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+pub mod weights;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::{
+		pallet_prelude::*,
+		traits::{QueryPreimage, StorePreimage},
+	};
+	use frame_system::pallet_prelude::*;
+	use polkadot_primitives::{HeadData, Id as ParaId, ValidationCode};
+	use polkadot_runtime_common::paras_registrar;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config + paras_registrar::Config {
+		/// The preimages of the validation code of the paras.
+		type Preimages: QueryPreimage<H = Self::Hashing> + StorePreimage;
+
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The validation code is not noted as a preimage, or not of the given length.
+		CodeNotNoted,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Reserve a para id and register the para with `genesis_head` and the validation code
+		/// noted as the preimage `code_hash` of `code_len` bytes.
+		///
+		/// The caller is the manager of the para and holds the deposits of the registrar.
+		#[pallet::call_index(0)]
+		#[pallet::weight(<T as Config>::WeightInfo::register(*code_len))]
+		pub fn register(
+			origin: OriginFor<T>,
+			genesis_head: HeadData,
+			code_hash: T::Hash,
+			code_len: u32,
+		) -> DispatchResult {
+			let code = T::Preimages::fetch(&code_hash, Some(code_len))
+				.map_err(|_| Error::<T>::CodeNotNoted)?;
+
+			paras_registrar::Pallet::<T>::reserve(origin.clone())?;
+			let id: ParaId =
+				u32::from(paras_registrar::NextFreeParaId::<T>::get()).saturating_sub(1).into();
+			paras_registrar::Pallet::<T>::register(
+				origin,
+				id,
+				genesis_head,
+				ValidationCode(code.into_owned()),
+			)
+		}
+	}
+}
@@ -0,0 +1,168 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Benchmarks for `pallet_relay_calls`.
+
+use super::*;
+use frame_benchmarking::v2::*;
+use frame_support::traits::EnsureOrigin;
+use frame_system::RawOrigin;
+use xcm_builder::EnsureDelivery;
+use xcm_executor::traits::{FeeReason, TransactAsset};
+
+type RuntimeOrigin<T> = <T as frame_system::Config>::RuntimeOrigin;
+
+/// The amount of the Relay Chain token teleported by the benchmarked calls.
+const AMOUNT: u128 = 1_000_000_000_000;
+
+/// Wrapper of the pallet with the configuration of its benchmarks.
+pub struct Pallet<T: Config>(crate::Pallet<T>);
+
+/// The configuration of the benchmarks of the pallet.
+pub trait Config: crate::Config {
+	/// Prepares the delivery of the messages to the Relay Chain.
+	type DeliveryHelper: EnsureDelivery;
+
+	/// The origin of a sibling para, if sibling paras are allowed to call this chain.
+	fn sibling_para_origin() -> Option<RuntimeOrigin<Self>>;
+}
+
+/// Fund `location` with the Relay Chain token it teleports and pays the delivery with.
+fn fund<T: Config>(location: &Location) -> Result<(), BenchmarkError> {
+	T::DeliveryHelper::ensure_successful_delivery(
+		location,
+		&Location::parent(),
+		FeeReason::ChargeFees,
+	);
+	T::AssetTransactor::deposit_asset(&(Parent, 2 * AMOUNT).into(), location, None)
+		.map_err(|_| BenchmarkError::Stop("cannot fund the caller"))
+}
+
+/// A signed origin able to send to the Relay Chain, funded.
+fn funded_signed_origin<T: Config>() -> Result<RuntimeOrigin<T>, BenchmarkError> {
+	let caller: T::AccountId = whitelisted_caller();
+	let origin: RuntimeOrigin<T> = RawOrigin::Signed(caller).into();
+	let location = <T as pallet_xcm::Config>::SendXcmOrigin::try_origin(origin.clone())
+		.map_err(|_| BenchmarkError::Stop("signed origins cannot send to the Relay Chain"))?;
+	fund::<T>(&location)?;
+	Ok(origin)
+}
+
+/// The origin of a sibling para, with its sovereign account funded.
+fn funded_sibling_para_origin<T: Config>() -> Result<RuntimeOrigin<T>, BenchmarkError> {
+	let origin = T::sibling_para_origin().ok_or(BenchmarkError::Skip)?;
+	let location = T::SiblingParaOrigin::try_origin(origin.clone())
+		.map_err(|_| BenchmarkError::Stop("not the origin of a sibling para"))?;
+	fund::<T>(&location)?;
+	Ok(origin)
+}
+
+#[benchmarks]
+mod benchmarks {
+	use super::*;
+
+	#[benchmark]
+	fn place_on_demand_order() -> Result<(), BenchmarkError> {
+		let origin = funded_signed_origin::<T>()?;
+
+		#[block]
+		{
+			crate::Pallet::<T>::place_on_demand_order(origin, 2000.into(), AMOUNT / 2, AMOUNT / 2)
+				.map_err(|_| BenchmarkError::Stop("cannot place the order"))?;
+		}
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn register_para() -> Result<(), BenchmarkError> {
+		let origin = funded_signed_origin::<T>()?;
+		// The largest head data allowed by the configuration of the Relay Chains.
+		let genesis_head = HeadData(alloc::vec![0; 20 * 1024]);
+
+		#[block]
+		{
+			crate::Pallet::<T>::register_para(
+				origin,
+				genesis_head,
+				H256::repeat_byte(1),
+				1024,
+				AMOUNT / 2,
+				AMOUNT / 2,
+			)
+			.map_err(|_| BenchmarkError::Stop("cannot register the para"))?;
+		}
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn deregister_para() -> Result<(), BenchmarkError> {
+		let origin = funded_signed_origin::<T>()?;
+
+		#[block]
+		{
+			crate::Pallet::<T>::deregister_para(
+				origin,
+				2000.into(),
+				AMOUNT / 2,
+				AMOUNT / 4,
+				AMOUNT / 2,
+			)
+			.map_err(|_| BenchmarkError::Stop("cannot deregister the para"))?;
+		}
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn init_open_hrmp_channel() -> Result<(), BenchmarkError> {
+		let origin = funded_sibling_para_origin::<T>()?;
+
+		#[block]
+		{
+			crate::Pallet::<T>::init_open_hrmp_channel(
+				origin,
+				2000.into(),
+				8,
+				1024,
+				AMOUNT / 2,
+				AMOUNT / 2,
+			)
+			.map_err(|_| BenchmarkError::Stop("cannot request the channel"))?;
+		}
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn accept_open_hrmp_channel() -> Result<(), BenchmarkError> {
+		let origin = funded_sibling_para_origin::<T>()?;
+
+		#[block]
+		{
+			crate::Pallet::<T>::accept_open_hrmp_channel(
+				origin,
+				2000.into(),
+				AMOUNT / 2,
+				AMOUNT / 2,
+			)
+			.map_err(|_| BenchmarkError::Stop("cannot accept the channel"))?;
+		}
+
+		Ok(())
+	}
+}
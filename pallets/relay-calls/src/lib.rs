@@ -0,0 +1,569 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Pallet to dispatch calls on the Relay Chain with funds held on a system parachain.
+//!
+//! Every call of this pallet teleports some of the Relay Chain token of the caller to the Relay
+//! Chain together with the calls to dispatch there. The Relay Chain buys the execution with part of
+//! the teleported funds, aliases the origin into the account of the caller, deposits the rest of
+//! the funds into it and dispatches the calls from it. Whatever is not spent stays in the account
+//! of the caller on the Relay Chain.
+//!
+//! The fees of the execution on the Relay Chain are estimated by dry-running the call with the
+//! `DryRunApi` of this chain, the program forwarded to the Relay Chain being weighed and priced
+//! with the `XcmPaymentApi` of the Relay Chain. The weight of the program does not depend on the
+//! fees it carries, so the call can be dry-run with no fees first. The delivery fees are those of
+//! the program sent with the estimated fees, from the `XcmPaymentApi` of this chain.
+//!
+//! Paras are onboarded through the `pallet-para-onboarding` of the Relay Chain, their validation
+//! code being noted as a preimage there beforehand. The code is too large to be sent in a message,
+//! so noting it is a transaction on the Relay Chain, paid with the Relay Chain token teleported
+//! there from this chain, e.g. with `pallet_xcm::limited_teleport_assets`.
+//!
+//! Sibling paras operate their HRMP channels with the Relay Chain token held by their sovereign
+//! accounts on this chain. The deposits are teleported to their sovereign accounts on the Relay
+//! Chain, and the channels are requested or accepted on their behalf through the
+//! `pallet-para-channels` of the Relay Chain.
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+pub use pallet::*;
+pub use weights::WeightInfo;
+
+#[cfg(feature = "runtime-benchmarks")]
+pub mod benchmarking;
+pub mod weights;
+
+use alloc::vec::Vec;
+use codec::Encode;
+use frame_support::weights::Weight;
+use polkadot_parachain_primitives::primitives::{HeadData, Id as ParaId};
+use sp_core::H256;
+use xcm::latest::prelude::*;
+
+/// Call encoding for the calls needed from the on-demand assignment provider pallet of the Relay
+/// Chain.
+#[derive(Encode)]
+enum OnDemandCalls {
+	#[codec(index = 0)]
+	PlaceOrderAllowDeath { max_amount: u128, para_id: ParaId },
+}
+
+/// Call encoding for the calls needed from the para channels pallet of the Relay Chain.
+#[derive(Encode)]
+enum ParaChannelsCalls {
+	#[codec(index = 0)]
+	InitOpenChannel {
+		para: ParaId,
+		recipient: ParaId,
+		proposed_max_capacity: u32,
+		proposed_max_message_size: u32,
+	},
+	#[codec(index = 1)]
+	AcceptOpenChannel { para: ParaId, sender: ParaId },
+}
+
+/// Call encoding for the calls needed from the paras registrar pallet of the Relay Chain.
+#[derive(Encode)]
+enum RegistrarCalls {
+	#[codec(index = 2)]
+	Deregister { id: ParaId },
+}
+
+/// Call encoding for the calls needed from the para onboarding pallet of the Relay Chain.
+#[derive(Encode)]
+enum ParaOnboardingCalls {
+	#[codec(index = 0)]
+	Register { genesis_head: HeadData, code_hash: H256, code_len: u32 },
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::*;
+	use sp_runtime::ArithmeticError;
+	use xcm_executor::traits::TransactAsset;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config + pallet_xcm::Config {
+		/// The overarching event type.
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// Withdraws the Relay Chain token from the callers and checks it out of this chain as it
+		/// is teleported.
+		type AssetTransactor: TransactAsset;
+
+		/// The index of the on-demand assignment provider pallet in the Relay Chain runtime.
+		#[pallet::constant]
+		type OnDemandPalletIndex: Get<u8>;
+
+		/// The weight of `place_order_allow_death` on the Relay Chain.
+		#[pallet::constant]
+		type OnDemandOrderWeight: Get<Weight>;
+
+		/// The index of the paras registrar pallet in the Relay Chain runtime.
+		#[pallet::constant]
+		type RegistrarPalletIndex: Get<u8>;
+
+		/// The index of the para onboarding pallet in the Relay Chain runtime.
+		#[pallet::constant]
+		type ParaOnboardingPalletIndex: Get<u8>;
+
+		/// The weight of `register` of the para onboarding pallet on the Relay Chain, not counting
+		/// the proof of the validation code.
+		#[pallet::constant]
+		type RegisterParaWeight: Get<Weight>;
+
+		/// The weight of `deregister` of the paras registrar pallet on the Relay Chain.
+		#[pallet::constant]
+		type DeregisterParaWeight: Get<Weight>;
+
+		/// The existential deposit of the Relay Chain token on this chain, which the refunds of
+		/// deposits teleported back from the Relay Chain have to reach after their fees.
+		#[pallet::constant]
+		type ExistentialDeposit: Get<u128>;
+
+		/// The origin of the sibling paras operating their HRMP channels, as their location.
+		type SiblingParaOrigin: EnsureOrigin<
+			<Self as frame_system::Config>::RuntimeOrigin,
+			Success = Location,
+		>;
+
+		/// The index of the para channels pallet in the Relay Chain runtime.
+		#[pallet::constant]
+		type ParaChannelsPalletIndex: Get<u8>;
+
+		/// The weight of `init_open_channel` of the para channels pallet on the Relay Chain.
+		#[pallet::constant]
+		type InitOpenHrmpChannelWeight: Get<Weight>;
+
+		/// The weight of `accept_open_channel` of the para channels pallet on the Relay Chain.
+		#[pallet::constant]
+		type AcceptOpenHrmpChannelWeight: Get<Weight>;
+
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// Calls of `origin` were sent to the Relay Chain with `amount` of its token.
+		Sent { origin: Location, amount: u128, message_id: XcmHash },
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The funds could not be withdrawn from the caller.
+		InsufficientFunds,
+		/// The funds cannot be teleported to the Relay Chain.
+		CannotCheckOut,
+		/// The fees of the delivery to the Relay Chain could not be paid.
+		FeesNotMet,
+		/// The message could not be sent to the Relay Chain.
+		SendFailure,
+		/// This chain cannot be addressed from the Relay Chain.
+		NotReachable,
+		/// The refund does not cover its fees and the existential deposit on this chain.
+		RefundTooLow,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Place an order for an on-demand core for `para_id` on the Relay Chain.
+		///
+		/// Teleports `max_amount + fees` of the Relay Chain token of the caller to its account on
+		/// the Relay Chain, `fees` paying for the execution there. The order is placed from that
+		/// account, for at most `max_amount`.
+		///
+		/// The execution fees can be estimated as described in the [crate] documentation.
+		#[pallet::call_index(0)]
+		#[pallet::weight(<T as Config>::WeightInfo::place_on_demand_order())]
+		pub fn place_on_demand_order(
+			origin: OriginFor<T>,
+			para_id: ParaId,
+			max_amount: u128,
+			fees: u128,
+		) -> DispatchResult {
+			let origin = <T as pallet_xcm::Config>::SendXcmOrigin::ensure_origin(origin)?;
+			ensure!(origin.parent_count() == 0, DispatchError::BadOrigin);
+			let amount = max_amount.checked_add(fees).ok_or(ArithmeticError::Overflow)?;
+
+			let message = Self::on_demand_order_xcm(&origin, para_id, max_amount, fees);
+			Self::send_to_relay(origin, amount, message)
+		}
+
+		/// Reserve a para id and register a para on the Relay Chain, with `genesis_head` and the
+		/// validation code noted as the preimage `code_hash` of `code_len` bytes there.
+		///
+		/// Teleports `deposit + fees` of the Relay Chain token of the caller to its account on the
+		/// Relay Chain, `fees` paying for the execution there. The caller becomes the manager of
+		/// the para, its account on the Relay Chain holding the deposits of the registrar.
+		///
+		/// The execution fees can be estimated as described in the [crate] documentation.
+		#[pallet::call_index(1)]
+		#[pallet::weight(<T as Config>::WeightInfo::register_para())]
+		pub fn register_para(
+			origin: OriginFor<T>,
+			genesis_head: HeadData,
+			code_hash: H256,
+			code_len: u32,
+			deposit: u128,
+			fees: u128,
+		) -> DispatchResult {
+			let origin = <T as pallet_xcm::Config>::SendXcmOrigin::ensure_origin(origin)?;
+			ensure!(origin.parent_count() == 0, DispatchError::BadOrigin);
+			let amount = deposit.checked_add(fees).ok_or(ArithmeticError::Overflow)?;
+
+			let message =
+				Self::register_para_xcm(&origin, genesis_head, code_hash, code_len, deposit, fees);
+			Self::send_to_relay(origin, amount, message)
+		}
+
+		/// Deregister the para `para_id` managed by the caller on the Relay Chain, teleporting
+		/// `refund` of the deposits released there back to the caller.
+		///
+		/// Teleports `fees` of the Relay Chain token of the caller to its account on the Relay
+		/// Chain, paying for the execution there and for the delivery of the refund. At most
+		/// `refund_fees` of the refund pay for its execution on this chain, the rest of it has to
+		/// reach the existential deposit. The refund is only made if the para is deregistered.
+		///
+		/// The execution fees can be estimated as described in the [crate] documentation.
+		#[pallet::call_index(2)]
+		#[pallet::weight(<T as Config>::WeightInfo::deregister_para())]
+		pub fn deregister_para(
+			origin: OriginFor<T>,
+			para_id: ParaId,
+			refund: u128,
+			refund_fees: u128,
+			fees: u128,
+		) -> DispatchResult {
+			let origin = <T as pallet_xcm::Config>::SendXcmOrigin::ensure_origin(origin)?;
+			ensure!(origin.parent_count() == 0, DispatchError::BadOrigin);
+			ensure!(
+				refund.saturating_sub(refund_fees) >= T::ExistentialDeposit::get().max(1),
+				Error::<T>::RefundTooLow
+			);
+
+			let message = Self::deregister_para_xcm(&origin, para_id, refund, refund_fees, fees)?;
+			Self::send_to_relay(origin, fees, message)
+		}
+
+		/// Request to open an HRMP channel from the calling sibling para to `recipient` on the
+		/// Relay Chain.
+		///
+		/// Teleports `deposit + fees` of the Relay Chain token of the sovereign account of the
+		/// para on this chain to its sovereign account on the Relay Chain, `fees` paying for the
+		/// execution there. The request is made on behalf of the para, whose sovereign account on
+		/// the Relay Chain holds the deposit.
+		///
+		/// The execution fees can be estimated as described in the [crate] documentation.
+		#[pallet::call_index(3)]
+		#[pallet::weight(<T as Config>::WeightInfo::init_open_hrmp_channel())]
+		pub fn init_open_hrmp_channel(
+			origin: OriginFor<T>,
+			recipient: ParaId,
+			proposed_max_capacity: u32,
+			proposed_max_message_size: u32,
+			deposit: u128,
+			fees: u128,
+		) -> DispatchResult {
+			let (origin, para_id) = Self::ensure_sibling_para(origin)?;
+			let amount = deposit.checked_add(fees).ok_or(ArithmeticError::Overflow)?;
+
+			let message = Self::init_open_hrmp_channel_xcm(
+				para_id,
+				recipient,
+				proposed_max_capacity,
+				proposed_max_message_size,
+				deposit,
+				fees,
+			);
+			Self::send_to_relay(origin, amount, message)
+		}
+
+		/// Accept the request to open an HRMP channel from `sender` to the calling sibling para on
+		/// the Relay Chain.
+		///
+		/// Teleports `deposit + fees` of the Relay Chain token of the sovereign account of the
+		/// para on this chain to its sovereign account on the Relay Chain, `fees` paying for the
+		/// execution there. The channel is accepted on behalf of the para, whose sovereign account
+		/// on the Relay Chain holds the deposit.
+		///
+		/// The execution fees can be estimated as described in the [crate] documentation.
+		#[pallet::call_index(4)]
+		#[pallet::weight(<T as Config>::WeightInfo::accept_open_hrmp_channel())]
+		pub fn accept_open_hrmp_channel(
+			origin: OriginFor<T>,
+			sender: ParaId,
+			deposit: u128,
+			fees: u128,
+		) -> DispatchResult {
+			let (origin, para_id) = Self::ensure_sibling_para(origin)?;
+			let amount = deposit.checked_add(fees).ok_or(ArithmeticError::Overflow)?;
+
+			let message = Self::accept_open_hrmp_channel_xcm(para_id, sender, deposit, fees);
+			Self::send_to_relay(origin, amount, message)
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// The program sent to the Relay Chain by [`Pallet::place_on_demand_order`].
+		fn on_demand_order_xcm(
+			origin: &Location,
+			para_id: ParaId,
+			max_amount: u128,
+			fees: u128,
+		) -> Xcm<()> {
+			let call = OnDemandCalls::PlaceOrderAllowDeath { max_amount, para_id };
+			Self::relay_xcm(
+				origin,
+				max_amount.saturating_add(fees),
+				fees,
+				Self::transact(
+					(T::OnDemandPalletIndex::get(), call),
+					T::OnDemandOrderWeight::get(),
+				),
+			)
+		}
+
+		/// The program sent to the Relay Chain by [`Pallet::register_para`].
+		fn register_para_xcm(
+			origin: &Location,
+			genesis_head: HeadData,
+			code_hash: H256,
+			code_len: u32,
+			deposit: u128,
+			fees: u128,
+		) -> Xcm<()> {
+			let call = ParaOnboardingCalls::Register { genesis_head, code_hash, code_len };
+			let weight =
+				T::RegisterParaWeight::get().saturating_add(Weight::from_parts(0, code_len.into()));
+			Self::relay_xcm(
+				origin,
+				deposit.saturating_add(fees),
+				fees,
+				Self::transact((T::ParaOnboardingPalletIndex::get(), call), weight),
+			)
+		}
+
+		/// The program sent to the Relay Chain by [`Pallet::deregister_para`].
+		fn deregister_para_xcm(
+			origin: &Location,
+			para_id: ParaId,
+			refund: u128,
+			refund_fees: u128,
+			fees: u128,
+		) -> Result<Xcm<()>, Error<T>> {
+			let this_chain = Location::here()
+				.reanchored(
+					&Location::parent(),
+					&<T as pallet_xcm::Config>::UniversalLocation::get(),
+				)
+				.map_err(|_| Error::<T>::NotReachable)?;
+			let call = RegistrarCalls::Deregister { id: para_id };
+
+			let mut xcm = Self::transact(
+				(T::RegistrarPalletIndex::get(), call),
+				T::DeregisterParaWeight::get(),
+			);
+			xcm.extend([
+				ExpectTransactStatus(MaybeErrorCode::Success),
+				WithdrawAsset((Here, refund).into()),
+				InitiateTeleport {
+					assets: Definite((Here, refund).into()),
+					dest: this_chain,
+					xcm: Xcm(alloc::vec![
+						BuyExecution {
+							fees: (Parent, refund_fees).into(),
+							weight_limit: Unlimited,
+						},
+						DepositAsset { assets: Wild(AllCounted(1)), beneficiary: origin.clone() },
+					]),
+				},
+			]);
+			Ok(Self::relay_xcm(origin, fees, fees, xcm))
+		}
+
+		/// The program sent to the Relay Chain by [`Pallet::init_open_hrmp_channel`] of the
+		/// sibling para `para_id`.
+		fn init_open_hrmp_channel_xcm(
+			para_id: ParaId,
+			recipient: ParaId,
+			proposed_max_capacity: u32,
+			proposed_max_message_size: u32,
+			deposit: u128,
+			fees: u128,
+		) -> Xcm<()> {
+			let call = ParaChannelsCalls::InitOpenChannel {
+				para: para_id,
+				recipient,
+				proposed_max_capacity,
+				proposed_max_message_size,
+			};
+			Self::para_xcm(
+				para_id,
+				deposit.saturating_add(fees),
+				fees,
+				Self::transact_native(
+					(T::ParaChannelsPalletIndex::get(), call),
+					T::InitOpenHrmpChannelWeight::get(),
+				),
+			)
+		}
+
+		/// The program sent to the Relay Chain by [`Pallet::accept_open_hrmp_channel`] of the
+		/// sibling para `para_id`.
+		fn accept_open_hrmp_channel_xcm(
+			para_id: ParaId,
+			sender: ParaId,
+			deposit: u128,
+			fees: u128,
+		) -> Xcm<()> {
+			let call = ParaChannelsCalls::AcceptOpenChannel { para: para_id, sender };
+			Self::para_xcm(
+				para_id,
+				deposit.saturating_add(fees),
+				fees,
+				Self::transact_native(
+					(T::ParaChannelsPalletIndex::get(), call),
+					T::AcceptOpenHrmpChannelWeight::get(),
+				),
+			)
+		}
+
+		/// The program teleporting `amount` of the Relay Chain token to the account of `origin` on
+		/// the Relay Chain and dispatching `xcm` from that account.
+		///
+		/// `fees` of `amount` pay for the execution and for the delivery of the messages sent from
+		/// `xcm`, what is left of them being deposited into the account in the end.
+		fn relay_xcm(
+			origin: &Location,
+			amount: u128,
+			fees: u128,
+			xcm: Vec<Instruction<()>>,
+		) -> Xcm<()> {
+			let account = Location::new(0, origin.interior().clone());
+			let aliasing = alloc::vec![
+				DescendOrigin(origin.interior().clone()),
+				AliasOrigin(account.clone()),
+			];
+			Self::funding_xcm(aliasing, account, amount, fees, xcm)
+		}
+
+		/// The program teleporting `amount` of the Relay Chain token to the sovereign account of
+		/// the para `para_id` on the Relay Chain and dispatching `xcm` from this chain.
+		///
+		/// `fees` of `amount` pay for the execution, what is left of them being deposited into the
+		/// sovereign account in the end.
+		fn para_xcm(
+			para_id: ParaId,
+			amount: u128,
+			fees: u128,
+			xcm: Vec<Instruction<()>>,
+		) -> Xcm<()> {
+			let account = Location::new(0, Parachain(para_id.into()));
+			Self::funding_xcm(Vec::new(), account, amount, fees, xcm)
+		}
+
+		/// Buy the execution with `fees` of the teleported `amount`, switch the origin with
+		/// `aliasing`, deposit the rest into `account` before `xcm` and the leftovers after it.
+		fn funding_xcm(
+			aliasing: Vec<Instruction<()>>,
+			account: Location,
+			amount: u128,
+			fees: u128,
+			xcm: Vec<Instruction<()>>,
+		) -> Xcm<()> {
+			let mut message = alloc::vec![
+				ReceiveTeleportedAsset((Here, amount).into()),
+				BuyExecution { fees: (Here, fees).into(), weight_limit: Unlimited },
+			];
+			message.extend(aliasing);
+			if amount > fees {
+				message.push(DepositAsset {
+					assets: Definite((Here, amount - fees).into()),
+					beneficiary: account.clone(),
+				});
+			}
+			message.extend(xcm);
+			message.extend([
+				RefundSurplus,
+				DepositAsset { assets: Wild(AllCounted(1)), beneficiary: account },
+			]);
+			Xcm(message)
+		}
+
+		fn transact(call: impl Encode, weight: Weight) -> Vec<Instruction<()>> {
+			alloc::vec![Transact {
+				origin_kind: OriginKind::SovereignAccount,
+				require_weight_at_most: weight,
+				call: call.encode().into(),
+			}]
+		}
+
+		/// Dispatch `call` from the native origin of this chain on the Relay Chain.
+		fn transact_native(call: impl Encode, weight: Weight) -> Vec<Instruction<()>> {
+			alloc::vec![Transact {
+				origin_kind: OriginKind::Native,
+				require_weight_at_most: weight,
+				call: call.encode().into(),
+			}]
+		}
+
+		/// Ensure `origin` is a sibling para, returning its location and id.
+		fn ensure_sibling_para(origin: OriginFor<T>) -> Result<(Location, ParaId), DispatchError> {
+			let origin = T::SiblingParaOrigin::ensure_origin(origin)?;
+			match origin.unpack() {
+				(1, [Parachain(id)]) => {
+					let para_id = (*id).into();
+					Ok((origin, para_id))
+				},
+				_ => Err(DispatchError::BadOrigin),
+			}
+		}
+
+		/// Teleport `amount` of the Relay Chain token of `origin` to the Relay Chain with
+		/// `message`, `origin` also paying for the delivery.
+		fn send_to_relay(origin: Location, amount: u128, message: Xcm<()>) -> DispatchResult {
+			let dest = Location::parent();
+			let (ticket, price) =
+				validate_send::<<T as pallet_xcm::Config>::XcmRouter>(dest.clone(), message)
+					.map_err(|_| Error::<T>::SendFailure)?;
+			<T as pallet_xcm::Config>::XcmExecutor::charge_fees(origin.clone(), price)
+				.map_err(|_| Error::<T>::FeesNotMet)?;
+
+			let asset: Asset = (Parent, amount).into();
+			let context =
+				XcmContext { origin: Some(origin.clone()), message_id: [0; 32], topic: None };
+			T::AssetTransactor::withdraw_asset(&asset, &origin, Some(&context))
+				.map_err(|_| Error::<T>::InsufficientFunds)?;
+			T::AssetTransactor::can_check_out(&dest, &asset, &context)
+				.map_err(|_| Error::<T>::CannotCheckOut)?;
+			let message_id = <T as pallet_xcm::Config>::XcmRouter::deliver(ticket)
+				.map_err(|_| Error::<T>::SendFailure)?;
+			T::AssetTransactor::check_out(&dest, &asset, &context);
+
+			Self::deposit_event(Event::Sent { origin, amount, message_id });
+			Ok(())
+		}
+	}
+}
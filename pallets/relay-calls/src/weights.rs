@@ -0,0 +1,28 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Weights for `pallet_relay_calls`.
+
+use frame_support::weights::Weight;
+
+/// Weight functions needed for `pallet_relay_calls`.
+pub trait WeightInfo {
+	fn place_on_demand_order() -> Weight;
+	fn register_para() -> Weight;
+	fn deregister_para() -> Weight;
+	fn init_open_hrmp_channel() -> Weight;
+	fn accept_open_hrmp_channel() -> Weight;
+}
@@ -0,0 +1,188 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Runtime API to introspect the proxy types of a runtime.
+//!
+//! Allows wallets and multisig tools to find out whether a proxy may dispatch a call before
+//! signing it, instead of submitting it and looking at the result.
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+use alloc::{string::String, vec::Vec};
+use codec::{Decode, DecodeLimit, Encode};
+use frame_support::traits::{InstanceFilter, IsSubType};
+use scale_info::{TypeDef, TypeInfo};
+use sp_api::MAX_EXTRINSIC_DEPTH;
+use sp_runtime::RuntimeDebug;
+
+/// A proxy type of the runtime.
+#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct ProxyTypeInfo<ProxyType> {
+	/// The proxy type.
+	pub proxy_type: ProxyType,
+	/// The index of the proxy type in its SCALE encoding.
+	pub index: u8,
+	/// The name of the proxy type.
+	pub name: String,
+	/// The other proxy types this proxy type is a superset of.
+	///
+	/// A proxy may only add or remove proxies of the proxy types it is a superset of.
+	pub supersets: Vec<ProxyType>,
+}
+
+/// Errors of [`ProxyFilterApi::is_call_allowed`].
+#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub enum ProxyFilterError {
+	/// The call could not be decoded into a call of the runtime.
+	InvalidCall,
+}
+
+sp_api::decl_runtime_apis! {
+	/// Introspection of the proxy types of the runtime and the calls they may dispatch.
+	pub trait ProxyFilterApi<ProxyType> where ProxyType: codec::Codec {
+		/// Return all proxy types of the runtime.
+		fn proxy_types() -> Vec<ProxyTypeInfo<ProxyType>>;
+
+		/// Return whether a proxy of `proxy_type` may dispatch the SCALE encoded `call`.
+		///
+		/// Calls nested into the `call`, e.g. by `Utility::batch`, are filtered again when they
+		/// are dispatched and need to be checked separately.
+		fn is_call_allowed(proxy_type: ProxyType, call: Vec<u8>) -> Result<bool, ProxyFilterError>;
+	}
+}
+
+/// All proxy types of the runtime `T`, in the order of their indices.
+///
+/// The proxy types are read from the type information of `T::ProxyType`, so every variant is
+/// listed without the runtime having to maintain a list of them.
+pub fn proxy_types<T: pallet_proxy::Config>() -> Vec<ProxyTypeInfo<T::ProxyType>> {
+	let TypeDef::Variant(def) = T::ProxyType::type_info().type_def else { return Vec::new() };
+	let all: Vec<_> = def
+		.variants
+		.into_iter()
+		.filter_map(|variant| {
+			T::ProxyType::decode(&mut &[variant.index][..])
+				.ok()
+				.map(|proxy_type| (variant.index, variant.name, proxy_type))
+		})
+		.collect();
+
+	all.iter()
+		.map(|(index, name, proxy_type)| ProxyTypeInfo {
+			proxy_type: proxy_type.clone(),
+			index: *index,
+			name: String::from(*name),
+			supersets: all
+				.iter()
+				.map(|(_, _, other)| other)
+				.filter(|other| *other != proxy_type && proxy_type.is_superset(other))
+				.cloned()
+				.collect(),
+		})
+		.collect()
+}
+
+/// Whether a proxy of `proxy_type` may dispatch the SCALE encoded `call` in the runtime `T`.
+///
+/// Mirrors the filter `pallet_proxy` puts on the origin of proxied calls, including the checks
+/// for managing other proxies.
+pub fn is_call_allowed<T: pallet_proxy::Config>(
+	proxy_type: &T::ProxyType,
+	mut call: &[u8],
+) -> Result<bool, ProxyFilterError> {
+	let call = <T as pallet_proxy::Config>::RuntimeCall::decode_all_with_depth_limit(
+		MAX_EXTRINSIC_DEPTH,
+		&mut call,
+	)
+	.map_err(|_| ProxyFilterError::InvalidCall)?;
+
+	Ok(match call.is_sub_type() {
+		Some(pallet_proxy::Call::add_proxy { proxy_type: ref other, .. }) |
+		Some(pallet_proxy::Call::remove_proxy { proxy_type: ref other, .. })
+			if !proxy_type.is_superset(other) =>
+			false,
+		Some(pallet_proxy::Call::remove_proxies { .. }) |
+		Some(pallet_proxy::Call::kill_pure { .. })
+			if *proxy_type != T::ProxyType::default() =>
+			false,
+		_ => proxy_type.filter(&call),
+	})
+}
+
+/// Check [`proxy_types`] and [`is_call_allowed`] against the proxy types of the runtime `T`.
+///
+/// Meant for the tests of the runtimes implementing [`ProxyFilterApi`], which pass whether a few
+/// of their calls are `expected` to be allowed for some of their proxy types.
+#[cfg(feature = "std")]
+pub fn assert_api_works<T: pallet_proxy::Config>(
+	expected: &[(T::ProxyType, <T as pallet_proxy::Config>::RuntimeCall, bool)],
+) where
+	<T as pallet_proxy::Config>::RuntimeCall: From<pallet_proxy::Call<T>>,
+{
+	use sp_runtime::traits::{StaticLookup, TrailingZeroInput};
+
+	let proxy_types = proxy_types::<T>();
+	assert!(!proxy_types.is_empty());
+	for (position, info) in proxy_types.iter().enumerate() {
+		assert!(position == 0 || proxy_types[position - 1].index < info.index);
+		assert_eq!(info.proxy_type.encode(), [info.index]);
+		for other in &proxy_types {
+			assert_eq!(
+				info.supersets.contains(&other.proxy_type),
+				other.proxy_type != info.proxy_type &&
+					info.proxy_type.is_superset(&other.proxy_type),
+			);
+		}
+	}
+	let default = proxy_types
+		.iter()
+		.find(|info| info.proxy_type == T::ProxyType::default())
+		.expect("the default proxy type is listed");
+	assert_eq!(default.supersets.len(), proxy_types.len() - 1);
+
+	for (proxy_type, call, allowed) in expected {
+		assert_eq!(
+			is_call_allowed::<T>(proxy_type, &call.encode()),
+			Ok(*allowed),
+			"{proxy_type:?} dispatching {call:?}",
+		);
+	}
+
+	// Proxies may only add proxies of the proxy types they are a superset of.
+	let delegate = T::AccountId::decode(&mut TrailingZeroInput::zeroes())
+		.expect("infinite input is never too short; qed");
+	for info in &proxy_types {
+		for other in proxy_types
+			.iter()
+			.filter(|other| !info.proxy_type.is_superset(&other.proxy_type))
+		{
+			let add_proxy: <T as pallet_proxy::Config>::RuntimeCall =
+				pallet_proxy::Call::<T>::add_proxy {
+					delegate: T::Lookup::unlookup(delegate.clone()),
+					proxy_type: other.proxy_type.clone(),
+					delay: Default::default(),
+				}
+				.into();
+			assert_eq!(is_call_allowed::<T>(&info.proxy_type, &add_proxy.encode()), Ok(false));
+		}
+	}
+
+	assert_eq!(
+		is_call_allowed::<T>(&T::ProxyType::default(), &[0xff]),
+		Err(ProxyFilterError::InvalidCall)
+	);
+}
@@ -0,0 +1,283 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Runtime API to evaluate the referenda tracks of a runtime.
+//!
+//! Exposes the tracks of the referenda pallets of a runtime together with the approval and support
+//! thresholds of their curves, so UIs do not have to re-derive the curves by hand.
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+use alloc::{string::String, vec::Vec};
+use codec::{Decode, Encode};
+use frame_support::traits::VoteTally;
+use frame_system::pallet_prelude::BlockNumberFor;
+use pallet_referenda::{
+	BalanceOf, Curve, ReferendumIndex, ReferendumInfo, ReferendumInfoFor, TrackIdOf, TrackInfo,
+	TrackInfoOf, TracksInfo,
+};
+use scale_info::TypeInfo;
+use sp_runtime::{traits::Saturating, Perbill, RuntimeDebug};
+
+/// Scales a governance period, e.g. of a referenda track, down from days to minutes if `fast`, so
+/// that referenda run through their whole lifecycle on test networks.
+///
+/// Non-zero periods stay at least one block long.
+pub const fn scale_governance_period(period: u32, fast: bool) -> u32 {
+	// The minutes of a day, independent of the block time.
+	let fast_period = period / (24 * 60);
+	if !fast {
+		period
+	} else if fast_period == 0 && period > 0 {
+		1
+	} else {
+		fast_period
+	}
+}
+
+/// Scales a governance period with [`scale_governance_period`] in `fast-runtime` builds of the
+/// calling crate, like `prod_or_fast!`.
+#[macro_export]
+macro_rules! governance_period {
+	($period:expr) => {
+		$crate::scale_governance_period($period, cfg!(feature = "fast-runtime"))
+	};
+}
+
+/// The configuration of a referenda track.
+#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct TrackDetails<Balance, Moment> {
+	/// Name of the track.
+	pub name: String,
+	/// The maximum number of referenda which may be deciding at once on the track.
+	pub max_deciding: u32,
+	/// Amount that must be placed on deposit before a decision can be made.
+	pub decision_deposit: Balance,
+	/// Amount of time a referendum must be prepared for before it can start deciding.
+	pub prepare_period: Moment,
+	/// Amount of time a referendum may be deciding for before it times out.
+	pub decision_period: Moment,
+	/// Amount of time a referendum must pass for before it is confirmed.
+	pub confirm_period: Moment,
+	/// Minimum amount of time between the approval and the enactment of a referendum.
+	pub min_enactment_period: Moment,
+	/// Minimum aye votes as percentage of overall conviction-weighted votes needed for approval
+	/// as a function of the time into the decision period.
+	pub min_approval: Curve,
+	/// Minimum pre-conviction aye-votes ("support") as percentage of overall population needed
+	/// for approval as a function of the time into the decision period.
+	pub min_support: Curve,
+}
+
+impl<Balance: Clone, Moment: Clone> From<&TrackInfo<Balance, Moment>>
+	for TrackDetails<Balance, Moment>
+{
+	fn from(info: &TrackInfo<Balance, Moment>) -> Self {
+		Self {
+			name: String::from(info.name),
+			max_deciding: info.max_deciding,
+			decision_deposit: info.decision_deposit.clone(),
+			prepare_period: info.prepare_period.clone(),
+			decision_period: info.decision_period.clone(),
+			confirm_period: info.confirm_period.clone(),
+			min_enactment_period: info.min_enactment_period.clone(),
+			min_approval: info.min_approval.clone(),
+			min_support: info.min_support.clone(),
+		}
+	}
+}
+
+/// Approval and support of a referendum.
+#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct Thresholds {
+	/// The approval, i.e. the aye votes as percentage of all conviction-weighted votes.
+	pub approval: Perbill,
+	/// The support, i.e. the pre-conviction aye votes as percentage of the overall population.
+	pub support: Perbill,
+}
+
+/// Thresholds of an ongoing referendum.
+#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct ReferendumThresholds<TrackId, Moment> {
+	/// The track of the referendum.
+	pub track: TrackId,
+	/// The approval and support the referendum needs to pass at the current block.
+	pub required: Thresholds,
+	/// The approval and support the referendum has at the current block.
+	pub current: Thresholds,
+	/// The earliest block at which the referendum can be confirmed, assuming its tally does not
+	/// change.
+	///
+	/// `None` if the current tally does not pass before the decision period ends.
+	pub earliest_confirmation: Option<Moment>,
+}
+
+sp_api::decl_runtime_apis! {
+	/// Evaluation of the referenda tracks of the runtime.
+	///
+	/// Every referenda pallet of the runtime is identified by its `pallet_index`.
+	pub trait ReferendaTracksApi<TrackId, Balance, BlockNumber>
+	where
+		TrackId: codec::Codec,
+		Balance: codec::Codec,
+		BlockNumber: codec::Codec,
+	{
+		/// Return the tracks of the referenda pallet.
+		fn tracks(pallet_index: u8) -> Vec<(TrackId, TrackDetails<Balance, BlockNumber>)>;
+
+		/// Return the approval and support a referendum on `track` needs to pass after it has
+		/// been deciding for `elapsed` blocks.
+		fn track_thresholds(
+			pallet_index: u8,
+			track: TrackId,
+			elapsed: BlockNumber,
+		) -> Option<Thresholds>;
+
+		/// Return the thresholds of the ongoing referendum `index`.
+		fn referendum_thresholds(
+			pallet_index: u8,
+			index: ReferendumIndex,
+		) -> Option<ReferendumThresholds<TrackId, BlockNumber>>;
+	}
+}
+
+/// The details of a track of the referenda pallet instance `I` of the runtime `T`.
+pub type TrackDetailsOf<T, I> = TrackDetails<BalanceOf<T, I>, BlockNumberFor<T>>;
+
+/// All tracks of the referenda pallet instance `I` of the runtime `T`.
+pub fn tracks<T: pallet_referenda::Config<I>, I: 'static>(
+) -> Vec<(TrackIdOf<T, I>, TrackDetailsOf<T, I>)> {
+	T::Tracks::tracks().iter().map(|(id, info)| (*id, info.into())).collect()
+}
+
+/// The approval and support a referendum on `track` of the referenda pallet instance `I` of the
+/// runtime `T` needs to pass after it has been deciding for `elapsed` blocks.
+pub fn track_thresholds<T: pallet_referenda::Config<I>, I: 'static>(
+	track: TrackIdOf<T, I>,
+	elapsed: BlockNumberFor<T>,
+) -> Option<Thresholds> {
+	T::Tracks::info(track).map(|info| thresholds_at::<T, I>(info, elapsed))
+}
+
+/// The thresholds of the ongoing referendum `index` of the referenda pallet instance `I` of the
+/// runtime `T`.
+///
+/// A referendum which is not deciding yet is assumed to start deciding as soon as it is prepared.
+pub fn referendum_thresholds<T: pallet_referenda::Config<I>, I: 'static>(
+	index: ReferendumIndex,
+) -> Option<ReferendumThresholds<TrackIdOf<T, I>, BlockNumberFor<T>>> {
+	let ReferendumInfo::Ongoing(status) = ReferendumInfoFor::<T, I>::get(index)? else {
+		return None
+	};
+	let info = T::Tracks::info(status.track)?;
+	let now = frame_system::Pallet::<T>::block_number();
+
+	let (since, confirming) = match status.deciding {
+		Some(deciding) => (deciding.since, deciding.confirming),
+		None => (now.max(status.submitted.saturating_add(info.prepare_period)), None),
+	};
+	let current = Thresholds {
+		approval: status.tally.approval(status.track),
+		support: status.tally.support(status.track),
+	};
+	// Same as the alarm `pallet_referenda` sets for the point at which the tally passes.
+	let earliest_confirmation = confirming.or_else(|| {
+		let passes_eventually = info.min_approval.passing(Perbill::one(), current.approval) &&
+			info.min_support.passing(Perbill::one(), current.support);
+		passes_eventually.then(|| {
+			let offset = info
+				.min_approval
+				.delay(current.approval)
+				.max(info.min_support.delay(current.support));
+			since
+				.saturating_add(offset.mul_ceil(info.decision_period))
+				.max(now)
+				.saturating_add(info.confirm_period)
+		})
+	});
+
+	Some(ReferendumThresholds {
+		track: status.track,
+		required: thresholds_at::<T, I>(info, now.saturating_sub(since)),
+		current,
+		earliest_confirmation,
+	})
+}
+
+fn thresholds_at<T: pallet_referenda::Config<I>, I: 'static>(
+	info: &TrackInfoOf<T, I>,
+	elapsed: BlockNumberFor<T>,
+) -> Thresholds {
+	let x = Perbill::from_rational(elapsed.min(info.decision_period), info.decision_period);
+	Thresholds { approval: info.min_approval.threshold(x), support: info.min_support.threshold(x) }
+}
+
+/// Check [`tracks`] and [`track_thresholds`] against the tracks of the referenda pallet instance
+/// `I` of the runtime `T`.
+///
+/// Meant for the tests of the runtimes implementing [`ReferendaTracksApi`].
+#[cfg(feature = "std")]
+pub fn assert_api_works<T: pallet_referenda::Config<I>, I: 'static>() {
+	use sp_runtime::traits::Zero;
+
+	let tracks = tracks::<T, I>();
+	assert!(!tracks.is_empty());
+	for (track, details) in &tracks {
+		let info = T::Tracks::info(*track).expect("listed tracks exist");
+		assert_eq!(details, &TrackDetails::from(info));
+
+		let start = track_thresholds::<T, I>(*track, Zero::zero());
+		let end = track_thresholds::<T, I>(*track, details.decision_period);
+		assert_eq!(
+			start,
+			Some(Thresholds {
+				approval: details.min_approval.threshold(Perbill::zero()),
+				support: details.min_support.threshold(Perbill::zero()),
+			})
+		);
+		assert_eq!(
+			end,
+			Some(Thresholds {
+				approval: details.min_approval.threshold(Perbill::one()),
+				support: details.min_support.threshold(Perbill::one()),
+			})
+		);
+		// The thresholds stay at their floor after the decision period.
+		let after = details.decision_period.saturating_mul(2u32.into());
+		assert_eq!(track_thresholds::<T, I>(*track, after), end);
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::scale_governance_period;
+
+	const MINUTES: u32 = 10;
+	const HOURS: u32 = 60 * MINUTES;
+	const DAYS: u32 = 24 * HOURS;
+
+	#[test]
+	fn governance_period_is_only_scaled_if_fast() {
+		assert_eq!(scale_governance_period(28 * DAYS, false), 28 * DAYS);
+		assert_eq!(scale_governance_period(2 * HOURS, false), 2 * HOURS);
+		assert_eq!(scale_governance_period(28 * DAYS, true), 28 * MINUTES);
+		// Non-zero periods stay at least one block long.
+		assert_eq!(scale_governance_period(2 * HOURS, true), 1);
+		assert_eq!(scale_governance_period(0, true), 0);
+		assert_eq!(scale_governance_period(0, false), 0);
+	}
+}
@@ -17,11 +17,27 @@
 //! Shared code between the Kusama nd Polkadot RC Runtimes.
 #![cfg_attr(not(feature = "std"), no_std)]
 
-use polkadot_primitives::Balance;
-use sp_runtime::{Perquintill, Saturating};
+extern crate alloc;
+
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+use frame_support::traits::ContainsPair;
+use polkadot_primitives::{Balance, LOWEST_PUBLIC_ID};
+use sp_runtime::{
+	traits::{Get, Saturating},
+	FixedPointNumber, FixedU128, Perquintill,
+};
+use xcm::latest::Location;
+
+const LOG_TARGET: &str = "runtime::relay-common";
+
+/// The duration of a year, as used to calculate the fraction of a year an era lasts.
+const MILLISECONDS_PER_YEAR: u64 = (1000 * 3600 * 24 * 36525) / 100;
 
 /// Extra runtime APIs for kusama runtime.
 pub mod apis {
+	use alloc::vec::Vec;
+
 	/// Information about the current inflation rate of the system.
 	///
 	/// Both fields should be treated as best-effort, given that the inflation rate might not be
@@ -39,14 +55,276 @@ pub mod apis {
 	}
 
 	sp_api::decl_runtime_apis! {
+		#[api_version(2)]
 		pub trait Inflation {
 			/// Return the current estimates of the inflation amount.
 			///
 			/// This is marked as experimental in light of RFC#89. Nonetheless, its usage is highly
 			/// recommended over trying to read-storage, or re-create the onchain logic.
 			fn experimental_inflation_prediction_info() -> InflationInfo;
+
+			/// Return the projected `(staking_payout, rest)` of the next `eras` eras, assuming
+			/// that `staking_rate` of the stakable issuance is staked in each of them.
+			///
+			/// The projection uses the current inflation parameters of the runtime and accounts
+			/// for the issuance minted by each era. At most [`super::MAX_FORECAST_ERAS`] eras are
+			/// returned.
+			#[api_version(2)]
+			fn experimental_inflation_forecast(
+				staking_rate: sp_runtime::Perquintill,
+				eras: u32,
+			) -> Vec<(polkadot_primitives::Balance, polkadot_primitives::Balance)>;
+		}
+	}
+
+	/// The estimated staking rewards of a stake.
+	#[derive(scale_info::TypeInfo, codec::Encode, codec::Decode, Clone, PartialEq, Eq)]
+	#[cfg_attr(feature = "std", derive(Debug))]
+	pub struct RewardEstimate {
+		/// The stake the rewards are estimated for.
+		pub stake: polkadot_primitives::Balance,
+		/// The estimated reward of the stake per era.
+		pub era_reward: polkadot_primitives::Balance,
+		/// The estimated yield of the stake per annum, without compounding the rewards.
+		pub annual_yield: sp_runtime::Perquintill,
+	}
+
+	sp_api::decl_runtime_apis! {
+		/// Estimation of the staking rewards, following the payout logic of the runtime.
+		///
+		/// The estimates are for the active era, assuming all of its validators earn the same
+		/// amount of era points.
+		pub trait StakingRewards<AccountId> where AccountId: codec::Codec {
+			/// Return the estimated rewards of the bonded `stash`, as a validator and as a
+			/// nominator of its exposures in the active era.
+			fn estimate_stash_rewards(stash: AccountId) -> Option<RewardEstimate>;
+
+			/// Return the estimated rewards of nominating `targets` with `stake`.
+			///
+			/// The stake is assumed to be split evenly between the targets that are active
+			/// validators.
+			fn estimate_nomination_rewards(
+				targets: Vec<AccountId>,
+				stake: polkadot_primitives::Balance,
+			) -> RewardEstimate;
+
+			/// Return the estimated rewards of the members of the nomination pool `pool_id`,
+			/// after the commission of the pool.
+			fn estimate_pool_rewards(pool_id: u32) -> Option<RewardEstimate>;
+		}
+	}
+}
+
+pub mod staking_rewards;
+
+/// The maximum number of eras [`forecast_era_payouts`] projects.
+pub const MAX_FORECAST_ERAS: u32 = 10_000;
+
+/// Projects the `(staking_payout, rest)` of `eras` consecutive eras, assuming that `staking_rate`
+/// of the stakable issuance is staked in each of them.
+///
+/// `era_payout` is called with the total staked and total stakable amount of each era. Both parts
+/// of its payout are minted, so they are added to the stakable issuance of the following eras.
+pub fn forecast_era_payouts(
+	mut total_stakable: Balance,
+	staking_rate: Perquintill,
+	eras: u32,
+	era_payout: impl Fn(Balance, Balance) -> (Balance, Balance),
+) -> Vec<(Balance, Balance)> {
+	(0..eras.min(MAX_FORECAST_ERAS))
+		.map(|_| {
+			let (staking_payout, rest) = era_payout(staking_rate * total_stakable, total_stakable);
+			total_stakable = total_stakable.saturating_add(staking_payout).saturating_add(rest);
+			(staking_payout, rest)
+		})
+		.collect()
+}
+
+/// The parameters of the staking curve of [`relay_era_payout`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StakingCurve {
+	/// Ideal stake ratio, reduced by the auctioned parachains if `use_auction_slots`.
+	pub ideal_stake: Perquintill,
+	/// Maximum inflation rate.
+	pub max_annual_inflation: Perquintill,
+	/// Minimum inflation rate.
+	pub min_annual_inflation: Perquintill,
+	/// Falloff used to calculate era payouts.
+	pub falloff: Perquintill,
+	/// Whether to subtract `auctioned_paras.min(60) / 200` from the ideal stake.
+	///
+	/// That is, we assume up to 60 parachains that are leased can reduce the ideal stake by a
+	/// maximum of 30%.
+	pub use_auction_slots: bool,
+}
+
+/// The inflation model of [`EraPayout`], together with its parameters.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InflationModel {
+	/// Inflation following the staking curve of [`relay_era_payout`].
+	StakingCurve(StakingCurve),
+	/// A fixed yearly emission, independent of the stake.
+	FixedEmission {
+		/// The total issuance the yearly emission is calculated from.
+		total_issuance: Balance,
+		/// The yearly emission as a fraction of `total_issuance`.
+		annual_inflation: Perquintill,
+		/// The fraction of each era's emission that goes to the treasury instead of the stakers.
+		treasury_share: Perquintill,
+	},
+}
+
+/// The runtime configured inputs of [`EraPayout`].
+///
+/// Can be implemented with constants or backed by `pallet_parameters`, to let governance adjust
+/// the inflation without a runtime upgrade.
+pub trait EraPayoutConfig {
+	/// The inflation model and its current parameters.
+	fn inflation_model() -> InflationModel;
+	/// The stakable issuance, given the total issuance.
+	fn total_stakable(total_issuance: Balance) -> Balance {
+		total_issuance
+	}
+}
+
+/// The number of active parachains that are not system parachains, i.e. the ones that acquired
+/// a slot in an auction.
+pub struct AuctionedParas<T>(PhantomData<T>);
+impl<T: runtime_parachains::paras::Config> Get<u64> for AuctionedParas<T> {
+	fn get() -> u64 {
+		runtime_parachains::paras::Parachains::<T>::get()
+			.into_iter()
+			// All active para-ids that do not belong to a system chain is the number of
+			// parachains that we should take into account for inflation.
+			.filter(|i| *i >= LOWEST_PUBLIC_ID)
+			.count() as u64
+	}
+}
+
+/// Era payout following the [`InflationModel`] of `Params`.
+///
+/// `ParasProvider` provides the number of auctioned parachains of the staking curve, see
+/// [`InflationModel::StakingCurve`]. It is not used by a fixed emission.
+pub struct EraPayout<Params, ParasProvider>(PhantomData<(Params, ParasProvider)>);
+
+impl<Params: EraPayoutConfig, ParasProvider: Get<u64>> EraPayout<Params, ParasProvider> {
+	/// The ideal stake ratio of the staking curve, after subtracting the legacy auction
+	/// proportion, or `None` for a fixed emission.
+	pub fn ideal_stake() -> Option<Perquintill> {
+		match Params::inflation_model() {
+			InflationModel::StakingCurve(curve) => Some(
+				curve
+					.ideal_stake
+					.saturating_sub(Self::legacy_auction_proportion(&curve).unwrap_or_default()),
+			),
+			InflationModel::FixedEmission { .. } => None,
+		}
+	}
+
+	/// The parameters of [`relay_era_payout`] for an era of `era_duration_millis`, or `None` for
+	/// a fixed emission.
+	pub fn params(
+		total_staked: Balance,
+		total_stakable: Balance,
+		era_duration_millis: u64,
+	) -> Option<EraPayoutParams> {
+		match Params::inflation_model() {
+			InflationModel::StakingCurve(curve) =>
+				Some(Self::curve_params(curve, total_staked, total_stakable, era_duration_millis)),
+			InflationModel::FixedEmission { .. } => None,
+		}
+	}
+
+	/// The `(staking_payout, rest)` of an era of `era_duration_millis`, given the total stakable
+	/// issuance rather than the total issuance.
+	pub fn payout(
+		total_staked: Balance,
+		total_stakable: Balance,
+		era_duration_millis: u64,
+	) -> (Balance, Balance) {
+		match Params::inflation_model() {
+			InflationModel::StakingCurve(curve) => {
+				let params =
+					Self::curve_params(curve, total_staked, total_stakable, era_duration_millis);
+				log::debug!(target: LOG_TARGET, "params: {:?}", params);
+				relay_era_payout(params)
+			},
+			InflationModel::FixedEmission { total_issuance, annual_inflation, treasury_share } => {
+				log::debug!(
+					target: LOG_TARGET,
+					"fixed emission: {:?} of {}, {:?} to the treasury",
+					annual_inflation,
+					total_issuance,
+					treasury_share,
+				);
+				fixed_era_payout(
+					total_issuance,
+					annual_inflation,
+					treasury_share,
+					era_duration_millis,
+				)
+			},
+		}
+	}
+
+	fn curve_params(
+		curve: StakingCurve,
+		total_staked: Balance,
+		total_stakable: Balance,
+		era_duration_millis: u64,
+	) -> EraPayoutParams {
+		EraPayoutParams {
+			total_staked,
+			total_stakable,
+			legacy_auction_proportion: Self::legacy_auction_proportion(&curve),
+			ideal_stake: curve.ideal_stake,
+			max_annual_inflation: curve.max_annual_inflation,
+			min_annual_inflation: curve.min_annual_inflation,
+			falloff: curve.falloff,
+			period_fraction: Perquintill::from_rational(era_duration_millis, MILLISECONDS_PER_YEAR),
 		}
 	}
+
+	fn legacy_auction_proportion(curve: &StakingCurve) -> Option<Perquintill> {
+		curve
+			.use_auction_slots
+			.then(|| Perquintill::from_rational(ParasProvider::get().min(60), 200u64))
+	}
+}
+
+impl<Params: EraPayoutConfig, ParasProvider: Get<u64>> pallet_staking::EraPayout<Balance>
+	for EraPayout<Params, ParasProvider>
+{
+	fn era_payout(
+		total_staked: Balance,
+		total_issuance: Balance,
+		era_duration_millis: u64,
+	) -> (Balance, Balance) {
+		Self::payout(total_staked, Params::total_stakable(total_issuance), era_duration_millis)
+	}
+}
+
+/// The `(to_stakers, to_treasury)` of an era of `era_duration_millis` of a fixed yearly emission
+/// of `annual_inflation` of `total_issuance`.
+fn fixed_era_payout(
+	total_issuance: Balance,
+	annual_inflation: Perquintill,
+	treasury_share: Perquintill,
+	era_duration_millis: u64,
+) -> (Balance, Balance) {
+	// A normal-sized era will have 1 / 365.25 here:
+	let relative_era_len =
+		FixedU128::from_rational(era_duration_millis.into(), MILLISECONDS_PER_YEAR.into());
+
+	// `FixedU128` and `Perquintill` share the same precision, so the conversion is lossless.
+	let fraction = |p: Perquintill| FixedU128::from_inner(p.deconstruct().into());
+	let yearly_emission = fraction(annual_inflation).saturating_mul_int(total_issuance);
+
+	let era_emission = relative_era_len.saturating_mul_int(yearly_emission);
+	let to_treasury = fraction(treasury_share).saturating_mul_int(era_emission);
+	let to_stakers = era_emission.saturating_sub(to_treasury);
+
+	(to_stakers, to_treasury)
 }
 
 // ---- TODO: Below is copy pasted from sdk, remove once we pull the version containing
@@ -114,3 +392,109 @@ pub fn relay_era_payout(params: EraPayoutParams) -> (Balance, Balance) {
 
 // ---- TODO: Above is copy pasted from sdk, remove once we pull the version containing
 // https://github.com/paritytech/polkadot-sdk/pull/4938
+
+/// Alias a location to any of its interior locations, e.g. a parachain to one of its accounts.
+///
+/// TODO: replace with `xcm_builder::AliasChildLocation` once we pull a version containing it.
+pub struct AliasChildLocation;
+impl ContainsPair<Location, Location> for AliasChildLocation {
+	fn contains(origin: &Location, target: &Location) -> bool {
+		target.starts_with(origin)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use pallet_staking::EraPayout as _;
+	use sp_runtime::traits::ConstU64;
+
+	const YEAR: u64 = MILLISECONDS_PER_YEAR;
+	const ISSUANCE: Balance = 1_000_000_000_000_000_000;
+
+	/// Inflation parameters whose payouts are exact fractions of [`ISSUANCE`].
+	///
+	/// The inflation parameters of the relays are tested by the relay runtimes.
+	struct TestParams;
+	impl EraPayoutConfig for TestParams {
+		fn inflation_model() -> InflationModel {
+			InflationModel::StakingCurve(StakingCurve {
+				ideal_stake: Perquintill::from_percent(75),
+				max_annual_inflation: Perquintill::from_percent(10),
+				min_annual_inflation: Perquintill::from_rational(25u64, 1000),
+				falloff: Perquintill::from_percent(5),
+				use_auction_slots: true,
+			})
+		}
+	}
+	type TestEraPayout<AuctionedParas> = EraPayout<TestParams, AuctionedParas>;
+
+	/// A fixed emission of a tenth of [`ISSUANCE`] per year, 15% of it to the treasury.
+	struct TestFixedParams;
+	impl EraPayoutConfig for TestFixedParams {
+		fn inflation_model() -> InflationModel {
+			InflationModel::FixedEmission {
+				total_issuance: ISSUANCE,
+				annual_inflation: Perquintill::from_percent(10),
+				treasury_share: Perquintill::from_percent(15),
+			}
+		}
+	}
+	type TestFixedEraPayout = EraPayout<TestFixedParams, ConstU64<0>>;
+
+	#[test]
+	fn era_payout_follows_the_inflation_curve() {
+		type NoParas = TestEraPayout<ConstU64<0>>;
+
+		// Nothing staked: minimum inflation to stakers, the rest of the maximum to the treasury.
+		assert_eq!(NoParas::era_payout(0, ISSUANCE, YEAR), (ISSUANCE / 40, ISSUANCE * 3 / 40));
+		// Half of the ideal stake: half way between minimum and maximum inflation.
+		assert_eq!(
+			NoParas::era_payout(ISSUANCE * 375 / 1000, ISSUANCE, YEAR),
+			(ISSUANCE / 16, ISSUANCE * 3 / 80),
+		);
+		// Ideal stake: maximum inflation to stakers.
+		assert_eq!(NoParas::era_payout(ISSUANCE * 3 / 4, ISSUANCE, YEAR), (ISSUANCE / 10, 0));
+		// A quarter of a year.
+		assert_eq!(
+			NoParas::era_payout(0, ISSUANCE, YEAR / 4),
+			(ISSUANCE / 160, ISSUANCE * 3 / 160),
+		);
+	}
+
+	#[test]
+	fn era_payout_accounts_for_auctioned_paras() {
+		// 60 or more auctioned parachains reduce the ideal stake by 30%.
+		assert_eq!(
+			TestEraPayout::<ConstU64<60>>::ideal_stake(),
+			Some(Perquintill::from_percent(45))
+		);
+		assert_eq!(
+			TestEraPayout::<ConstU64<100>>::ideal_stake(),
+			Some(Perquintill::from_percent(45))
+		);
+		assert_eq!(
+			TestEraPayout::<ConstU64<20>>::ideal_stake(),
+			Some(Perquintill::from_percent(65))
+		);
+
+		assert_eq!(
+			TestEraPayout::<ConstU64<60>>::era_payout(ISSUANCE * 45 / 100, ISSUANCE, YEAR),
+			(ISSUANCE / 10, 0),
+		);
+	}
+
+	#[test]
+	fn fixed_emission_is_independent_of_the_stake() {
+		let yearly = (ISSUANCE / 10 * 85 / 100, ISSUANCE / 10 * 15 / 100);
+		assert_eq!(TestFixedEraPayout::era_payout(0, ISSUANCE, YEAR), yearly);
+		assert_eq!(TestFixedEraPayout::era_payout(ISSUANCE / 2, ISSUANCE * 2, YEAR), yearly);
+		assert_eq!(
+			TestFixedEraPayout::era_payout(0, ISSUANCE, YEAR / 4),
+			(yearly.0 / 4, yearly.1 / 4)
+		);
+		// There is no staking curve to report.
+		assert_eq!(TestFixedEraPayout::ideal_stake(), None);
+		assert!(TestFixedEraPayout::params(0, ISSUANCE, YEAR).is_none());
+	}
+}
@@ -0,0 +1,205 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot. If not, see <http://www.gnu.org/licenses/>.
+
+//! Estimation of the staking rewards, see [`crate::apis::StakingRewards`].
+//!
+//! Follows how `pallet_staking` pays out an era: the staking part of the `EraPayout` is split
+//! between the validators by their era points, every validator takes its commission and the rest
+//! is shared by its exposure, pro rata to the stake.
+
+use crate::{apis::RewardEstimate, MILLISECONDS_PER_YEAR};
+use alloc::vec::Vec;
+use frame_support::traits::Currency;
+use pallet_nomination_pools::{BondedPools, PoolId};
+use pallet_staking::{
+	ActiveEra, Bonded, EraPayout, ErasStakersOverview, ErasStakersPaged, ErasTotalStake,
+	ErasValidatorPrefs, Ledger, Nominators,
+};
+use polkadot_primitives::Balance;
+use sp_runtime::{traits::Saturating, Perbill, Perquintill};
+
+/// The estimated rewards of the bonded `stash`, in eras of `era_duration_millis`.
+///
+/// Includes the rewards of the stash as a validator, i.e. its commission and the reward of its own
+/// stake, and as a nominator, i.e. the rewards of its stake in the exposures of its targets.
+pub fn estimate_stash_rewards<T>(
+	stash: &T::AccountId,
+	era_duration_millis: u64,
+) -> Option<RewardEstimate>
+where
+	T: pallet_staking::Config<CurrencyBalance = Balance>,
+{
+	let stake = Ledger::<T>::get(Bonded::<T>::get(stash)?)?.active;
+	let Some((era, validator_payout)) = active_era_payout::<T>(era_duration_millis) else {
+		return Some(estimate(stake, 0, era_duration_millis))
+	};
+	let commission =
+		|validator: &T::AccountId| ErasValidatorPrefs::<T>::get(era, validator).commission;
+
+	let mut era_reward: Balance = 0;
+	if let Some(exposure) = ErasStakersOverview::<T>::get(era, stash) {
+		let commission = commission(stash);
+		era_reward = (commission * validator_payout).saturating_add(exposure_reward(
+			validator_payout,
+			commission,
+			exposure.own,
+			exposure.total,
+		));
+	}
+	let targets = Nominators::<T>::get(stash).map(|n| n.targets.into_inner()).unwrap_or_default();
+	for target in targets {
+		let Some(exposure) = ErasStakersOverview::<T>::get(era, &target) else { continue };
+		let value = (0..exposure.page_count)
+			.filter_map(|page| ErasStakersPaged::<T>::get((era, &target, page)))
+			.flat_map(|page| page.others)
+			.filter(|individual| individual.who == *stash)
+			.fold(0, |value: Balance, individual| value.saturating_add(individual.value));
+		era_reward.saturating_accrue(exposure_reward(
+			validator_payout,
+			commission(&target),
+			value,
+			exposure.total,
+		));
+	}
+
+	Some(estimate(stake, era_reward, era_duration_millis))
+}
+
+/// The estimated rewards of nominating `targets` with `stake`, in eras of `era_duration_millis`.
+///
+/// The stake is split evenly between the targets which are active validators, and added to their
+/// exposures.
+pub fn estimate_nomination_rewards<T>(
+	mut targets: Vec<T::AccountId>,
+	stake: Balance,
+	era_duration_millis: u64,
+) -> RewardEstimate
+where
+	T: pallet_staking::Config<CurrencyBalance = Balance>,
+{
+	let Some((era, validator_payout)) = active_era_payout::<T>(era_duration_millis) else {
+		return estimate(stake, 0, era_duration_millis)
+	};
+	targets.sort();
+	targets.dedup();
+	let active: Vec<_> = targets
+		.iter()
+		.filter_map(|target| {
+			ErasStakersOverview::<T>::get(era, target).map(|exposure| (target, exposure.total))
+		})
+		.collect();
+	let Some(share) = stake.checked_div(active.len() as Balance) else {
+		return estimate(stake, 0, era_duration_millis)
+	};
+
+	let era_reward = active.into_iter().fold(0, |reward: Balance, (target, total)| {
+		let commission = ErasValidatorPrefs::<T>::get(era, target).commission;
+		reward.saturating_add(exposure_reward(
+			validator_payout,
+			commission,
+			share,
+			total.saturating_add(share),
+		))
+	});
+	estimate(stake, era_reward, era_duration_millis)
+}
+
+/// The estimated rewards of the members of the nomination pool `pool_id`, in eras of
+/// `era_duration_millis`.
+///
+/// The rewards of the pool are the ones of its bonded account, minus the commission of the pool.
+pub fn estimate_pool_rewards<T>(pool_id: PoolId, era_duration_millis: u64) -> Option<RewardEstimate>
+where
+	T: pallet_staking::Config<CurrencyBalance = Balance> + pallet_nomination_pools::Config,
+{
+	let pool = BondedPools::<T>::get(pool_id)?;
+	let bonded = pallet_nomination_pools::Pallet::<T>::generate_bonded_account(pool_id);
+	let RewardEstimate { stake, era_reward, .. } =
+		estimate_stash_rewards::<T>(&bonded, era_duration_millis)?;
+
+	let commission = pool.commission.current.map(|(commission, _)| commission).unwrap_or_default();
+	let era_reward = era_reward.saturating_sub(commission * era_reward);
+	Some(estimate(stake, era_reward, era_duration_millis))
+}
+
+/// The active era and the payout of each of its validators, assuming they all earn the same
+/// amount of era points.
+fn active_era_payout<T>(era_duration_millis: u64) -> Option<(u32, Balance)>
+where
+	T: pallet_staking::Config<CurrencyBalance = Balance>,
+{
+	let era = ActiveEra::<T>::get()?.index;
+	let validators = ErasStakersOverview::<T>::iter_key_prefix(era).count() as Balance;
+	let (staking_payout, _rest) = T::EraPayout::era_payout(
+		ErasTotalStake::<T>::get(era),
+		T::Currency::total_issuance(),
+		era_duration_millis,
+	);
+	Some((era, staking_payout.checked_div(validators)?))
+}
+
+/// The reward of `value` in the exposure `total` of a validator with `commission`.
+fn exposure_reward(
+	validator_payout: Balance,
+	commission: Perbill,
+	value: Balance,
+	total: Balance,
+) -> Balance {
+	let leftover = validator_payout.saturating_sub(commission * validator_payout);
+	Perbill::from_rational(value, total) * leftover
+}
+
+fn estimate(stake: Balance, era_reward: Balance, era_duration_millis: u64) -> RewardEstimate {
+	let annual_reward = era_reward
+		.saturating_mul(MILLISECONDS_PER_YEAR.into())
+		.checked_div(era_duration_millis.into())
+		.unwrap_or_default();
+	let annual_yield = match stake {
+		0 => Perquintill::zero(),
+		stake => Perquintill::from_rational(annual_reward, stake),
+	};
+	RewardEstimate { stake, era_reward, annual_yield }
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn exposure_reward_takes_commission_first() {
+		let payout = 1_000;
+		assert_eq!(exposure_reward(payout, Perbill::zero(), 1, 4), 250);
+		assert_eq!(exposure_reward(payout, Perbill::from_percent(20), 1, 4), 200);
+		assert_eq!(exposure_reward(payout, Perbill::one(), 1, 4), 0);
+		assert_eq!(exposure_reward(payout, Perbill::zero(), 0, 0), 0);
+	}
+
+	#[test]
+	fn estimate_annualises_the_era_reward() {
+		let day = MILLISECONDS_PER_YEAR * 100 / 36525;
+		// 1% per day is 365.25% per year, which saturates.
+		assert_eq!(estimate(100, 1, day).annual_yield, Perquintill::one());
+		assert_eq!(
+			estimate(36_525, 1, day),
+			RewardEstimate {
+				stake: 36_525,
+				era_reward: 1,
+				annual_yield: Perquintill::from_percent(1)
+			}
+		);
+		assert_eq!(estimate(0, 1, day).annual_yield, Perquintill::zero());
+	}
+}
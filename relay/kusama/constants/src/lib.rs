@@ -25,6 +25,9 @@ pub mod currency {
 	/// The existential deposit.
 	pub const EXISTENTIAL_DEPOSIT: Balance = CENTS;
 
+	/// The existential deposit of the NIS counterpart token, one KTC cent.
+	pub const NIS_COUNTERPART_EXISTENTIAL_DEPOSIT: Balance = 10_000_000_000;
+
 	pub const UNITS: Balance = 1_000_000_000_000;
 	pub const QUID: Balance = UNITS / 30;
 	pub const CENTS: Balance = QUID / 100;
@@ -134,6 +137,21 @@ pub mod system_parachain {
 /// Kusama Treasury pallet instance.
 pub const TREASURY_PALLET_ID: u8 = 18;
 
+/// Kusama NIS counterpart balances pallet instance.
+pub const NIS_COUNTERPART_PALLET_ID: u8 = 45;
+
+/// Kusama on-demand assignment provider pallet instance.
+pub const ON_DEMAND_PALLET_ID: u8 = 64;
+
+/// Kusama para channels pallet instance.
+pub const PARA_CHANNELS_PALLET_ID: u8 = 66;
+
+/// Kusama paras registrar pallet instance.
+pub const REGISTRAR_PALLET_ID: u8 = 70;
+
+/// Kusama para onboarding pallet instance.
+pub const PARA_ONBOARDING_PALLET_ID: u8 = 75;
+
 #[cfg(test)]
 mod tests {
 	use super::{
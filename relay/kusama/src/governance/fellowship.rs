@@ -31,7 +31,7 @@ use super::*;
 parameter_types! {
 	pub const AlarmInterval: BlockNumber = 1;
 	pub const SubmissionDeposit: Balance = 0;
-	pub const UndecidingTimeout: BlockNumber = 7 * DAYS;
+	pub const UndecidingTimeout: BlockNumber = governance_period!(7 * DAYS);
 }
 
 pub struct TracksInfo;
@@ -46,10 +46,10 @@ impl pallet_referenda::TracksInfo<Balance, BlockNumber> for TracksInfo {
 					name: "candidates",
 					max_deciding: 10,
 					decision_deposit: 100 * QUID,
-					prepare_period: 30 * MINUTES,
-					decision_period: 7 * DAYS,
-					confirm_period: 30 * MINUTES,
-					min_enactment_period: MINUTES,
+					prepare_period: governance_period!(30 * MINUTES),
+					decision_period: governance_period!(7 * DAYS),
+					confirm_period: governance_period!(30 * MINUTES),
+					min_enactment_period: governance_period!(MINUTES),
 					min_approval: pallet_referenda::Curve::LinearDecreasing {
 						length: Perbill::from_percent(100),
 						floor: Perbill::from_percent(50),
@@ -68,10 +68,10 @@ impl pallet_referenda::TracksInfo<Balance, BlockNumber> for TracksInfo {
 					name: "members",
 					max_deciding: 10,
 					decision_deposit: 10 * QUID,
-					prepare_period: 30 * MINUTES,
-					decision_period: 7 * DAYS,
-					confirm_period: 30 * MINUTES,
-					min_enactment_period: MINUTES,
+					prepare_period: governance_period!(30 * MINUTES),
+					decision_period: governance_period!(7 * DAYS),
+					confirm_period: governance_period!(30 * MINUTES),
+					min_enactment_period: governance_period!(MINUTES),
 					min_approval: pallet_referenda::Curve::LinearDecreasing {
 						length: Perbill::from_percent(100),
 						floor: Perbill::from_percent(50),
@@ -90,10 +90,10 @@ impl pallet_referenda::TracksInfo<Balance, BlockNumber> for TracksInfo {
 					name: "proficients",
 					max_deciding: 10,
 					decision_deposit: 10 * QUID,
-					prepare_period: 30 * MINUTES,
-					decision_period: 7 * DAYS,
-					confirm_period: 30 * MINUTES,
-					min_enactment_period: MINUTES,
+					prepare_period: governance_period!(30 * MINUTES),
+					decision_period: governance_period!(7 * DAYS),
+					confirm_period: governance_period!(30 * MINUTES),
+					min_enactment_period: governance_period!(MINUTES),
 					min_approval: pallet_referenda::Curve::LinearDecreasing {
 						length: Perbill::from_percent(100),
 						floor: Perbill::from_percent(50),
@@ -112,10 +112,10 @@ impl pallet_referenda::TracksInfo<Balance, BlockNumber> for TracksInfo {
 					name: "fellows",
 					max_deciding: 10,
 					decision_deposit: 10 * QUID,
-					prepare_period: 30 * MINUTES,
-					decision_period: 7 * DAYS,
-					confirm_period: 30 * MINUTES,
-					min_enactment_period: MINUTES,
+					prepare_period: governance_period!(30 * MINUTES),
+					decision_period: governance_period!(7 * DAYS),
+					confirm_period: governance_period!(30 * MINUTES),
+					min_enactment_period: governance_period!(MINUTES),
 					min_approval: pallet_referenda::Curve::LinearDecreasing {
 						length: Perbill::from_percent(100),
 						floor: Perbill::from_percent(50),
@@ -134,10 +134,10 @@ impl pallet_referenda::TracksInfo<Balance, BlockNumber> for TracksInfo {
 					name: "senior fellows",
 					max_deciding: 10,
 					decision_deposit: 10 * QUID,
-					prepare_period: 30 * MINUTES,
-					decision_period: 7 * DAYS,
-					confirm_period: 30 * MINUTES,
-					min_enactment_period: MINUTES,
+					prepare_period: governance_period!(30 * MINUTES),
+					decision_period: governance_period!(7 * DAYS),
+					confirm_period: governance_period!(30 * MINUTES),
+					min_enactment_period: governance_period!(MINUTES),
 					min_approval: pallet_referenda::Curve::LinearDecreasing {
 						length: Perbill::from_percent(100),
 						floor: Perbill::from_percent(50),
@@ -156,10 +156,10 @@ impl pallet_referenda::TracksInfo<Balance, BlockNumber> for TracksInfo {
 					name: "experts",
 					max_deciding: 10,
 					decision_deposit: QUID,
-					prepare_period: 30 * MINUTES,
-					decision_period: 7 * DAYS,
-					confirm_period: 30 * MINUTES,
-					min_enactment_period: MINUTES,
+					prepare_period: governance_period!(30 * MINUTES),
+					decision_period: governance_period!(7 * DAYS),
+					confirm_period: governance_period!(30 * MINUTES),
+					min_enactment_period: governance_period!(MINUTES),
 					min_approval: pallet_referenda::Curve::LinearDecreasing {
 						length: Perbill::from_percent(100),
 						floor: Perbill::from_percent(50),
@@ -178,10 +178,10 @@ impl pallet_referenda::TracksInfo<Balance, BlockNumber> for TracksInfo {
 					name: "senior experts",
 					max_deciding: 10,
 					decision_deposit: QUID,
-					prepare_period: 30 * MINUTES,
-					decision_period: 7 * DAYS,
-					confirm_period: 30 * MINUTES,
-					min_enactment_period: MINUTES,
+					prepare_period: governance_period!(30 * MINUTES),
+					decision_period: governance_period!(7 * DAYS),
+					confirm_period: governance_period!(30 * MINUTES),
+					min_enactment_period: governance_period!(MINUTES),
 					min_approval: pallet_referenda::Curve::LinearDecreasing {
 						length: Perbill::from_percent(100),
 						floor: Perbill::from_percent(50),
@@ -200,10 +200,10 @@ impl pallet_referenda::TracksInfo<Balance, BlockNumber> for TracksInfo {
 					name: "masters",
 					max_deciding: 10,
 					decision_deposit: QUID,
-					prepare_period: 30 * MINUTES,
-					decision_period: 7 * DAYS,
-					confirm_period: 30 * MINUTES,
-					min_enactment_period: MINUTES,
+					prepare_period: governance_period!(30 * MINUTES),
+					decision_period: governance_period!(7 * DAYS),
+					confirm_period: governance_period!(30 * MINUTES),
+					min_enactment_period: governance_period!(MINUTES),
 					min_approval: pallet_referenda::Curve::LinearDecreasing {
 						length: Perbill::from_percent(100),
 						floor: Perbill::from_percent(50),
@@ -222,10 +222,10 @@ impl pallet_referenda::TracksInfo<Balance, BlockNumber> for TracksInfo {
 					name: "senior masters",
 					max_deciding: 10,
 					decision_deposit: QUID,
-					prepare_period: 30 * MINUTES,
-					decision_period: 7 * DAYS,
-					confirm_period: 30 * MINUTES,
-					min_enactment_period: MINUTES,
+					prepare_period: governance_period!(30 * MINUTES),
+					decision_period: governance_period!(7 * DAYS),
+					confirm_period: governance_period!(30 * MINUTES),
+					min_enactment_period: governance_period!(MINUTES),
 					min_approval: pallet_referenda::Curve::LinearDecreasing {
 						length: Perbill::from_percent(100),
 						floor: Perbill::from_percent(50),
@@ -244,10 +244,10 @@ impl pallet_referenda::TracksInfo<Balance, BlockNumber> for TracksInfo {
 					name: "grand masters",
 					max_deciding: 10,
 					decision_deposit: QUID,
-					prepare_period: 30 * MINUTES,
-					decision_period: 7 * DAYS,
-					confirm_period: 30 * MINUTES,
-					min_enactment_period: MINUTES,
+					prepare_period: governance_period!(30 * MINUTES),
+					decision_period: governance_period!(7 * DAYS),
+					confirm_period: governance_period!(30 * MINUTES),
+					min_enactment_period: governance_period!(MINUTES),
 					min_approval: pallet_referenda::Curve::LinearDecreasing {
 						length: Perbill::from_percent(100),
 						floor: Perbill::from_percent(50),
@@ -88,6 +88,8 @@ pub mod pallet_custom_origins {
 		Fellowship9Dan,
 		/// Origin for signaling that the network wishes for some change.
 		WishForChange,
+		/// Origin for administering the Society, e.g. setting its founder.
+		SocietyAdmin,
 	}
 
 	macro_rules! decl_unit_ensures {
@@ -131,6 +133,7 @@ pub mod pallet_custom_origins {
 		ReferendumKiller,
 		WhitelistedCaller,
 		WishForChange,
+		SocietyAdmin,
 		FellowshipInitiates: u16 = 0,
 		Fellows: u16 = 3,
 		FellowshipExperts: u16 = 5,
@@ -40,6 +40,10 @@ const APP_AUCTION_ADMIN: Curve =
 	Curve::make_reciprocal(4, 28, percent(80), percent(50), percent(100));
 const SUP_AUCTION_ADMIN: Curve =
 	Curve::make_reciprocal(7, 28, percent(10), percent(0), percent(50));
+const APP_SOCIETY_ADMIN: Curve =
+	Curve::make_reciprocal(4, 28, percent(80), percent(50), percent(100));
+const SUP_SOCIETY_ADMIN: Curve =
+	Curve::make_reciprocal(7, 28, percent(10), percent(0), percent(50));
 const APP_LEASE_ADMIN: Curve = Curve::make_linear(17, 28, percent(50), percent(100));
 const SUP_LEASE_ADMIN: Curve = Curve::make_reciprocal(12, 28, percent(1), percent(0), percent(50));
 const APP_REFERENDUM_CANCELLER: Curve = Curve::make_linear(17, 28, percent(50), percent(100));
@@ -65,17 +69,17 @@ const APP_WHITELISTED_CALLER: Curve =
 const SUP_WHITELISTED_CALLER: Curve =
 	Curve::make_reciprocal(1, 28, percent(20), percent(5), percent(50));
 
-const TRACKS_DATA: [(u16, pallet_referenda::TrackInfo<Balance, BlockNumber>); 16] = [
+const TRACKS_DATA: [(u16, pallet_referenda::TrackInfo<Balance, BlockNumber>); 17] = [
 	(
 		0,
 		pallet_referenda::TrackInfo {
 			name: "root",
 			max_deciding: 1,
 			decision_deposit: 100 * GRAND,
-			prepare_period: 2 * HOURS,
-			decision_period: 14 * DAYS,
-			confirm_period: 24 * HOURS,
-			min_enactment_period: 24 * HOURS,
+			prepare_period: governance_period!(2 * HOURS),
+			decision_period: governance_period!(14 * DAYS),
+			confirm_period: governance_period!(24 * HOURS),
+			min_enactment_period: governance_period!(24 * HOURS),
 			min_approval: APP_ROOT,
 			min_support: SUP_ROOT,
 		},
@@ -86,10 +90,10 @@ const TRACKS_DATA: [(u16, pallet_referenda::TrackInfo<Balance, BlockNumber>); 16
 			name: "whitelisted_caller",
 			max_deciding: 100,
 			decision_deposit: 10 * GRAND,
-			prepare_period: 30 * MINUTES,
-			decision_period: 14 * DAYS,
-			confirm_period: 10 * MINUTES,
-			min_enactment_period: 10 * MINUTES,
+			prepare_period: governance_period!(30 * MINUTES),
+			decision_period: governance_period!(14 * DAYS),
+			confirm_period: governance_period!(10 * MINUTES),
+			min_enactment_period: governance_period!(10 * MINUTES),
 			min_approval: APP_WHITELISTED_CALLER,
 			min_support: SUP_WHITELISTED_CALLER,
 		},
@@ -100,10 +104,10 @@ const TRACKS_DATA: [(u16, pallet_referenda::TrackInfo<Balance, BlockNumber>); 16
 			name: "wish_for_change",
 			max_deciding: 10,
 			decision_deposit: 20 * GRAND,
-			prepare_period: 2 * HOURS,
-			decision_period: 14 * DAYS,
-			confirm_period: 24 * HOURS,
-			min_enactment_period: 10 * MINUTES,
+			prepare_period: governance_period!(2 * HOURS),
+			decision_period: governance_period!(14 * DAYS),
+			confirm_period: governance_period!(24 * HOURS),
+			min_enactment_period: governance_period!(10 * MINUTES),
 			min_approval: APP_ROOT,
 			min_support: SUP_ROOT,
 		},
@@ -114,10 +118,10 @@ const TRACKS_DATA: [(u16, pallet_referenda::TrackInfo<Balance, BlockNumber>); 16
 			name: "staking_admin",
 			max_deciding: 10,
 			decision_deposit: 5 * GRAND,
-			prepare_period: 2 * HOURS,
-			decision_period: 14 * DAYS,
-			confirm_period: 3 * HOURS,
-			min_enactment_period: 10 * MINUTES,
+			prepare_period: governance_period!(2 * HOURS),
+			decision_period: governance_period!(14 * DAYS),
+			confirm_period: governance_period!(3 * HOURS),
+			min_enactment_period: governance_period!(10 * MINUTES),
 			min_approval: APP_STAKING_ADMIN,
 			min_support: SUP_STAKING_ADMIN,
 		},
@@ -128,10 +132,10 @@ const TRACKS_DATA: [(u16, pallet_referenda::TrackInfo<Balance, BlockNumber>); 16
 			name: "treasurer",
 			max_deciding: 10,
 			decision_deposit: GRAND,
-			prepare_period: 2 * HOURS,
-			decision_period: 14 * DAYS,
-			confirm_period: 48 * HOURS,
-			min_enactment_period: 24 * HOURS,
+			prepare_period: governance_period!(2 * HOURS),
+			decision_period: governance_period!(14 * DAYS),
+			confirm_period: governance_period!(48 * HOURS),
+			min_enactment_period: governance_period!(24 * HOURS),
 			min_approval: APP_TREASURER,
 			min_support: SUP_TREASURER,
 		},
@@ -142,10 +146,10 @@ const TRACKS_DATA: [(u16, pallet_referenda::TrackInfo<Balance, BlockNumber>); 16
 			name: "lease_admin",
 			max_deciding: 10,
 			decision_deposit: 5 * GRAND,
-			prepare_period: 2 * HOURS,
-			decision_period: 14 * DAYS,
-			confirm_period: 3 * HOURS,
-			min_enactment_period: 10 * MINUTES,
+			prepare_period: governance_period!(2 * HOURS),
+			decision_period: governance_period!(14 * DAYS),
+			confirm_period: governance_period!(3 * HOURS),
+			min_enactment_period: governance_period!(10 * MINUTES),
 			min_approval: APP_LEASE_ADMIN,
 			min_support: SUP_LEASE_ADMIN,
 		},
@@ -156,10 +160,10 @@ const TRACKS_DATA: [(u16, pallet_referenda::TrackInfo<Balance, BlockNumber>); 16
 			name: "fellowship_admin",
 			max_deciding: 10,
 			decision_deposit: 5 * GRAND,
-			prepare_period: 2 * HOURS,
-			decision_period: 14 * DAYS,
-			confirm_period: 3 * HOURS,
-			min_enactment_period: 10 * MINUTES,
+			prepare_period: governance_period!(2 * HOURS),
+			decision_period: governance_period!(14 * DAYS),
+			confirm_period: governance_period!(3 * HOURS),
+			min_enactment_period: governance_period!(10 * MINUTES),
 			min_approval: APP_FELLOWSHIP_ADMIN,
 			min_support: SUP_FELLOWSHIP_ADMIN,
 		},
@@ -170,10 +174,10 @@ const TRACKS_DATA: [(u16, pallet_referenda::TrackInfo<Balance, BlockNumber>); 16
 			name: "general_admin",
 			max_deciding: 10,
 			decision_deposit: 5 * GRAND,
-			prepare_period: 2 * HOURS,
-			decision_period: 14 * DAYS,
-			confirm_period: 3 * HOURS,
-			min_enactment_period: 10 * MINUTES,
+			prepare_period: governance_period!(2 * HOURS),
+			decision_period: governance_period!(14 * DAYS),
+			confirm_period: governance_period!(3 * HOURS),
+			min_enactment_period: governance_period!(10 * MINUTES),
 			min_approval: APP_GENERAL_ADMIN,
 			min_support: SUP_GENERAL_ADMIN,
 		},
@@ -184,24 +188,38 @@ const TRACKS_DATA: [(u16, pallet_referenda::TrackInfo<Balance, BlockNumber>); 16
 			name: "auction_admin",
 			max_deciding: 10,
 			decision_deposit: 5 * GRAND,
-			prepare_period: 2 * HOURS,
-			decision_period: 14 * DAYS,
-			confirm_period: 3 * HOURS,
-			min_enactment_period: 10 * MINUTES,
+			prepare_period: governance_period!(2 * HOURS),
+			decision_period: governance_period!(14 * DAYS),
+			confirm_period: governance_period!(3 * HOURS),
+			min_enactment_period: governance_period!(10 * MINUTES),
 			min_approval: APP_AUCTION_ADMIN,
 			min_support: SUP_AUCTION_ADMIN,
 		},
 	),
+	(
+		16,
+		pallet_referenda::TrackInfo {
+			name: "society_admin",
+			max_deciding: 10,
+			decision_deposit: 5 * GRAND,
+			prepare_period: governance_period!(2 * HOURS),
+			decision_period: governance_period!(14 * DAYS),
+			confirm_period: governance_period!(3 * HOURS),
+			min_enactment_period: governance_period!(10 * MINUTES),
+			min_approval: APP_SOCIETY_ADMIN,
+			min_support: SUP_SOCIETY_ADMIN,
+		},
+	),
 	(
 		20,
 		pallet_referenda::TrackInfo {
 			name: "referendum_canceller",
 			max_deciding: 1_000,
 			decision_deposit: 10 * GRAND,
-			prepare_period: 2 * HOURS,
-			decision_period: 7 * DAYS,
-			confirm_period: 3 * HOURS,
-			min_enactment_period: 10 * MINUTES,
+			prepare_period: governance_period!(2 * HOURS),
+			decision_period: governance_period!(7 * DAYS),
+			confirm_period: governance_period!(3 * HOURS),
+			min_enactment_period: governance_period!(10 * MINUTES),
 			min_approval: APP_REFERENDUM_CANCELLER,
 			min_support: SUP_REFERENDUM_CANCELLER,
 		},
@@ -212,10 +230,10 @@ const TRACKS_DATA: [(u16, pallet_referenda::TrackInfo<Balance, BlockNumber>); 16
 			name: "referendum_killer",
 			max_deciding: 1_000,
 			decision_deposit: 50 * GRAND,
-			prepare_period: 2 * HOURS,
-			decision_period: 14 * DAYS,
-			confirm_period: 3 * HOURS,
-			min_enactment_period: 10 * MINUTES,
+			prepare_period: governance_period!(2 * HOURS),
+			decision_period: governance_period!(14 * DAYS),
+			confirm_period: governance_period!(3 * HOURS),
+			min_enactment_period: governance_period!(10 * MINUTES),
 			min_approval: APP_REFERENDUM_KILLER,
 			min_support: SUP_REFERENDUM_KILLER,
 		},
@@ -226,10 +244,10 @@ const TRACKS_DATA: [(u16, pallet_referenda::TrackInfo<Balance, BlockNumber>); 16
 			name: "small_tipper",
 			max_deciding: 200,
 			decision_deposit: QUID,
-			prepare_period: MINUTES,
-			decision_period: 7 * DAYS,
-			confirm_period: 10 * MINUTES,
-			min_enactment_period: MINUTES,
+			prepare_period: governance_period!(MINUTES),
+			decision_period: governance_period!(7 * DAYS),
+			confirm_period: governance_period!(10 * MINUTES),
+			min_enactment_period: governance_period!(MINUTES),
 			min_approval: APP_SMALL_TIPPER,
 			min_support: SUP_SMALL_TIPPER,
 		},
@@ -240,10 +258,10 @@ const TRACKS_DATA: [(u16, pallet_referenda::TrackInfo<Balance, BlockNumber>); 16
 			name: "big_tipper",
 			max_deciding: 100,
 			decision_deposit: 10 * QUID,
-			prepare_period: 10 * MINUTES,
-			decision_period: 7 * DAYS,
-			confirm_period: HOURS,
-			min_enactment_period: 10 * MINUTES,
+			prepare_period: governance_period!(10 * MINUTES),
+			decision_period: governance_period!(7 * DAYS),
+			confirm_period: governance_period!(HOURS),
+			min_enactment_period: governance_period!(10 * MINUTES),
 			min_approval: APP_BIG_TIPPER,
 			min_support: SUP_BIG_TIPPER,
 		},
@@ -254,10 +272,10 @@ const TRACKS_DATA: [(u16, pallet_referenda::TrackInfo<Balance, BlockNumber>); 16
 			name: "small_spender",
 			max_deciding: 50,
 			decision_deposit: 100 * QUID,
-			prepare_period: 4 * HOURS,
-			decision_period: 14 * DAYS,
-			confirm_period: 12 * HOURS,
-			min_enactment_period: 24 * HOURS,
+			prepare_period: governance_period!(4 * HOURS),
+			decision_period: governance_period!(14 * DAYS),
+			confirm_period: governance_period!(12 * HOURS),
+			min_enactment_period: governance_period!(24 * HOURS),
 			min_approval: APP_SMALL_SPENDER,
 			min_support: SUP_SMALL_SPENDER,
 		},
@@ -268,10 +286,10 @@ const TRACKS_DATA: [(u16, pallet_referenda::TrackInfo<Balance, BlockNumber>); 16
 			name: "medium_spender",
 			max_deciding: 50,
 			decision_deposit: 200 * QUID,
-			prepare_period: 4 * HOURS,
-			decision_period: 14 * DAYS,
-			confirm_period: 24 * HOURS,
-			min_enactment_period: 24 * HOURS,
+			prepare_period: governance_period!(4 * HOURS),
+			decision_period: governance_period!(14 * DAYS),
+			confirm_period: governance_period!(24 * HOURS),
+			min_enactment_period: governance_period!(24 * HOURS),
 			min_approval: APP_MEDIUM_SPENDER,
 			min_support: SUP_MEDIUM_SPENDER,
 		},
@@ -282,10 +300,10 @@ const TRACKS_DATA: [(u16, pallet_referenda::TrackInfo<Balance, BlockNumber>); 16
 			name: "big_spender",
 			max_deciding: 50,
 			decision_deposit: 400 * QUID,
-			prepare_period: 4 * HOURS,
-			decision_period: 14 * DAYS,
-			confirm_period: 48 * HOURS,
-			min_enactment_period: 24 * HOURS,
+			prepare_period: governance_period!(4 * HOURS),
+			decision_period: governance_period!(14 * DAYS),
+			confirm_period: governance_period!(48 * HOURS),
+			min_enactment_period: governance_period!(24 * HOURS),
 			min_approval: APP_BIG_SPENDER,
 			min_support: SUP_BIG_SPENDER,
 		},
@@ -316,6 +334,7 @@ impl pallet_referenda::TracksInfo<Balance, BlockNumber> for TracksInfo {
 				origins::Origin::FellowshipAdmin => Ok(13),
 				origins::Origin::GeneralAdmin => Ok(14),
 				origins::Origin::AuctionAdmin => Ok(15),
+				origins::Origin::SocietyAdmin => Ok(16),
 				// Referendum admins
 				origins::Origin::ReferendumCanceller => Ok(20),
 				origins::Origin::ReferendumKiller => Ok(21),
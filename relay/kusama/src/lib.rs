@@ -36,7 +36,7 @@ use polkadot_primitives::{
 	GroupRotationInfo, Hash, Id as ParaId, InboundDownwardMessage, InboundHrmpMessage, Moment,
 	NodeFeatures, Nonce, OccupiedCoreAssumption, PersistedValidationData, ScrapedOnChainVotes,
 	SessionInfo, Signature, ValidationCode, ValidationCodeHash, ValidatorId, ValidatorIndex,
-	LOWEST_PUBLIC_ID, PARACHAIN_KEY_TYPE_ID,
+	PARACHAIN_KEY_TYPE_ID,
 };
 use polkadot_runtime_common::{
 	auctions, claims, crowdloan, impl_runtime_weights,
@@ -49,7 +49,6 @@ use polkadot_runtime_common::{
 };
 use relay_common::apis::*;
 use scale_info::TypeInfo;
-use sp_runtime::traits::Saturating;
 use sp_std::{
 	cmp::Ordering,
 	collections::{btree_map::BTreeMap, vec_deque::VecDeque},
@@ -90,9 +89,9 @@ use frame_support::{
 	traits::{
 		fungible::HoldConsideration,
 		tokens::{imbalance::ResolveTo, UnityOrOuterConversion},
-		ConstU32, ConstU8, EitherOf, EitherOfDiverse, Everything, FromContains, InstanceFilter,
-		KeyOwnerProofSystem, LinearStoragePrice, PrivilegeCmp, ProcessMessage, ProcessMessageError,
-		StorageMapShim, WithdrawReasons,
+		ConstU32, ConstU8, EitherOf, EitherOfDiverse, Equals, Everything, FromContains,
+		InstanceFilter, KeyOwnerProofSystem, LinearStoragePrice, PalletInfoAccess, PrivilegeCmp,
+		ProcessMessage, ProcessMessageError, StorageMapShim, WithdrawReasons,
 	},
 	weights::{ConstantMultiplier, WeightMeter, WeightToFee as _},
 	PalletId,
@@ -159,8 +158,8 @@ pub mod xcm_config;
 // Governance configurations.
 pub mod governance;
 use governance::{
-	pallet_custom_origins, AuctionAdmin, Fellows, GeneralAdmin, LeaseAdmin, StakingAdmin,
-	Treasurer, TreasurySpender,
+	pallet_custom_origins, AuctionAdmin, Fellows, FellowshipReferendaInstance, GeneralAdmin,
+	LeaseAdmin, SocietyAdmin, StakingAdmin, Treasurer, TreasurySpender,
 };
 
 #[cfg(test)]
@@ -229,7 +228,7 @@ impl frame_system::Config for Runtime {
 	type OnSetCode = ();
 	type MaxConsumers = frame_support::traits::ConstU32<16>;
 	type SingleBlockMigrations = ();
-	type MultiBlockMigrator = ();
+	type MultiBlockMigrator = MultiBlockMigrations;
 	type PreInherents = ();
 	type PostInherents = ();
 	type PostTransactions = ();
@@ -743,40 +742,28 @@ impl pallet_parameters::Config for Runtime {
 	type WeightInfo = weights::pallet_parameters::WeightInfo<Runtime>;
 }
 
-pub struct EraPayout;
-impl pallet_staking::EraPayout<Balance> for EraPayout {
-	fn era_payout(
-		total_staked: Balance,
-		_total_issuance: Balance,
-		era_duration_millis: u64,
-	) -> (Balance, Balance) {
-		const MILLISECONDS_PER_YEAR: u64 = 1000 * 3600 * 24 * 36525 / 100;
-
-		let params = relay_common::EraPayoutParams {
-			total_staked,
-			total_stakable: Nis::issuance().other,
+/// The inflation parameters of Kusama, see [`dynamic_params::inflation`].
+pub struct InflationParams;
+impl relay_common::EraPayoutConfig for InflationParams {
+	fn inflation_model() -> relay_common::InflationModel {
+		relay_common::InflationModel::StakingCurve(relay_common::StakingCurve {
 			ideal_stake: dynamic_params::inflation::IdealStake::get(),
 			max_annual_inflation: dynamic_params::inflation::MaxInflation::get(),
 			min_annual_inflation: dynamic_params::inflation::MinInflation::get(),
 			falloff: dynamic_params::inflation::Falloff::get(),
-			period_fraction: Perquintill::from_rational(era_duration_millis, MILLISECONDS_PER_YEAR),
-			legacy_auction_proportion: if dynamic_params::inflation::UseAuctionSlots::get() {
-				let auctioned_slots = parachains_paras::Parachains::<Runtime>::get()
-					.into_iter()
-					// All active para-ids that do not belong to a system chain is the number of
-					// parachains that we should take into account for inflation.
-					.filter(|i| *i >= LOWEST_PUBLIC_ID)
-					.count() as u64;
-				Some(Perquintill::from_rational(auctioned_slots.min(60), 200u64))
-			} else {
-				None
-			},
-		};
-		log::debug!(target: "runtime::kusama", "params: {:?}", params);
-		relay_common::relay_era_payout(params)
+			use_auction_slots: dynamic_params::inflation::UseAuctionSlots::get(),
+		})
+	}
+
+	fn total_stakable(_total_issuance: Balance) -> Balance {
+		Nis::issuance().other
 	}
 }
 
+/// Defines how much should the inflation be for an era given its duration.
+pub type EraPayout =
+	relay_common::EraPayout<InflationParams, relay_common::AuctionedParas<Runtime>>;
+
 parameter_types! {
 	// Six sessions in an era (6 hours).
 	pub const SessionsPerEra: SessionIndex = prod_or_fast!(6, 1);
@@ -1126,7 +1113,7 @@ impl pallet_society::Config for Runtime {
 	type VotingPeriod = ConstU32<{ 5 * DAYS }>;
 	type ClaimPeriod = ConstU32<{ 2 * DAYS }>;
 	type MaxLockDuration = ConstU32<{ 36 * 30 * DAYS }>;
-	type FounderSetOrigin = EnsureRoot<AccountId>;
+	type FounderSetOrigin = EitherOf<EnsureRoot<AccountId>, SocietyAdmin>;
 	type ChallengePeriod = ConstU32<{ 7 * DAYS }>;
 	type MaxPayouts = ConstU32<8>;
 	type MaxBids = ConstU32<512>;
@@ -1175,9 +1162,11 @@ parameter_types! {
 	RuntimeDebug,
 	MaxEncodedLen,
 	TypeInfo,
+	Default,
 )]
 pub enum ProxyType {
 	#[codec(index = 0)]
+	#[default]
 	Any,
 	#[codec(index = 1)]
 	NonTransfer,
@@ -1198,12 +1187,10 @@ pub enum ProxyType {
 	Spokesperson,
 	#[codec(index = 10)]
 	ParaRegistration,
-}
-
-impl Default for ProxyType {
-	fn default() -> Self {
-		Self::Any
-	}
+	#[codec(index = 11)]
+	Treasury,
+	#[codec(index = 12)]
+	Bounties,
 }
 
 impl InstanceFilter<RuntimeCall> for ProxyType {
@@ -1306,11 +1293,46 @@ impl InstanceFilter<RuntimeCall> for ProxyType {
 				c,
 				RuntimeCall::Registrar(paras_registrar::Call::reserve { .. }) |
 					RuntimeCall::Registrar(paras_registrar::Call::register { .. }) |
+					RuntimeCall::ParaOnboarding(pallet_para_onboarding::Call::register { .. }) |
 					RuntimeCall::Utility(pallet_utility::Call::batch { .. }) |
 					RuntimeCall::Utility(pallet_utility::Call::batch_all { .. }) |
 					RuntimeCall::Utility(pallet_utility::Call::force_batch { .. }) |
 					RuntimeCall::Proxy(pallet_proxy::Call::remove_proxy { .. })
 			),
+			ProxyType::Treasury => matches!(
+				c,
+				RuntimeCall::Treasury(
+					pallet_treasury::Call::payout { .. } |
+						pallet_treasury::Call::check_status { .. } |
+						pallet_treasury::Call::void_spend { .. }
+				) | RuntimeCall::Utility(
+					pallet_utility::Call::batch { .. } |
+						pallet_utility::Call::batch_all { .. } |
+						pallet_utility::Call::force_batch { .. }
+				)
+			),
+			ProxyType::Bounties => matches!(
+				c,
+				RuntimeCall::Bounties(
+					pallet_bounties::Call::accept_curator { .. } |
+						pallet_bounties::Call::unassign_curator { .. } |
+						pallet_bounties::Call::award_bounty { .. } |
+						pallet_bounties::Call::claim_bounty { .. } |
+						pallet_bounties::Call::extend_bounty_expiry { .. }
+				) | RuntimeCall::ChildBounties(
+					pallet_child_bounties::Call::add_child_bounty { .. } |
+						pallet_child_bounties::Call::propose_curator { .. } |
+						pallet_child_bounties::Call::accept_curator { .. } |
+						pallet_child_bounties::Call::unassign_curator { .. } |
+						pallet_child_bounties::Call::award_child_bounty { .. } |
+						pallet_child_bounties::Call::claim_child_bounty { .. } |
+						pallet_child_bounties::Call::close_child_bounty { .. }
+				) | RuntimeCall::Utility(
+					pallet_utility::Call::batch { .. } |
+						pallet_utility::Call::batch_all { .. } |
+						pallet_utility::Call::force_batch { .. }
+				)
+			),
 		}
 	}
 	fn is_superset(&self, o: &Self) -> bool {
@@ -1319,6 +1341,7 @@ impl InstanceFilter<RuntimeCall> for ProxyType {
 			(ProxyType::Any, _) => true,
 			(_, ProxyType::Any) => false,
 			(ProxyType::NonTransfer, _) => true,
+			(ProxyType::Governance, ProxyType::Treasury | ProxyType::Bounties) => true,
 			_ => false,
 		}
 	}
@@ -1448,6 +1471,15 @@ impl parachains_hrmp::Config for Runtime {
 	type VersionWrapper = XcmPallet;
 }
 
+parameter_types! {
+	pub const AssetHubParaId: ParaId = ParaId::new(system_parachain::ASSET_HUB_ID);
+}
+
+impl pallet_para_channels::Config for Runtime {
+	type ParaOrigin = RuntimeOrigin;
+	type Operators = Equals<AssetHubParaId>;
+}
+
 impl parachains_paras_inherent::Config for Runtime {
 	type WeightInfo = weights::runtime_parachains_paras_inherent::WeightInfo<Runtime>;
 }
@@ -1553,6 +1585,11 @@ impl paras_registrar::Config for Runtime {
 	type WeightInfo = weights::polkadot_runtime_common_paras_registrar::WeightInfo<Runtime>;
 }
 
+impl pallet_para_onboarding::Config for Runtime {
+	type Preimages = Preimage;
+	type WeightInfo = weights::pallet_para_onboarding::WeightInfo<Runtime>;
+}
+
 parameter_types! {
 	// 6 weeks
 	pub LeasePeriod: BlockNumber = prod_or_fast!(6 * WEEKS, 6 * WEEKS, "KSM_LEASE_PERIOD");
@@ -1613,7 +1650,7 @@ impl pallet_balances::Config<NisCounterpartInstance> for Runtime {
 	type Balance = Balance;
 	type DustRemoval = ();
 	type RuntimeEvent = RuntimeEvent;
-	type ExistentialDeposit = ConstU128<10_000_000_000>; // One KTC cent
+	type ExistentialDeposit = ConstU128<NIS_COUNTERPART_EXISTENTIAL_DEPOSIT>;
 	type AccountStore = StorageMapShim<
 		pallet_balances::Account<Runtime, NisCounterpartInstance>,
 		AccountId,
@@ -1733,6 +1770,25 @@ impl pallet_asset_rate::Config for Runtime {
 	type BenchmarkHelper = polkadot_runtime_common::impls::benchmarks::AssetRateArguments;
 }
 
+parameter_types! {
+	pub MbmServiceWeight: Weight = Perbill::from_percent(80) * BlockWeights::get().max_block;
+}
+
+impl pallet_migrations::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	#[cfg(not(feature = "runtime-benchmarks"))]
+	type Migrations = migrations::MultiBlock;
+	// Benchmarks need mocked migrations to guarantee that they succeed.
+	#[cfg(feature = "runtime-benchmarks")]
+	type Migrations = pallet_migrations::mock_helpers::MockedMigrations;
+	type CursorMaxLen = ConstU32<65_536>;
+	type IdentifierMaxLen = ConstU32<256>;
+	type MigrationStatusHandler = ();
+	type FailedMigrationHandler = frame_support::migrations::FreezeChainOnFailedMigration;
+	type MaxServiceWeight = MbmServiceWeight;
+	type WeightInfo = weights::pallet_migrations::WeightInfo<Runtime>;
+}
+
 construct_runtime! {
 	pub enum Runtime
 	{
@@ -1839,6 +1895,7 @@ construct_runtime! {
 		ParasSlashing: parachains_slashing = 63,
 		OnDemandAssignmentProvider: parachains_on_demand = 64,
 		CoretimeAssignmentProvider: parachains_assigner_coretime = 65,
+		ParaChannels: pallet_para_channels = 66,
 
 		// Parachain Onboarding Pallets. Start indices at 70 to leave room.
 		Registrar: paras_registrar = 70,
@@ -1846,6 +1903,7 @@ construct_runtime! {
 		Auctions: auctions = 72,
 		Crowdloan: crowdloan = 73,
 		Coretime: coretime = 74,
+		ParaOnboarding: pallet_para_onboarding = 75,
 
 		// Pallet for sending XCM.
 		XcmPallet: pallet_xcm = 99,
@@ -1856,6 +1914,9 @@ construct_runtime! {
 		// Asset rate.
 		AssetRate: pallet_asset_rate = 101,
 
+		// Multi-block migrations, transactions are paused while they run.
+		MultiBlockMigrations: pallet_migrations = 102,
+
 		// BEEFY Bridges support.
 		Beefy: pallet_beefy = 200,
 		// MMR leaf construction must be after session in order to have a leaf's next_auth_set
@@ -1929,6 +1990,12 @@ pub mod migrations {
 
 	/// Migrations/checks that do not need to be versioned and can run on every update.
 	pub type Permanent = (pallet_xcm::migration::MigrateToLatestXcmVersion<Runtime>,);
+
+	/// Migrations too heavy for a single block, run by `MultiBlockMigrations` after the upgrade.
+	///
+	/// Completed migrations are recorded by the pallet and not run again, so they can stay here
+	/// across releases.
+	pub type MultiBlock = ();
 }
 
 /// Migration to fix current corrupted staking ledgers in Kusama.
@@ -2121,9 +2188,11 @@ mod benches {
 		[pallet_nis, Nis]
 		[pallet_indices, Indices]
 		[pallet_message_queue, MessageQueue]
+		[pallet_migrations, MultiBlockMigrations]
 		[pallet_multisig, Multisig]
 		[pallet_nomination_pools, NominationPoolsBench::<Runtime>]
 		[pallet_offences, OffencesBench::<Runtime>]
+		[pallet_para_onboarding, ParaOnboarding]
 		[pallet_preimage, Preimage]
 		[pallet_proxy, Proxy]
 		[pallet_ranked_collective, FellowshipCollective]
@@ -2150,6 +2219,9 @@ mod benches {
 }
 
 impl Runtime {
+	/// The duration of an era used for the estimates, we assume un-delayed 6h eras.
+	const ERA_DURATION: Moment = 6 * (HOURS as Moment) * MILLISECS_PER_BLOCK;
+
 	fn impl_experimental_inflation_info() -> InflationInfo {
 		use pallet_staking::{ActiveEra, EraPayout, ErasTotalStake};
 		let (staked, _start) = ActiveEra::<Runtime>::get()
@@ -2157,22 +2229,9 @@ impl Runtime {
 			.unwrap_or((0, 0));
 		let stake_able_issuance = Nis::issuance().other;
 
-		let ideal_staking_rate = dynamic_params::inflation::IdealStake::get();
-		let inflation = if dynamic_params::inflation::UseAuctionSlots::get() {
-			let auctioned_slots = parachains_paras::Parachains::<Runtime>::get()
-				.into_iter()
-				// All active para-ids that do not belong to a system chain is the number of
-				// parachains that we should take into account for inflation.
-				.filter(|i| *i >= LOWEST_PUBLIC_ID)
-				.count() as u64;
-			ideal_staking_rate
-				.saturating_sub(Perquintill::from_rational(auctioned_slots.min(60), 200u64))
-		} else {
-			ideal_staking_rate
-		};
+		let inflation = crate::EraPayout::ideal_stake().unwrap_or_default();
 
-		// We assume un-delayed 6h eras.
-		let era_duration = 6 * (HOURS as Moment) * MILLISECS_PER_BLOCK;
+		let era_duration = Self::ERA_DURATION;
 		let next_mint = <Self as pallet_staking::Config>::EraPayout::era_payout(
 			staked,
 			stake_able_issuance,
@@ -2181,13 +2240,63 @@ impl Runtime {
 
 		InflationInfo { inflation, next_mint }
 	}
+
+	fn impl_experimental_inflation_forecast(
+		staking_rate: Perquintill,
+		eras: u32,
+	) -> Vec<(Balance, Balance)> {
+		let era_duration = Self::ERA_DURATION;
+		relay_common::forecast_era_payouts(
+			Nis::issuance().other,
+			staking_rate,
+			eras,
+			|total_staked, total_stakable| {
+				EraPayout::payout(total_staked, total_stakable, era_duration)
+			},
+		)
+	}
 }
 
 sp_api::impl_runtime_apis! {
+	#[api_version(2)]
 	impl relay_common::apis::Inflation<Block> for Runtime {
 		fn experimental_inflation_prediction_info() -> InflationInfo {
 			Runtime::impl_experimental_inflation_info()
 		}
+
+		fn experimental_inflation_forecast(
+			staking_rate: Perquintill,
+			eras: u32,
+		) -> Vec<(Balance, Balance)> {
+			Runtime::impl_experimental_inflation_forecast(staking_rate, eras)
+		}
+	}
+
+	impl relay_common::apis::StakingRewards<Block, AccountId> for Runtime {
+		fn estimate_stash_rewards(stash: AccountId) -> Option<RewardEstimate> {
+			relay_common::staking_rewards::estimate_stash_rewards::<Runtime>(
+				&stash,
+				Runtime::ERA_DURATION,
+			)
+		}
+
+		fn estimate_nomination_rewards(
+			targets: Vec<AccountId>,
+			stake: Balance,
+		) -> RewardEstimate {
+			relay_common::staking_rewards::estimate_nomination_rewards::<Runtime>(
+				targets,
+				stake,
+				Runtime::ERA_DURATION,
+			)
+		}
+
+		fn estimate_pool_rewards(pool_id: u32) -> Option<RewardEstimate> {
+			relay_common::staking_rewards::estimate_pool_rewards::<Runtime>(
+				pool_id,
+				Runtime::ERA_DURATION,
+			)
+		}
 	}
 
 	impl sp_api::Core<Block> for Runtime {
@@ -2685,6 +2794,64 @@ sp_api::impl_runtime_apis! {
 		}
 	}
 
+	impl referenda_tracks_api::ReferendaTracksApi<Block, u16, Balance, BlockNumber> for Runtime {
+		fn tracks(
+			pallet_index: u8,
+		) -> Vec<(u16, referenda_tracks_api::TrackDetails<Balance, BlockNumber>)> {
+			match pallet_index {
+				i if i == Referenda::index() as u8 => referenda_tracks_api::tracks::<Runtime, ()>(),
+				i if i == FellowshipReferenda::index() as u8 =>
+					referenda_tracks_api::tracks::<Runtime, FellowshipReferendaInstance>(),
+				_ => Vec::new(),
+			}
+		}
+
+		fn track_thresholds(
+			pallet_index: u8,
+			track: u16,
+			elapsed: BlockNumber,
+		) -> Option<referenda_tracks_api::Thresholds> {
+			match pallet_index {
+				i if i == Referenda::index() as u8 =>
+					referenda_tracks_api::track_thresholds::<Runtime, ()>(track, elapsed),
+				i if i == FellowshipReferenda::index() as u8 =>
+					referenda_tracks_api::track_thresholds::<Runtime, FellowshipReferendaInstance>(
+						track,
+						elapsed,
+					),
+				_ => None,
+			}
+		}
+
+		fn referendum_thresholds(
+			pallet_index: u8,
+			index: pallet_referenda::ReferendumIndex,
+		) -> Option<referenda_tracks_api::ReferendumThresholds<u16, BlockNumber>> {
+			match pallet_index {
+				i if i == Referenda::index() as u8 =>
+					referenda_tracks_api::referendum_thresholds::<Runtime, ()>(index),
+				i if i == FellowshipReferenda::index() as u8 =>
+					referenda_tracks_api::referendum_thresholds::<Runtime, FellowshipReferendaInstance>(
+						index,
+					),
+				_ => None,
+			}
+		}
+	}
+
+	impl proxy_filter_api::ProxyFilterApi<Block, ProxyType> for Runtime {
+		fn proxy_types() -> Vec<proxy_filter_api::ProxyTypeInfo<ProxyType>> {
+			proxy_filter_api::proxy_types::<Runtime>()
+		}
+
+		fn is_call_allowed(
+			proxy_type: ProxyType,
+			call: Vec<u8>,
+		) -> Result<bool, proxy_filter_api::ProxyFilterError> {
+			proxy_filter_api::is_call_allowed::<Runtime>(&proxy_type, &call)
+		}
+	}
+
 	impl xcm_runtime_apis::fees::XcmPaymentApi<Block> for Runtime {
 		fn query_acceptable_payment_assets(xcm_version: xcm::Version) -> Result<Vec<VersionedAssetId>, XcmPaymentApiError> {
 			let acceptable_assets = vec![AssetId(xcm_config::TokenLocation::get())];
@@ -2727,6 +2894,12 @@ sp_api::impl_runtime_apis! {
 		}
 	}
 
+	impl xcm_dry_run_api::DetailedDryRunApi<Block, RuntimeCall, RuntimeEvent, OriginCaller> for Runtime {
+		fn detailed_dry_run_call(origin: OriginCaller, call: RuntimeCall) -> Result<xcm_dry_run_api::DetailedCallDryRunEffects<RuntimeEvent>, XcmDryRunApiError> {
+			xcm_dry_run_api::detailed_dry_run_call::<Runtime, xcm_config::XcmRouter, OriginCaller, xcm_config::XcmConfig>(origin, call)
+		}
+	}
+
 	impl xcm_runtime_apis::conversions::LocationToAccountApi<Block, AccountId> for Runtime {
 		fn convert_location(location: VersionedLocation) -> Result<
 			AccountId,
@@ -3049,8 +3222,22 @@ sp_api::impl_runtime_apis! {
 				}
 
 				fn unlockable_asset() -> Result<(Location, Location, Asset), BenchmarkError> {
-					// Kusama doesn't support asset locking
-					Err(BenchmarkError::Skip)
+					use xcm_executor::traits::AssetLock;
+
+					// KSM of an account locked in our favour on Asset Hub. Kusama doesn't lock
+					// its own assets, so only `note_unlockable` and `request_unlock` are
+					// supported. The lock is noted already, as `request_unlock` expects.
+					let owner: AccountId = frame_benchmarking::account("owner", 0, 0);
+					let amount = ExistentialDeposit::get() * 1000;
+					let _ = <Balances as frame_support::traits::Currency<_>>::make_free_balance_be(
+						&owner,
+						amount * 2,
+					);
+					let owner: Location = AccountId32 { network: None, id: owner.into() }.into();
+					let asset: Asset = (TokenLocation::get(), amount).into();
+					XcmPallet::note_unlockable(AssetHubLocation::get(), asset.clone(), owner.clone())
+						.map_err(|_| BenchmarkError::Stop("the lock of Asset Hub is not trusted"))?;
+					Ok((AssetHubLocation::get(), owner, asset))
 				}
 
 				fn export_message_origin_and_destination(
@@ -3060,8 +3247,12 @@ sp_api::impl_runtime_apis! {
 				}
 
 				fn alias_origin() -> Result<(Location, Location), BenchmarkError> {
-					// The XCM executor of Kusama doesn't have a configured `Aliasers`
-					Err(BenchmarkError::Skip)
+					// An account of Asset Hub aliasing into the same account here.
+					let account = AccountId32 { network: None, id: [1; 32] };
+					Ok((
+						Location::new(0, [Parachain(system_parachain::ASSET_HUB_ID), account]),
+						Location::new(0, [account]),
+					))
 				}
 			}
 
@@ -3134,6 +3325,63 @@ mod multiplier_tests {
 		})
 	}
 
+	#[test]
+	fn era_payout_follows_inflation_parameters() {
+		const MILLISECONDS_PER_YEAR: u64 = (1000 * 3600 * 24 * 36525) / 100;
+
+		sp_io::TestExternalities::default().execute_with(|| {
+			let total_stakable = 10_000_000 * UNITS;
+			let params = EraPayout::params(0, total_stakable, MILLISECONDS_PER_YEAR)
+				.expect("Kusama follows the staking curve");
+			assert_eq!(params.ideal_stake, Perquintill::from_percent(75));
+			assert_eq!(params.max_annual_inflation, Perquintill::from_percent(10));
+			assert_eq!(params.min_annual_inflation, Perquintill::from_rational(25u64, 1000));
+			assert_eq!(params.falloff, Perquintill::from_percent(5));
+			assert_eq!(params.period_fraction, Perquintill::one());
+			// There are no auctioned parachains yet.
+			assert_eq!(params.legacy_auction_proportion, Some(Perquintill::zero()));
+
+			// Nothing staked: the minimum inflation to the stakers, the rest of the maximum one to
+			// the treasury.
+			assert_eq!(relay_common::relay_era_payout(params), (250_000 * UNITS, 750_000 * UNITS));
+			// Half of the ideal stake: half way between the minimum and the maximum inflation.
+			assert_eq!(
+				EraPayout::payout(3_750_000 * UNITS, total_stakable, MILLISECONDS_PER_YEAR),
+				(625_000 * UNITS, 375_000 * UNITS)
+			);
+			// A quarter of a year.
+			assert_eq!(
+				EraPayout::payout(0, total_stakable, MILLISECONDS_PER_YEAR / 4),
+				(62_500 * UNITS, 187_500 * UNITS)
+			);
+		});
+	}
+
+	#[test]
+	fn inflation_forecast_compounds_issuance() {
+		sp_io::TestExternalities::default().execute_with(|| {
+			let era_duration = 6 * (HOURS as Moment) * MILLISECS_PER_BLOCK;
+			let payout = |total_staked, total_stakable| {
+				EraPayout::payout(total_staked, total_stakable, era_duration)
+			};
+			let staking_rate = Perquintill::from_percent(50);
+			let total_stakable = 15_000_000 * UNITS;
+
+			let forecast =
+				relay_common::forecast_era_payouts(total_stakable, staking_rate, 3, payout);
+			assert_eq!(forecast.len(), 3);
+			assert_eq!(forecast[0], payout(staking_rate * total_stakable, total_stakable));
+			// The payouts of the first era are part of the stakable issuance of the second one.
+			let total_stakable = total_stakable + forecast[0].0 + forecast[0].1;
+			assert_eq!(forecast[1], payout(staking_rate * total_stakable, total_stakable));
+			assert!(forecast[2].0 > forecast[1].0);
+
+			let forecast =
+				relay_common::forecast_era_payouts(total_stakable, staking_rate, u32::MAX, payout);
+			assert_eq!(forecast.len() as u32, relay_common::MAX_FORECAST_ERAS);
+		});
+	}
+
 	#[test]
 	fn fast_unstake_estimate() {
 		use pallet_fast_unstake::WeightInfo;
@@ -3152,6 +3400,46 @@ mod multiplier_tests {
 		assert_eq!(TREASURY_PALLET_ID, <Treasury as PalletInfoAccess>::index() as u8);
 	}
 
+	#[test]
+	fn nis_counterpart_pallet_index_is_correct() {
+		assert_eq!(
+			kusama_runtime_constants::NIS_COUNTERPART_PALLET_ID,
+			<NisCounterpartBalances as PalletInfoAccess>::index() as u8
+		);
+	}
+
+	#[test]
+	fn on_demand_pallet_index_is_correct() {
+		assert_eq!(
+			kusama_runtime_constants::ON_DEMAND_PALLET_ID,
+			<OnDemandAssignmentProvider as PalletInfoAccess>::index() as u8
+		);
+	}
+
+	#[test]
+	fn para_channels_pallet_index_is_correct() {
+		assert_eq!(
+			kusama_runtime_constants::PARA_CHANNELS_PALLET_ID,
+			<ParaChannels as PalletInfoAccess>::index() as u8
+		);
+	}
+
+	#[test]
+	fn registrar_pallet_index_is_correct() {
+		assert_eq!(
+			kusama_runtime_constants::REGISTRAR_PALLET_ID,
+			<Registrar as PalletInfoAccess>::index() as u8
+		);
+	}
+
+	#[test]
+	fn para_onboarding_pallet_index_is_correct() {
+		assert_eq!(
+			kusama_runtime_constants::PARA_ONBOARDING_PALLET_ID,
+			<ParaOnboarding as PalletInfoAccess>::index() as u8
+		);
+	}
+
 	#[test]
 	#[ignore]
 	fn multiplier_growth_simulator() {
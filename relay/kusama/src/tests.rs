@@ -180,3 +180,564 @@ fn check_treasury_pallet_id() {
 		kusama_runtime_constants::TREASURY_PALLET_ID
 	);
 }
+
+#[test]
+fn treasury_proxy_types_encode_correctly() {
+	assert_eq!(ProxyType::Treasury.encode(), vec![11]);
+	assert_eq!(ProxyType::Bounties.encode(), vec![12]);
+	assert_eq!(ProxyType::decode(&mut &[11u8][..]).unwrap(), ProxyType::Treasury);
+	assert_eq!(ProxyType::decode(&mut &[12u8][..]).unwrap(), ProxyType::Bounties);
+}
+
+#[test]
+fn treasury_proxy_types_superset_works() {
+	for proxy_type in [ProxyType::Treasury, ProxyType::Bounties] {
+		assert!(ProxyType::Any.is_superset(&proxy_type));
+		assert!(ProxyType::NonTransfer.is_superset(&proxy_type));
+		assert!(ProxyType::Governance.is_superset(&proxy_type));
+		assert!(!ProxyType::Staking.is_superset(&proxy_type));
+		assert!(!proxy_type.is_superset(&ProxyType::Governance));
+	}
+	assert!(!ProxyType::Treasury.is_superset(&ProxyType::Bounties));
+	assert!(!ProxyType::Bounties.is_superset(&ProxyType::Treasury));
+}
+
+#[test]
+fn proxy_filter_api_works() {
+	let remark = RuntimeCall::System(frame_system::Call::remark { remark: vec![] });
+	let transfer = RuntimeCall::Balances(pallet_balances::Call::transfer_allow_death {
+		dest: AccountId::from([0u8; 32]).into(),
+		value: 1,
+	});
+	let chill = RuntimeCall::Staking(pallet_staking::Call::chill {});
+	let chill_pool =
+		RuntimeCall::NominationPools(pallet_nomination_pools::Call::chill { pool_id: 1 });
+	let check_spend = RuntimeCall::Treasury(pallet_treasury::Call::check_status { index: 0 });
+	let claim_bounty = RuntimeCall::Bounties(pallet_bounties::Call::claim_bounty { bounty_id: 0 });
+	let reject_announcement = RuntimeCall::Proxy(pallet_proxy::Call::reject_announcement {
+		delegate: AccountId::from([0u8; 32]).into(),
+		call_hash: Default::default(),
+	});
+	let reserve_para = RuntimeCall::Registrar(paras_registrar::Call::reserve {});
+	let swap_para =
+		RuntimeCall::Registrar(paras_registrar::Call::swap { id: 2000.into(), other: 2001.into() });
+	let unbid = RuntimeCall::Society(pallet_society::Call::unbid {});
+	proxy_filter_api::assert_api_works::<Runtime>(&[
+		(ProxyType::Any, transfer.clone(), true),
+		(ProxyType::NonTransfer, remark.clone(), true),
+		(ProxyType::NonTransfer, transfer.clone(), false),
+		(ProxyType::NonTransfer, swap_para.clone(), false),
+		(ProxyType::Governance, check_spend.clone(), true),
+		(ProxyType::Governance, claim_bounty.clone(), true),
+		(ProxyType::Governance, chill.clone(), false),
+		(ProxyType::Staking, chill.clone(), true),
+		(ProxyType::Staking, chill_pool.clone(), true),
+		(ProxyType::Staking, check_spend.clone(), false),
+		(ProxyType::NominationPools, chill_pool, true),
+		(ProxyType::NominationPools, chill, false),
+		(ProxyType::CancelProxy, reject_announcement, true),
+		(ProxyType::CancelProxy, remark.clone(), false),
+		(ProxyType::Auction, swap_para.clone(), true),
+		(ProxyType::Auction, reserve_para.clone(), true),
+		(ProxyType::Society, unbid.clone(), true),
+		(ProxyType::Society, remark.clone(), false),
+		(ProxyType::Spokesperson, remark, true),
+		(ProxyType::Spokesperson, unbid, false),
+		(ProxyType::ParaRegistration, reserve_para, true),
+		(ProxyType::ParaRegistration, swap_para, false),
+		(ProxyType::Treasury, check_spend.clone(), true),
+		(ProxyType::Treasury, claim_bounty.clone(), false),
+		(ProxyType::Bounties, claim_bounty, true),
+		(ProxyType::Bounties, check_spend, false),
+		(ProxyType::Bounties, transfer, false),
+	]);
+}
+
+#[test]
+fn referenda_tracks_api_works() {
+	use referenda_tracks_api::{assert_api_works, track_thresholds, tracks};
+
+	assert_api_works::<Runtime, ()>();
+	assert_api_works::<Runtime, FellowshipReferendaInstance>();
+
+	let (root, root_info) = &tracks::<Runtime, ()>()[0];
+	assert_eq!((*root, root_info.name.as_str()), (0, "root"));
+	assert_eq!(track_thresholds::<Runtime, ()>(0, 0).unwrap().support, Perbill::from_percent(50));
+	assert_eq!(track_thresholds::<Runtime, ()>(u16::MAX, 0), None);
+}
+
+#[test]
+#[cfg(not(feature = "fast-runtime"))]
+fn governance_periods_are_not_scaled_without_fast_runtime() {
+	use pallet_referenda::TracksInfo as _;
+
+	let (_, root) = &<Runtime as pallet_referenda::Config>::Tracks::tracks()[0];
+	assert_eq!(root.name, "root");
+	assert_eq!(
+		(root.prepare_period, root.decision_period, root.confirm_period),
+		(2 * HOURS, 14 * DAYS, 24 * HOURS)
+	);
+	assert_eq!(root.min_enactment_period, 24 * HOURS);
+	assert_eq!(<Runtime as pallet_referenda::Config>::UndecidingTimeout::get(), 14 * DAYS);
+	assert_eq!(<Runtime as pallet_conviction_voting::Config>::VoteLockingPeriod::get(), 7 * DAYS);
+
+	type FellowshipTracks =
+		<Runtime as pallet_referenda::Config<FellowshipReferendaInstance>>::Tracks;
+	type FellowshipUndecidingTimeout =
+		<Runtime as pallet_referenda::Config<FellowshipReferendaInstance>>::UndecidingTimeout;
+	let (_, candidates) = &FellowshipTracks::tracks()[0];
+	assert_eq!(
+		(candidates.prepare_period, candidates.decision_period, candidates.confirm_period),
+		(30 * MINUTES, 7 * DAYS, 30 * MINUTES)
+	);
+	assert_eq!(FellowshipUndecidingTimeout::get(), 7 * DAYS);
+}
+
+#[test]
+#[cfg(feature = "fast-runtime")]
+fn governance_periods_are_scaled_with_fast_runtime() {
+	use pallet_referenda::TracksInfo as _;
+
+	let (_, root) = &<Runtime as pallet_referenda::Config>::Tracks::tracks()[0];
+	assert_eq!(
+		(root.prepare_period, root.decision_period, root.confirm_period),
+		(1, 14 * MINUTES, MINUTES)
+	);
+	assert_eq!(root.min_enactment_period, MINUTES);
+	assert_eq!(<Runtime as pallet_referenda::Config>::UndecidingTimeout::get(), 14 * MINUTES);
+	assert_eq!(<Runtime as pallet_conviction_voting::Config>::VoteLockingPeriod::get(), 1);
+
+	type FellowshipTracks =
+		<Runtime as pallet_referenda::Config<FellowshipReferendaInstance>>::Tracks;
+	type FellowshipUndecidingTimeout =
+		<Runtime as pallet_referenda::Config<FellowshipReferendaInstance>>::UndecidingTimeout;
+	let (_, candidates) = &FellowshipTracks::tracks()[0];
+	assert_eq!(
+		(candidates.prepare_period, candidates.decision_period, candidates.confirm_period),
+		(1, 7 * MINUTES, 1)
+	);
+	assert_eq!(FellowshipUndecidingTimeout::get(), 7 * MINUTES);
+}
+
+#[test]
+fn staking_reward_estimates_follow_exposures() {
+	use pallet_staking::{
+		ActiveEra, ActiveEraInfo, Bonded, EraPayout as _, ErasStakersOverview, ErasStakersPaged,
+		ErasTotalStake, ErasValidatorPrefs, Ledger, Nominations, Nominators, StakingLedger,
+		ValidatorPrefs,
+	};
+	use relay_common::staking_rewards::*;
+	use sp_staking::{ExposurePage, IndividualExposure, PagedExposureMetadata};
+
+	let (validator, other, nominator) =
+		(AccountId::from([1; 32]), AccountId::from([2; 32]), AccountId::from([3; 32]));
+	sp_io::TestExternalities::default().execute_with(|| {
+		pallet_balances::TotalIssuance::<Runtime>::put(1_600_000_000 * UNITS);
+		ActiveEra::<Runtime>::put(ActiveEraInfo { index: 1, start: None });
+		ErasTotalStake::<Runtime>::insert(1, 800_000_000 * UNITS);
+		let overview = |total, own, nominator_count| PagedExposureMetadata {
+			total,
+			own,
+			nominator_count,
+			page_count: nominator_count,
+		};
+		ErasStakersOverview::<Runtime>::insert(
+			1,
+			&validator,
+			overview(400_000_000 * UNITS, 100_000_000 * UNITS, 1),
+		);
+		ErasStakersPaged::<Runtime>::insert(
+			(1, &validator, 0),
+			ExposurePage {
+				page_total: 300_000_000 * UNITS,
+				others: vec![IndividualExposure {
+					who: nominator.clone(),
+					value: 300_000_000 * UNITS,
+				}],
+			},
+		);
+		ErasValidatorPrefs::<Runtime>::insert(
+			1,
+			&validator,
+			ValidatorPrefs { commission: Perbill::from_percent(10), blocked: false },
+		);
+		ErasStakersOverview::<Runtime>::insert(
+			1,
+			&other,
+			overview(400_000_000 * UNITS, 400_000_000 * UNITS, 0),
+		);
+		Bonded::<Runtime>::insert(&nominator, &nominator);
+		Ledger::<Runtime>::insert(
+			&nominator,
+			StakingLedger::<Runtime>::new(nominator.clone(), 300_000_000 * UNITS),
+		);
+		Nominators::<Runtime>::insert(
+			&nominator,
+			Nominations {
+				targets: vec![validator.clone()].try_into().unwrap(),
+				submitted_in: 0,
+				suppressed: false,
+			},
+		);
+
+		let (staking_payout, _rest) = EraPayout::era_payout(
+			800_000_000 * UNITS,
+			1_600_000_000 * UNITS,
+			Runtime::ERA_DURATION,
+		);
+		// Both validators are paid the same, the first one takes 10% of it as commission.
+		let validator_payout = staking_payout / 2;
+		let leftover = validator_payout - Perbill::from_percent(10) * validator_payout;
+
+		let stash = estimate_stash_rewards::<Runtime>(&nominator, Runtime::ERA_DURATION);
+		let stash = stash.unwrap();
+		assert_eq!(stash.stake, 300_000_000 * UNITS);
+		assert_eq!(stash.era_reward, Perbill::from_rational(3u32, 4) * leftover);
+		assert!(stash.annual_yield > sp_runtime::Perquintill::zero());
+
+		// The stake is split between the active targets, the duplicate and inactive ones are
+		// ignored.
+		let targets = vec![other.clone(), other, AccountId::from([4; 32])];
+		let nomination = estimate_nomination_rewards::<Runtime>(
+			targets,
+			400_000_000 * UNITS,
+			Runtime::ERA_DURATION,
+		);
+		assert_eq!(nomination.era_reward, validator_payout / 2);
+		assert_eq!(
+			estimate_nomination_rewards::<Runtime>(vec![], 1, Runtime::ERA_DURATION).era_reward,
+			0
+		);
+
+		assert_eq!(estimate_stash_rewards::<Runtime>(&validator, Runtime::ERA_DURATION), None);
+		assert_eq!(estimate_pool_rewards::<Runtime>(1, Runtime::ERA_DURATION), None);
+	});
+}
+
+migration_test_utils::impl_migration_tests!(crate, "development");
+
+#[test]
+fn society_founder_is_set_by_referendum() {
+	use frame_support::{
+		assert_ok,
+		traits::{schedule::DispatchTime, Currency, OnInitialize, StorePreimage},
+	};
+	use pallet_conviction_voting::{AccountVote, Conviction, Vote};
+	use pallet_referenda::TracksInfo as _;
+	use sp_keyring::Sr25519Keyring::{Alice, Bob};
+	use sp_runtime::traits::Dispatchable;
+
+	fn run_to_block(n: BlockNumber) {
+		while System::block_number() < n {
+			let next = System::block_number() + 1;
+			System::set_block_number(next);
+			Scheduler::on_initialize(next);
+		}
+	}
+
+	sp_io::TestExternalities::new_empty().execute_with(|| {
+		let preset = genesis_config_presets::get_preset(&"development".into()).unwrap();
+		frame_support::genesis_builder_helper::build_state::<RuntimeGenesisConfig>(preset).unwrap();
+		System::set_block_number(1);
+
+		let (alice, bob) = (Alice.to_account_id(), Bob.to_account_id());
+		let found_society = RuntimeCall::Society(pallet_society::Call::found_society {
+			founder: bob.clone().into(),
+			max_members: 100,
+			max_intake: 10,
+			max_strikes: 10,
+			candidate_deposit: QUID,
+			rules: b"be cool".to_vec(),
+		});
+		// Only Root or a `SocietyAdmin` referendum may set the founder.
+		assert!(found_society.clone().dispatch(RuntimeOrigin::signed(alice.clone())).is_err());
+
+		// Alice holds most of the issuance, so her vote alone passes the referendum.
+		let issuance = Balances::total_issuance();
+		Balances::make_free_balance_be(&alice, issuance * 10);
+		let society_admin: OriginCaller = pallet_custom_origins::Origin::SocietyAdmin.into();
+		assert_eq!(governance::TracksInfo::track_for(&society_admin), Ok(16));
+		assert_ok!(Referenda::submit(
+			RuntimeOrigin::signed(alice.clone()),
+			Box::new(society_admin),
+			Preimage::bound(found_society).unwrap(),
+			DispatchTime::After(0),
+		));
+		assert_ok!(Referenda::place_decision_deposit(RuntimeOrigin::signed(alice.clone()), 0));
+		assert_ok!(ConvictionVoting::vote(
+			RuntimeOrigin::signed(alice),
+			0,
+			AccountVote::Standard {
+				vote: Vote { aye: true, conviction: Conviction::Locked1x },
+				balance: issuance * 9,
+			},
+		));
+
+		let track = governance::TracksInfo::info(16).unwrap();
+		run_to_block(1 + track.prepare_period + track.confirm_period + track.min_enactment_period);
+
+		assert_eq!(pallet_society::Founder::<Runtime>::get(), Some(bob));
+	});
+}
+
+#[test]
+fn unlockable_notes_are_only_executed_for_free_alone_and_from_asset_hub() {
+	use xcm::latest::{Instruction, Junction::*, Location, Weight as XcmWeight};
+	use xcm_config::{AssetHubLocation, Barrier, TokenLocation};
+	use xcm_executor::traits::{Properties, ShouldExecute};
+
+	let note = Instruction::<RuntimeCall>::NoteUnlockable {
+		asset: (TokenLocation::get(), UNITS).into(),
+		owner: AccountId32 { network: None, id: [1; 32] }.into(),
+	};
+	let should_execute = |origin: Location, mut message: Vec<Instruction<RuntimeCall>>| {
+		Barrier::should_execute(
+			&origin,
+			&mut message,
+			XcmWeight::from_parts(1_000_000, 1_000),
+			&mut Properties { weight_credit: XcmWeight::zero(), message_id: None },
+		)
+	};
+
+	assert_eq!(should_execute(AssetHubLocation::get(), vec![note.clone()]), Ok(()));
+	// Nothing but the note is executed for free.
+	for message in [
+		vec![note.clone(), Instruction::ClearOrigin],
+		vec![Instruction::ClearOrigin, note.clone()],
+		vec![note.clone(), note.clone()],
+		vec![
+			note.clone(),
+			Instruction::Transact {
+				origin_kind: xcm::latest::OriginKind::Superuser,
+				require_weight_at_most: XcmWeight::from_parts(1_000_000, 1_000),
+				call: RuntimeCall::System(frame_system::Call::remark { remark: vec![] })
+					.encode()
+					.into(),
+			},
+		],
+	] {
+		assert!(should_execute(AssetHubLocation::get(), message).is_err());
+	}
+	// Only Asset Hub itself may send it.
+	assert!(should_execute(Location::new(0, [Parachain(2000)]), vec![note.clone()]).is_err());
+	assert!(should_execute(
+		Location::new(0, [Parachain(1000), AccountId32 { network: None, id: [1; 32] }]),
+		vec![note]
+	)
+	.is_err());
+}
+
+/// Externalities with the paras 2000 and 2001 onboarded and funded, under a configuration
+/// admitting channels between them.
+fn para_channels_ext() -> sp_io::TestExternalities {
+	use frame_support::{
+		storage::{storage_prefix, unhashed},
+		traits::fungible::Mutate,
+		StorageHasher, Twox64Concat,
+	};
+	use parachains_configuration::{ActiveConfig, HostConfiguration};
+	use sp_runtime::traits::AccountIdConversion;
+
+	let mut ext = sp_io::TestExternalities::default();
+	ext.execute_with(|| {
+		System::set_block_number(1);
+		ActiveConfig::<Runtime>::put(HostConfiguration {
+			hrmp_channel_max_capacity: 8,
+			hrmp_channel_max_message_size: 1024,
+			hrmp_max_parachain_outbound_channels: 4,
+			hrmp_max_parachain_inbound_channels: 4,
+			hrmp_sender_deposit: UNITS,
+			hrmp_recipient_deposit: UNITS,
+			..Default::default()
+		});
+		for para in [ParaId::from(2000), ParaId::from(2001)] {
+			// Onboarding takes sessions, so the paras are set as parachains directly.
+			let key = [
+				storage_prefix(b"Paras", b"ParaLifecycles").as_slice(),
+				&Twox64Concat::hash(&para.encode()),
+			]
+			.concat();
+			unhashed::put(&key, &parachains_paras::ParaLifecycle::Parachain);
+			Balances::set_balance(&para.into_account_truncating(), 10 * UNITS);
+		}
+	});
+	ext.commit_all().expect("the setup is committed");
+	ext
+}
+
+fn para_origin(para: u32) -> RuntimeOrigin {
+	parachains_origin::Origin::Parachain(para.into()).into()
+}
+
+#[test]
+fn only_asset_hub_operates_the_channels_of_other_paras() {
+	use frame_support::{assert_noop, assert_ok};
+	use parachains_hrmp::HrmpOpenChannelRequests;
+	use polkadot_primitives::HrmpChannelId;
+	use sp_runtime::{traits::AccountIdConversion, DispatchError::BadOrigin};
+
+	let asset_hub = system_parachain::ASSET_HUB_ID;
+	let (sender, recipient) = (ParaId::from(2000), ParaId::from(2001));
+	let channel = HrmpChannelId { sender, recipient };
+	let reserved = |para: ParaId| {
+		let account: AccountId = para.into_account_truncating();
+		Balances::reserved_balance(account)
+	};
+
+	para_channels_ext().execute_with(|| {
+		// Neither other system parachains, the paras themselves nor accounts operate channels.
+		for origin in [
+			para_origin(system_parachain::BRIDGE_HUB_ID),
+			para_origin(2000),
+			para_origin(2001),
+			RuntimeOrigin::signed(AccountId::from([1; 32])),
+			RuntimeOrigin::root(),
+		] {
+			assert_noop!(
+				ParaChannels::init_open_channel(origin.clone(), sender, recipient, 8, 1024),
+				BadOrigin
+			);
+			assert_noop!(ParaChannels::accept_open_channel(origin, recipient, sender), BadOrigin);
+		}
+		// Asset Hub operates its own channels through `Hrmp`.
+		assert_noop!(
+			ParaChannels::init_open_channel(
+				para_origin(asset_hub),
+				asset_hub.into(),
+				recipient,
+				8,
+				1024
+			),
+			BadOrigin
+		);
+		assert_noop!(
+			ParaChannels::accept_open_channel(para_origin(asset_hub), asset_hub.into(), sender),
+			BadOrigin
+		);
+
+		assert_ok!(ParaChannels::init_open_channel(
+			para_origin(asset_hub),
+			sender,
+			recipient,
+			8,
+			1024
+		));
+		assert!(
+			!HrmpOpenChannelRequests::<Runtime>::get(channel.clone())
+				.expect("the channel is requested")
+				.confirmed
+		);
+		assert_eq!((reserved(sender), reserved(recipient)), (UNITS, 0));
+
+		assert_ok!(ParaChannels::accept_open_channel(para_origin(asset_hub), recipient, sender));
+		assert!(
+			HrmpOpenChannelRequests::<Runtime>::get(channel)
+				.expect("the channel is requested")
+				.confirmed
+		);
+		assert_eq!((reserved(sender), reserved(recipient)), (UNITS, UNITS));
+	});
+}
+
+#[test]
+fn para_channels_weigh_as_much_as_the_hrmp_calls() {
+	use frame_support::{assert_ok, dispatch::GetDispatchInfo};
+
+	let asset_hub = system_parachain::ASSET_HUB_ID;
+	let (sender, recipient) = (ParaId::from(2000), ParaId::from(2001));
+	let requested = || {
+		let mut ext = para_channels_ext();
+		ext.execute_with(|| {
+			assert_ok!(Hrmp::hrmp_init_open_channel(para_origin(2000), recipient, 8, 1024));
+		});
+		ext.commit_all().expect("the request is committed");
+		ext
+	};
+
+	// The calls reuse the weights of the `Hrmp` calls they make on behalf of the paras, ...
+	assert_eq!(
+		RuntimeCall::ParaChannels(pallet_para_channels::Call::init_open_channel {
+			para: sender,
+			recipient,
+			proposed_max_capacity: 8,
+			proposed_max_message_size: 1024,
+		})
+		.get_dispatch_info()
+		.weight,
+		RuntimeCall::Hrmp(parachains_hrmp::Call::hrmp_init_open_channel {
+			recipient,
+			proposed_max_capacity: 8,
+			proposed_max_message_size: 1024,
+		})
+		.get_dispatch_info()
+		.weight,
+	);
+	assert_eq!(
+		RuntimeCall::ParaChannels(pallet_para_channels::Call::accept_open_channel {
+			para: recipient,
+			sender,
+		})
+		.get_dispatch_info()
+		.weight,
+		RuntimeCall::Hrmp(parachains_hrmp::Call::hrmp_accept_open_channel { sender })
+			.get_dispatch_info()
+			.weight,
+	);
+
+	// ... as checking the operator reads no storage on top of them.
+	let (_, operated) = para_channels_ext().execute_and_prove(|| {
+		assert_ok!(ParaChannels::init_open_channel(
+			para_origin(asset_hub),
+			sender,
+			recipient,
+			8,
+			1024
+		));
+	});
+	let (_, direct) = para_channels_ext().execute_and_prove(|| {
+		assert_ok!(Hrmp::hrmp_init_open_channel(para_origin(2000), recipient, 8, 1024));
+	});
+	assert_eq!(operated, direct);
+
+	let (_, operated) = requested().execute_and_prove(|| {
+		assert_ok!(ParaChannels::accept_open_channel(para_origin(asset_hub), recipient, sender));
+	});
+	let (_, direct) = requested().execute_and_prove(|| {
+		assert_ok!(Hrmp::hrmp_accept_open_channel(para_origin(2001), sender));
+	});
+	assert_eq!(operated, direct);
+}
+
+#[test]
+fn system_parachains_may_alias_into_their_accounts_and_children() {
+	use frame_support::traits::ContainsPair;
+	use xcm::latest::{Junction::*, Location};
+	use xcm_config::TrustedAliasers;
+
+	let account = AccountId32 { network: None, id: [1; 32] };
+	let aliases = |origin: Location, target: Location| {
+		<TrustedAliasers as ContainsPair<Location, Location>>::contains(&origin, &target)
+	};
+
+	// An account of a system parachain aliases into the same account here.
+	assert!(aliases(Location::new(0, [Parachain(1000), account]), Location::new(0, [account])));
+	// But not into another one, nor from a non-system parachain.
+	assert!(!aliases(
+		Location::new(0, [Parachain(1000), account]),
+		Location::new(0, [AccountId32 { network: None, id: [2; 32] }])
+	));
+	assert!(!aliases(Location::new(0, [Parachain(2000), account]), Location::new(0, [account])));
+	// Any location aliases into its own children, but not into its parent or siblings.
+	assert!(aliases(
+		Location::new(0, [Parachain(2000)]),
+		Location::new(0, [Parachain(2000), account])
+	));
+	assert!(!aliases(
+		Location::new(0, [Parachain(2000), account]),
+		Location::new(0, [Parachain(2000)])
+	));
+	assert!(!aliases(
+		Location::new(0, [Parachain(2000)]),
+		Location::new(0, [Parachain(2001), account])
+	));
+}
@@ -29,9 +29,11 @@ pub mod pallet_election_provider_multi_phase;
 pub mod pallet_fast_unstake;
 pub mod pallet_indices;
 pub mod pallet_message_queue;
+pub mod pallet_migrations;
 pub mod pallet_multisig;
 pub mod pallet_nis;
 pub mod pallet_nomination_pools;
+pub mod pallet_para_onboarding;
 pub mod pallet_parameters;
 pub mod pallet_preimage;
 pub mod pallet_proxy;
@@ -234,20 +234,18 @@ impl<RuntimeCall> XcmWeightInfo<RuntimeCall> for KusamaXcmWeight<RuntimeCall> {
 		Weight::MAX
 	}
 	fn lock_asset(_: &Asset, _: &Location) -> Weight {
-		// Kusama does not currently support asset locking operations
+		// Kusama does not lock its own assets in favour of other chains
 		Weight::MAX
 	}
 	fn unlock_asset(_: &Asset, _: &Location) -> Weight {
-		// Kusama does not currently support asset locking operations
+		// Kusama does not lock its own assets in favour of other chains
 		Weight::MAX
 	}
 	fn note_unlockable(_: &Asset, _: &Location) -> Weight {
-		// Kusama does not currently support asset locking operations
-		Weight::MAX
+		XcmGeneric::<Runtime>::note_unlockable()
 	}
 	fn request_unlock(_: &Asset, _: &Location) -> Weight {
-		// Kusama does not currently support asset locking operations
-		Weight::MAX
+		XcmGeneric::<Runtime>::request_unlock()
 	}
 	fn set_fees_mode(_: &bool) -> Weight {
 		XcmGeneric::<Runtime>::set_fees_mode()
@@ -259,8 +257,7 @@ impl<RuntimeCall> XcmWeightInfo<RuntimeCall> for KusamaXcmWeight<RuntimeCall> {
 		XcmGeneric::<Runtime>::clear_topic()
 	}
 	fn alias_origin(_: &Location) -> Weight {
-		// XCM Executor does not currently support alias origin operations
-		Weight::MAX
+		XcmGeneric::<Runtime>::alias_origin()
 	}
 	fn unpaid_execution(_: &WeightLimit, _: &Option<Location>) -> Weight {
 		XcmGeneric::<Runtime>::unpaid_execution()
@@ -333,6 +333,38 @@ impl<T: frame_system::Config> WeightInfo<T> {
 		Weight::from_parts(1_010_000, 0)
 			.saturating_add(Weight::from_parts(0, 0))
 	}
+	/// Storage: `XcmPallet::RemoteLockedFungibles` (r:1 w:1)
+	/// Proof: `XcmPallet::RemoteLockedFungibles` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	pub(crate) fn note_unlockable() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `130`
+		//  Estimated: `3595`
+		// Minimum execution time: 30_456_000 picoseconds.
+		Weight::from_parts(34_126_000, 3595)
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `XcmPallet::RemoteLockedFungibles` (r:1 w:1)
+	/// Proof: `XcmPallet::RemoteLockedFungibles` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Dmp::DeliveryFeeFactor` (r:1 w:0)
+	/// Proof: `Dmp::DeliveryFeeFactor` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `XcmPallet::SupportedVersion` (r:1 w:0)
+	/// Proof: `XcmPallet::SupportedVersion` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Dmp::DownwardMessageQueues` (r:1 w:1)
+	/// Proof: `Dmp::DownwardMessageQueues` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Dmp::DownwardMessageQueueHeads` (r:1 w:1)
+	/// Proof: `Dmp::DownwardMessageQueueHeads` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	pub(crate) fn request_unlock() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `313`
+		//  Estimated: `3778`
+		// Minimum execution time: 208_686_000 picoseconds.
+		Weight::from_parts(225_855_000, 3778)
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
 	pub(crate) fn unpaid_execution() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `0`
@@ -341,4 +373,11 @@ impl<T: frame_system::Config> WeightInfo<T> {
 		Weight::from_parts(1_120_000, 0)
 			.saturating_add(Weight::from_parts(0, 0))
 	}
+	pub(crate) fn alias_origin() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 2_449_000 picoseconds.
+		Weight::from_parts(2_720_000, 0)
+	}
 }
@@ -152,6 +152,18 @@ pub mod system_parachain {
 /// Polkadot Treasury pallet instance.
 pub const TREASURY_PALLET_ID: u8 = 19;
 
+/// Polkadot on-demand assignment provider pallet instance.
+pub const ON_DEMAND_PALLET_ID: u8 = 64;
+
+/// Polkadot para channels pallet instance.
+pub const PARA_CHANNELS_PALLET_ID: u8 = 66;
+
+/// Polkadot paras registrar pallet instance.
+pub const REGISTRAR_PALLET_ID: u8 = 70;
+
+/// Polkadot para onboarding pallet instance.
+pub const PARA_ONBOARDING_PALLET_ID: u8 = 75;
+
 #[cfg(test)]
 mod tests {
 	use super::{
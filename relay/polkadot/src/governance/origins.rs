@@ -63,6 +63,8 @@ pub mod pallet_custom_origins {
 		WhitelistedCaller,
 		/// Origin for signaling that the network wishes for some change.
 		WishForChange,
+		/// Origin for setting the dynamic parameters, i.e. the inflation and the treasury burn.
+		ParameterAdmin,
 	}
 
 	macro_rules! decl_unit_ensures {
@@ -106,6 +108,7 @@ pub mod pallet_custom_origins {
 		ReferendumKiller,
 		WhitelistedCaller,
 		WishForChange,
+		ParameterAdmin,
 	);
 
 	macro_rules! decl_ensure {
@@ -31,7 +31,7 @@ use polkadot_runtime_common::{
 	traits::OnSwap,
 	BlockHashCount, BlockLength, CurrencyToVote, SlowAdjustingFeeUpdate,
 };
-use relay_common::apis::InflationInfo;
+use relay_common::apis::{InflationInfo, RewardEstimate};
 
 use runtime_parachains::{
 	assigner_coretime as parachains_assigner_coretime, configuration as parachains_configuration,
@@ -62,14 +62,16 @@ use frame_election_provider_support::{
 };
 use frame_support::{
 	construct_runtime,
+	dynamic_params::{dynamic_pallet_params, dynamic_params},
 	genesis_builder_helper::{build_state, get_preset},
 	parameter_types,
 	traits::{
 		fungible::HoldConsideration,
 		tokens::{imbalance::ResolveTo, UnityOrOuterConversion},
-		ConstU32, ConstU8, EitherOf, EitherOfDiverse, Everything, FromContains, Get,
-		InstanceFilter, KeyOwnerProofSystem, LinearStoragePrice, OnRuntimeUpgrade, PrivilegeCmp,
-		ProcessMessage, ProcessMessageError, WithdrawReasons,
+		ConstU32, ConstU64, ConstU8, EitherOf, EitherOfDiverse, EnsureOrigin, EnsureOriginWithArg,
+		Equals, Everything, FromContains, Get, InstanceFilter, KeyOwnerProofSystem,
+		LinearStoragePrice, OnRuntimeUpgrade, PalletInfoAccess, PrivilegeCmp, ProcessMessage,
+		ProcessMessageError, WithdrawReasons,
 	},
 	weights::{
 		constants::{WEIGHT_PROOF_SIZE_PER_KB, WEIGHT_REF_TIME_PER_MICROS},
@@ -140,8 +142,8 @@ pub mod genesis_config_presets;
 // Governance configurations.
 pub mod governance;
 use governance::{
-	pallet_custom_origins, AuctionAdmin, FellowshipAdmin, GeneralAdmin, LeaseAdmin, StakingAdmin,
-	Treasurer, TreasurySpender,
+	pallet_custom_origins, AuctionAdmin, FellowshipAdmin, GeneralAdmin, LeaseAdmin, ParameterAdmin,
+	StakingAdmin, Treasurer, TreasurySpender,
 };
 pub mod impls;
 pub mod xcm_config;
@@ -215,7 +217,7 @@ impl frame_system::Config for Runtime {
 	type OnSetCode = ();
 	type MaxConsumers = frame_support::traits::ConstU32<16>;
 	type SingleBlockMigrations = ();
-	type MultiBlockMigrator = ();
+	type MultiBlockMigrator = MultiBlockMigrations;
 	type PreInherents = ();
 	type PostInherents = ();
 	type PostTransactions = ();
@@ -627,33 +629,120 @@ impl pallet_bags_list::Config<VoterBagsListInstance> for Runtime {
 	type Score = sp_npos_elections::VoteWeight;
 }
 
-/// Defines how much should the inflation be for an era given its duration.
-pub struct EraPayout;
-impl pallet_staking::EraPayout<Balance> for EraPayout {
-	fn era_payout(
-		_total_staked: Balance,
-		_total_issuance: Balance,
-		era_duration_millis: u64,
-	) -> (Balance, Balance) {
-		const MILLISECONDS_PER_YEAR: u64 = (1000 * 3600 * 24 * 36525) / 100;
-		// A normal-sized era will have 1 / 365.25 here:
-		let relative_era_len =
-			FixedU128::from_rational(era_duration_millis.into(), MILLISECONDS_PER_YEAR.into());
+#[derive(
+	Default, MaxEncodedLen, Encode, Decode, scale_info::TypeInfo, Clone, Eq, PartialEq, Debug,
+)]
+pub struct BurnDestinationAccount(pub Option<AccountId>);
 
-		// TI at the time of execution of [Referendum 1139](https://polkadot.subsquare.io/referenda/1139), block hash: `0x39422610299a75ef69860417f4d0e1d94e77699f45005645ffc5e8e619950f9f`.
-		let fixed_total_issuance: i128 = 15_011_657_390_566_252_333;
-		let fixed_inflation_rate = FixedU128::from_rational(8, 100);
-		let yearly_emission = fixed_inflation_rate.saturating_mul_int(fixed_total_issuance);
+impl BurnDestinationAccount {
+	pub fn is_set(&self) -> bool {
+		self.0.is_some()
+	}
+}
 
-		let era_emission = relative_era_len.saturating_mul_int(yearly_emission);
-		// 15% to treasury, as per ref 1139.
-		let to_treasury = FixedU128::from_rational(15, 100).saturating_mul_int(era_emission);
-		let to_stakers = era_emission.saturating_sub(to_treasury);
+/// Dynamic params that can be adjusted at runtime.
+#[dynamic_params(RuntimeParameters, pallet_parameters::Parameters::<Runtime>)]
+pub mod dynamic_params {
+	use super::*;
 
-		(to_stakers.saturated_into(), to_treasury.saturated_into())
+	/// Parameters used to calculate era payouts, see
+	/// [`relay_common::InflationModel::FixedEmission`].
+	///
+	/// The defaults are the values enacted by
+	/// [Referendum 1139](https://polkadot.subsquare.io/referenda/1139).
+	#[dynamic_pallet_params]
+	#[codec(index = 0)]
+	pub mod inflation {
+		/// The total issuance the yearly emission is calculated from.
+		///
+		/// TI at the time of execution of Referendum 1139, block hash:
+		/// `0x39422610299a75ef69860417f4d0e1d94e77699f45005645ffc5e8e619950f9f`.
+		#[codec(index = 0)]
+		pub static FixedTotalIssuance: Balance = 15_011_657_390_566_252_333;
+
+		/// The yearly emission as a fraction of [`FixedTotalIssuance`].
+		#[codec(index = 1)]
+		pub static AnnualInflation: Perquintill = Perquintill::from_percent(8);
+
+		/// The fraction of each era's emission that goes to the treasury instead of the stakers.
+		#[codec(index = 2)]
+		pub static TreasuryShare: Perquintill = Perquintill::from_percent(15);
+	}
+
+	/// Parameters used by `pallet-treasury` to handle the burn process.
+	#[dynamic_pallet_params]
+	#[codec(index = 1)]
+	pub mod treasury {
+		#[codec(index = 0)]
+		pub static BurnPortion: Permill = Permill::from_percent(1);
+
+		#[codec(index = 1)]
+		pub static BurnDestination: BurnDestinationAccount = Default::default();
 	}
 }
 
+#[cfg(feature = "runtime-benchmarks")]
+impl Default for RuntimeParameters {
+	fn default() -> Self {
+		RuntimeParameters::Inflation(dynamic_params::inflation::Parameters::AnnualInflation(
+			dynamic_params::inflation::AnnualInflation,
+			Some(Perquintill::from_percent(8)),
+		))
+	}
+}
+
+/// Defines what origin can modify which dynamic parameters.
+///
+/// All of them are set by Root or a referendum on the dedicated `parameter_admin` track.
+pub struct DynamicParameterOrigin;
+impl EnsureOriginWithArg<RuntimeOrigin, RuntimeParametersKey> for DynamicParameterOrigin {
+	type Success = ();
+
+	fn try_origin(
+		origin: RuntimeOrigin,
+		key: &RuntimeParametersKey,
+	) -> Result<Self::Success, RuntimeOrigin> {
+		use crate::RuntimeParametersKey::*;
+
+		match key {
+			Inflation(_) | Treasury(_) =>
+				EitherOf::<EnsureRoot<AccountId>, ParameterAdmin>::ensure_origin(origin.clone()),
+		}
+		.map_err(|_| origin)
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn try_successful_origin(_key: &RuntimeParametersKey) -> Result<RuntimeOrigin, ()> {
+		// Provide the origin for the parameter returned by `Default`:
+		Ok(RuntimeOrigin::root())
+	}
+}
+
+impl pallet_parameters::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type RuntimeParameters = RuntimeParameters;
+	type AdminOrigin = DynamicParameterOrigin;
+	// Use same weights as substrate ones.
+	type WeightInfo = weights::pallet_parameters::WeightInfo<Runtime>;
+}
+
+/// The inflation parameters of Polkadot, see [`dynamic_params::inflation`].
+pub struct InflationParams;
+impl relay_common::EraPayoutConfig for InflationParams {
+	fn inflation_model() -> relay_common::InflationModel {
+		relay_common::InflationModel::FixedEmission {
+			total_issuance: dynamic_params::inflation::FixedTotalIssuance::get(),
+			annual_inflation: dynamic_params::inflation::AnnualInflation::get(),
+			treasury_share: dynamic_params::inflation::TreasuryShare::get(),
+		}
+	}
+}
+
+/// Defines how much should the inflation be for an era given its duration.
+///
+/// The fixed emission doesn't depend on the auctioned parachains.
+pub type EraPayout = relay_common::EraPayout<InflationParams, ConstU64<0>>;
+
 parameter_types! {
 	// Six sessions in an era (24 hours).
 	pub const SessionsPerEra: SessionIndex = prod_or_fast!(6, 1);
@@ -726,7 +815,6 @@ parameter_types! {
 	pub const ProposalBondMinimum: Balance = 100 * DOLLARS;
 	pub const ProposalBondMaximum: Balance = 500 * DOLLARS;
 	pub const SpendPeriod: BlockNumber = 24 * DAYS;
-	pub const Burn: Permill = Permill::from_percent(1);
 	pub const TreasuryPalletId: PalletId = PalletId(*b"py/trsry");
 	pub const PayoutSpendPeriod: BlockNumber = 30 * DAYS;
 	// The asset's interior location for the paying account. This is the Treasury
@@ -745,14 +833,42 @@ parameter_types! {
 	pub const CouncilSpendOriginMaxAmount: Balance = Balance::MAX;
 }
 
+use frame_support::traits::{Currency, OnUnbalanced};
+
+pub type BalancesNegativeImbalance = <Balances as Currency<AccountId>>::NegativeImbalance;
+
+/// Burns the [`dynamic_params::treasury::BurnPortion`] of the treasury's funds every spend period,
+/// sending them to the [`dynamic_params::treasury::BurnDestination`] if one is set.
+pub struct TreasuryBurnHandler;
+
+impl OnUnbalanced<BalancesNegativeImbalance> for TreasuryBurnHandler {
+	fn on_nonzero_unbalanced(amount: BalancesNegativeImbalance) {
+		if let BurnDestinationAccount(Some(account)) =
+			dynamic_params::treasury::BurnDestination::get()
+		{
+			// Must resolve into existing but better to be safe.
+			Balances::resolve_creating(&account, amount);
+		} else {
+			// Without a destination the imbalance is dropped, i.e. the funds are burnt.
+			<() as OnUnbalanced<_>>::on_nonzero_unbalanced(amount)
+		}
+	}
+}
+
+impl Get<Permill> for TreasuryBurnHandler {
+	fn get() -> Permill {
+		dynamic_params::treasury::BurnPortion::get()
+	}
+}
+
 impl pallet_treasury::Config for Runtime {
 	type PalletId = TreasuryPalletId;
 	type Currency = Balances;
 	type RejectOrigin = EitherOfDiverse<EnsureRoot<AccountId>, Treasurer>;
 	type RuntimeEvent = RuntimeEvent;
 	type SpendPeriod = SpendPeriod;
-	type Burn = Burn;
-	type BurnDestination = ();
+	type Burn = TreasuryBurnHandler;
+	type BurnDestination = TreasuryBurnHandler;
 	type SpendFunds = Bounties;
 	type MaxApprovals = MaxApprovals;
 	type WeightInfo = weights::pallet_treasury::WeightInfo<Runtime>;
@@ -996,8 +1112,10 @@ parameter_types! {
 	RuntimeDebug,
 	MaxEncodedLen,
 	scale_info::TypeInfo,
+	Default,
 )]
 pub enum ProxyType {
+	#[default]
 	Any = 0,
 	NonTransfer = 1,
 	Governance = 2,
@@ -1008,6 +1126,8 @@ pub enum ProxyType {
 	Auction = 7,
 	NominationPools = 8,
 	ParaRegistration = 9,
+	Treasury = 10,
+	Bounties = 11,
 }
 
 #[cfg(test)]
@@ -1039,13 +1159,77 @@ mod proxy_type_tests {
 		assert!(ProxyType::decode(&mut &OldProxyType::SudoBalances.encode()[..]).is_err());
 		assert!(ProxyType::decode(&mut &OldProxyType::IdentityJudgement.encode()[..]).is_err());
 	}
-}
 
-impl Default for ProxyType {
-	fn default() -> Self {
-		Self::Any
+	#[test]
+	fn treasury_proxy_types_encode_correctly() {
+		assert_eq!(ProxyType::Treasury.encode(), vec![10]);
+		assert_eq!(ProxyType::Bounties.encode(), vec![11]);
+		assert_eq!(ProxyType::decode(&mut &[10u8][..]).unwrap(), ProxyType::Treasury);
+		assert_eq!(ProxyType::decode(&mut &[11u8][..]).unwrap(), ProxyType::Bounties);
+	}
+
+	#[test]
+	fn treasury_proxy_types_superset_works() {
+		for proxy_type in [ProxyType::Treasury, ProxyType::Bounties] {
+			assert!(ProxyType::Any.is_superset(&proxy_type));
+			assert!(ProxyType::NonTransfer.is_superset(&proxy_type));
+			assert!(ProxyType::Governance.is_superset(&proxy_type));
+			assert!(!ProxyType::Staking.is_superset(&proxy_type));
+			assert!(!proxy_type.is_superset(&ProxyType::Governance));
+		}
+		assert!(!ProxyType::Treasury.is_superset(&ProxyType::Bounties));
+		assert!(!ProxyType::Bounties.is_superset(&ProxyType::Treasury));
+	}
+
+	#[test]
+	fn proxy_filter_api_works() {
+		let remark = RuntimeCall::System(frame_system::Call::remark { remark: vec![] });
+		let transfer = RuntimeCall::Balances(pallet_balances::Call::transfer_allow_death {
+			dest: AccountId::from([0u8; 32]).into(),
+			value: 1,
+		});
+		let chill = RuntimeCall::Staking(pallet_staking::Call::chill {});
+		let chill_pool =
+			RuntimeCall::NominationPools(pallet_nomination_pools::Call::chill { pool_id: 1 });
+		let check_spend = RuntimeCall::Treasury(pallet_treasury::Call::check_status { index: 0 });
+		let claim_bounty =
+			RuntimeCall::Bounties(pallet_bounties::Call::claim_bounty { bounty_id: 0 });
+		let reject_announcement = RuntimeCall::Proxy(pallet_proxy::Call::reject_announcement {
+			delegate: AccountId::from([0u8; 32]).into(),
+			call_hash: Default::default(),
+		});
+		let reserve_para = RuntimeCall::Registrar(paras_registrar::Call::reserve {});
+		let swap_para = RuntimeCall::Registrar(paras_registrar::Call::swap {
+			id: 2000.into(),
+			other: 2001.into(),
+		});
+		proxy_filter_api::assert_api_works::<Runtime>(&[
+			(ProxyType::Any, transfer.clone(), true),
+			(ProxyType::NonTransfer, remark.clone(), true),
+			(ProxyType::NonTransfer, transfer, false),
+			(ProxyType::NonTransfer, swap_para.clone(), false),
+			(ProxyType::Governance, check_spend.clone(), true),
+			(ProxyType::Governance, claim_bounty.clone(), true),
+			(ProxyType::Governance, chill.clone(), false),
+			(ProxyType::Staking, chill.clone(), true),
+			(ProxyType::Staking, chill_pool.clone(), true),
+			(ProxyType::Staking, check_spend.clone(), false),
+			(ProxyType::NominationPools, chill_pool, true),
+			(ProxyType::NominationPools, chill, false),
+			(ProxyType::CancelProxy, reject_announcement, true),
+			(ProxyType::CancelProxy, remark, false),
+			(ProxyType::Auction, swap_para.clone(), true),
+			(ProxyType::Auction, reserve_para.clone(), true),
+			(ProxyType::ParaRegistration, reserve_para, true),
+			(ProxyType::ParaRegistration, swap_para, false),
+			(ProxyType::Treasury, check_spend.clone(), true),
+			(ProxyType::Treasury, claim_bounty.clone(), false),
+			(ProxyType::Bounties, claim_bounty, true),
+			(ProxyType::Bounties, check_spend, false),
+		]);
 	}
 }
+
 impl InstanceFilter<RuntimeCall> for ProxyType {
 	fn filter(&self, c: &RuntimeCall) -> bool {
 		match self {
@@ -1126,11 +1310,46 @@ impl InstanceFilter<RuntimeCall> for ProxyType {
 				c,
 				RuntimeCall::Registrar(paras_registrar::Call::reserve { .. }) |
 					RuntimeCall::Registrar(paras_registrar::Call::register { .. }) |
+					RuntimeCall::ParaOnboarding(pallet_para_onboarding::Call::register { .. }) |
 					RuntimeCall::Utility(pallet_utility::Call::batch { .. }) |
 					RuntimeCall::Utility(pallet_utility::Call::batch_all { .. }) |
 					RuntimeCall::Utility(pallet_utility::Call::force_batch { .. }) |
 					RuntimeCall::Proxy(pallet_proxy::Call::remove_proxy { .. })
 			),
+			ProxyType::Treasury => matches!(
+				c,
+				RuntimeCall::Treasury(
+					pallet_treasury::Call::payout { .. } |
+						pallet_treasury::Call::check_status { .. } |
+						pallet_treasury::Call::void_spend { .. }
+				) | RuntimeCall::Utility(
+					pallet_utility::Call::batch { .. } |
+						pallet_utility::Call::batch_all { .. } |
+						pallet_utility::Call::force_batch { .. }
+				)
+			),
+			ProxyType::Bounties => matches!(
+				c,
+				RuntimeCall::Bounties(
+					pallet_bounties::Call::accept_curator { .. } |
+						pallet_bounties::Call::unassign_curator { .. } |
+						pallet_bounties::Call::award_bounty { .. } |
+						pallet_bounties::Call::claim_bounty { .. } |
+						pallet_bounties::Call::extend_bounty_expiry { .. }
+				) | RuntimeCall::ChildBounties(
+					pallet_child_bounties::Call::add_child_bounty { .. } |
+						pallet_child_bounties::Call::propose_curator { .. } |
+						pallet_child_bounties::Call::accept_curator { .. } |
+						pallet_child_bounties::Call::unassign_curator { .. } |
+						pallet_child_bounties::Call::award_child_bounty { .. } |
+						pallet_child_bounties::Call::claim_child_bounty { .. } |
+						pallet_child_bounties::Call::close_child_bounty { .. }
+				) | RuntimeCall::Utility(
+					pallet_utility::Call::batch { .. } |
+						pallet_utility::Call::batch_all { .. } |
+						pallet_utility::Call::force_batch { .. }
+				)
+			),
 		}
 	}
 	fn is_superset(&self, o: &Self) -> bool {
@@ -1139,6 +1358,7 @@ impl InstanceFilter<RuntimeCall> for ProxyType {
 			(ProxyType::Any, _) => true,
 			(_, ProxyType::Any) => false,
 			(ProxyType::NonTransfer, _) => true,
+			(ProxyType::Governance, ProxyType::Treasury | ProxyType::Bounties) => true,
 			_ => false,
 		}
 	}
@@ -1268,6 +1488,15 @@ impl parachains_hrmp::Config for Runtime {
 	type VersionWrapper = XcmPallet;
 }
 
+parameter_types! {
+	pub const AssetHubParaId: ParaId = ParaId::new(system_parachain::ASSET_HUB_ID);
+}
+
+impl pallet_para_channels::Config for Runtime {
+	type ParaOrigin = RuntimeOrigin;
+	type Operators = Equals<AssetHubParaId>;
+}
+
 impl parachains_paras_inherent::Config for Runtime {
 	type WeightInfo = weights::runtime_parachains_paras_inherent::WeightInfo<Runtime>;
 }
@@ -1376,6 +1605,11 @@ impl paras_registrar::Config for Runtime {
 	type WeightInfo = weights::polkadot_runtime_common_paras_registrar::WeightInfo<Runtime>;
 }
 
+impl pallet_para_onboarding::Config for Runtime {
+	type Preimages = Preimage;
+	type WeightInfo = weights::pallet_para_onboarding::WeightInfo<Runtime>;
+}
+
 parameter_types! {
 	// 12 weeks = 3 months per lease period -> 8 lease periods ~ 2 years
 	pub LeasePeriod: BlockNumber = prod_or_fast!(12 * WEEKS, 12 * WEEKS, "DOT_LEASE_PERIOD");
@@ -1526,6 +1760,25 @@ impl pallet_state_trie_migration::Config for Runtime {
 	type MaxKeyLen = MigrationMaxKeyLen;
 }
 
+parameter_types! {
+	pub MbmServiceWeight: Weight = Perbill::from_percent(80) * BlockWeights::get().max_block;
+}
+
+impl pallet_migrations::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	#[cfg(not(feature = "runtime-benchmarks"))]
+	type Migrations = migrations::MultiBlock;
+	// Benchmarks need mocked migrations to guarantee that they succeed.
+	#[cfg(feature = "runtime-benchmarks")]
+	type Migrations = pallet_migrations::mock_helpers::MockedMigrations;
+	type CursorMaxLen = ConstU32<65_536>;
+	type IdentifierMaxLen = ConstU32<256>;
+	type MigrationStatusHandler = ();
+	type FailedMigrationHandler = frame_support::migrations::FreezeChainOnFailedMigration;
+	type MaxServiceWeight = MbmServiceWeight;
+	type WeightInfo = weights::pallet_migrations::WeightInfo<Runtime>;
+}
+
 /// The [frame_support::traits::tokens::ConversionFromAssetBalance] implementation provided by the
 /// `AssetRate` pallet instance.
 ///
@@ -1629,6 +1882,9 @@ construct_runtime! {
 		// Staking extension for delegation
 		DelegatedStaking: pallet_delegated_staking = 41,
 
+		// Parameters pallet.
+		Parameters: pallet_parameters = 46,
+
 		// Parachains pallets. Start indices at 50 to leave room.
 		ParachainsOrigin: parachains_origin = 50,
 		Configuration: parachains_configuration = 51,
@@ -1646,6 +1902,7 @@ construct_runtime! {
 		ParasSlashing: parachains_slashing = 63,
 		OnDemand: parachains_on_demand = 64,
 		CoretimeAssignmentProvider: parachains_assigner_coretime = 65,
+		ParaChannels: pallet_para_channels = 66,
 
 		// Parachain Onboarding Pallets. Start indices at 70 to leave room.
 		Registrar: paras_registrar = 70,
@@ -1653,6 +1910,7 @@ construct_runtime! {
 		Auctions: auctions = 72,
 		Crowdloan: crowdloan = 73,
 		Coretime: coretime = 74,
+		ParaOnboarding: pallet_para_onboarding = 75,
 
 		// State trie migration pallet, only temporary.
 		StateTrieMigration: pallet_state_trie_migration = 98,
@@ -1666,6 +1924,9 @@ construct_runtime! {
 		// Asset rate.
 		AssetRate: pallet_asset_rate = 101,
 
+		// Multi-block migrations, transactions are paused while they run.
+		MultiBlockMigrations: pallet_migrations = 102,
+
 		// BEEFY Bridges support.
 		Beefy: pallet_beefy = 200,
 		// MMR leaf construction must be after session in order to have a leaf's next_auth_set
@@ -1711,14 +1972,16 @@ mod clear_judgement_proxies {
 	use super::*;
 
 	use frame_support::{
+		migrations::{MigrationId, SteppedMigration, SteppedMigrationError},
 		pallet_prelude::{TypeInfo, ValueQuery},
 		storage_alias,
-		traits::{Currency, OnRuntimeUpgrade, ReservableCurrency},
+		traits::{Currency, ReservableCurrency, StorageInfoTrait},
+		weights::WeightMeter,
 		Twox64Concat,
 	};
 	use frame_system::pallet_prelude::BlockNumberFor;
 	use pallet_proxy::ProxyDefinition;
-	use sp_runtime::{BoundedVec, Saturating};
+	use sp_runtime::BoundedVec;
 
 	/// ProxyType including the deprecated `IdentityJudgement`.
 	#[derive(
@@ -1765,8 +2028,92 @@ mod clear_judgement_proxies {
 		ValueQuery,
 	>;
 
+	/// Removes the `IdentityJudgement` proxies of `who` and unreserves the part of the deposit
+	/// which is no longer needed.
+	fn clear_proxies(who: AccountId, (mut proxies, old_deposit): PrevProxiesValue<Runtime>) {
+		let proxies_len_before = proxies.len();
+		proxies.retain(|proxy| proxy.proxy_type != PrevProxyType::IdentityJudgement);
+		if proxies.len() == proxies_len_before {
+			// Nothing to do, keep the old deposit.
+			return;
+		}
+		log::info!(
+			"Removing {} IdentityJudgement proxies for {:?}",
+			proxies_len_before - proxies.len(),
+			&who
+		);
+
+		// Be kind and don't increase the deposit in case it increased (can happen if param
+		// change).
+		let deposit =
+			pallet_proxy::Pallet::<Runtime>::deposit(proxies.len() as u32).min(old_deposit);
+		if deposit < old_deposit {
+			<Balances as ReservableCurrency<AccountId>>::unreserve(&who, old_deposit - deposit);
+		}
+
+		// No need to keep the k/v around if there're no proxies left.
+		if proxies.is_empty() {
+			debug_assert_eq!(deposit, 0);
+			Proxies::<Runtime>::remove(&who);
+		} else {
+			Proxies::<Runtime>::insert(&who, (proxies, deposit));
+		}
+	}
+
+	/// The weight of clearing the proxies of one account, reading and writing its proxies and its
+	/// account, with their maximum proof size.
+	pub fn account_weight() -> Weight {
+		let proof_size = Proxies::<Runtime>::storage_info()
+			.into_iter()
+			.chain(frame_system::Account::<Runtime>::storage_info())
+			.filter_map(|info| info.max_size)
+			.map(u64::from)
+			.sum();
+		<Runtime as frame_system::Config>::DbWeight::get()
+			.reads_writes(2, 2)
+			.saturating_add(Weight::from_parts(0, proof_size))
+	}
+
+	/// Identifier of the [`Migration`].
+	const MIGRATION_ID: &[u8; 23] = b"clear-judgement-proxies";
+
+	/// Multi-block migration removing the `IdentityJudgement` proxies of one account per step.
+	///
+	/// The cursor is the last account whose proxies were cleared.
 	pub struct Migration;
-	impl OnRuntimeUpgrade for Migration {
+	impl SteppedMigration for Migration {
+		type Cursor = AccountId;
+		type Identifier = MigrationId<23>;
+
+		fn id() -> Self::Identifier {
+			MigrationId { pallet_id: *MIGRATION_ID, version_from: 0, version_to: 1 }
+		}
+
+		fn step(
+			mut cursor: Option<Self::Cursor>,
+			meter: &mut WeightMeter,
+		) -> Result<Option<Self::Cursor>, SteppedMigrationError> {
+			let required = account_weight();
+			if meter.remaining().any_lt(required) {
+				return Err(SteppedMigrationError::InsufficientWeight { required });
+			}
+
+			while meter.try_consume(required).is_ok() {
+				let mut iter = match cursor {
+					Some(last) =>
+						Proxies::<Runtime>::iter_from(Proxies::<Runtime>::hashed_key_for(last)),
+					None => Proxies::<Runtime>::iter(),
+				};
+				let Some((who, value)) = iter.next() else {
+					// All accounts are migrated.
+					return Ok(None);
+				};
+				clear_proxies(who.clone(), value);
+				cursor = Some(who);
+			}
+			Ok(cursor)
+		}
+
 		/// Compute the expected post-upgrade state for Proxies storage, and the reserved value
 		/// for all accounts with a proxy.
 		#[cfg(feature = "try-runtime")]
@@ -1806,65 +2153,6 @@ mod clear_judgement_proxies {
 			Ok(pre_upgrade_state.encode())
 		}
 
-		fn on_runtime_upgrade() -> Weight {
-			let mut reads = 0u64;
-			let mut writes = 0u64;
-			let mut proxies_removed_total = 0u64;
-
-			Proxies::<Runtime>::translate(
-				|who: AccountId, (mut proxies, old_deposit): PrevProxiesValue<Runtime>| {
-					// Remove filter out IdentityJudgement proxies.
-					let proxies_len_before = proxies.len() as u64;
-					proxies.retain(|proxy| proxy.proxy_type != PrevProxyType::IdentityJudgement);
-					let proxies_len_after = proxies.len() as u64;
-
-					let deposit = if proxies_len_before > proxies_len_after {
-						log::info!(
-							"Removing {} IdentityJudgement proxies for {:?}",
-							proxies_len_before - proxies_len_after,
-							&who
-						);
-						proxies_removed_total
-							.saturating_accrue(proxies_len_before - proxies_len_after);
-
-						let new_deposit =
-							pallet_proxy::Pallet::<Runtime>::deposit(proxies.len() as u32);
-
-						// Be kind and don't increase the deposit in case it increased (can
-						// happen if param change).
-						let deposit = new_deposit.min(old_deposit);
-						if deposit < old_deposit {
-							writes.saturating_inc();
-							<Balances as ReservableCurrency<AccountId>>::unreserve(
-								&who,
-								old_deposit - deposit,
-							);
-						}
-
-						deposit
-					} else {
-						// Nothing to do, use the old deposit.
-						old_deposit
-					};
-
-					reads.saturating_accrue(proxies_len_before + 1);
-					writes.saturating_accrue(proxies_len_after + 1);
-
-					// No need to keep the k/v around if there're no proxies left.
-					match proxies.is_empty() {
-						true => {
-							debug_assert_eq!(deposit, 0);
-							None
-						},
-						false => Some((proxies, deposit)),
-					}
-				},
-			);
-
-			log::info!("Removed {} IdentityJudgement proxies in total", proxies_removed_total);
-			<Runtime as frame_system::Config>::DbWeight::get().reads_writes(reads, writes)
-		}
-
 		#[cfg(feature = "try-runtime")]
 		fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
 			use frame_support::ensure;
@@ -2005,7 +2293,6 @@ pub mod migrations {
 			IdentityMigratorPalletName,
 			<Runtime as frame_system::Config>::DbWeight,
 		>,
-		clear_judgement_proxies::Migration,
 		// Migrate from legacy lease to coretime. Needs to run after configuration v11
 		coretime_migration::MigrateToCoretime<
 			Runtime,
@@ -2022,7 +2309,58 @@ pub mod migrations {
 	);
 
 	/// Migrations/checks that do not need to be versioned and can run on every update.
-	pub type Permanent = (pallet_xcm::migration::MigrateToLatestXcmVersion<Runtime>,);
+	pub type Permanent =
+		(pallet_xcm::migration::MigrateToLatestXcmVersion<Runtime>, CheckEraPayoutDefaults);
+
+	/// Migrations too heavy for a single block, run by `MultiBlockMigrations` after the upgrade.
+	///
+	/// Completed migrations are recorded by the pallet and not run again, so they can stay here
+	/// across releases.
+	pub type MultiBlock = (clear_judgement_proxies::Migration,);
+
+	/// The era payout of Referendum 1139, before its constants became [`dynamic_params`].
+	#[cfg(any(feature = "try-runtime", test))]
+	pub fn legacy_era_payout(era_duration_millis: u64) -> (Balance, Balance) {
+		const MILLISECONDS_PER_YEAR: u64 = (1000 * 3600 * 24 * 36525) / 100;
+		let relative_era_len =
+			FixedU128::from_rational(era_duration_millis.into(), MILLISECONDS_PER_YEAR.into());
+
+		let fixed_total_issuance: i128 = 15_011_657_390_566_252_333;
+		let fixed_inflation_rate = FixedU128::from_rational(8, 100);
+		let yearly_emission = fixed_inflation_rate.saturating_mul_int(fixed_total_issuance);
+
+		let era_emission = relative_era_len.saturating_mul_int(yearly_emission);
+		let to_treasury = FixedU128::from_rational(15, 100).saturating_mul_int(era_emission);
+		let to_stakers = era_emission.saturating_sub(to_treasury);
+
+		(to_stakers.saturated_into(), to_treasury.saturated_into())
+	}
+
+	/// Checks that the inflation parameters reproduce the era payouts of Referendum 1139 as long
+	/// as governance did not change them.
+	pub struct CheckEraPayoutDefaults;
+	impl OnRuntimeUpgrade for CheckEraPayoutDefaults {
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(_state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+			use pallet_staking::EraPayout as _;
+
+			if pallet_parameters::Parameters::<Runtime>::iter_keys()
+				.any(|key| matches!(key, RuntimeParametersKey::Inflation(_)))
+			{
+				log::info!(target: LOG_TARGET, "Inflation parameters were changed by governance");
+				return Ok(())
+			}
+
+			// A regular 24h era.
+			let era_duration_millis = 24 * (HOURS as Moment) * MILLISECS_PER_BLOCK;
+			frame_support::ensure!(
+				EraPayout::era_payout(0, 0, era_duration_millis) ==
+					legacy_era_payout(era_duration_millis),
+				"Default inflation parameters do not reproduce the era payout"
+			);
+			Ok(())
+		}
+	}
 }
 
 /// Migration to fix current corrupted staking ledgers in Polkadot.
@@ -2216,9 +2554,12 @@ mod benches {
 		[pallet_fast_unstake, FastUnstake]
 		[pallet_indices, Indices]
 		[pallet_message_queue, MessageQueue]
+		[pallet_migrations, MultiBlockMigrations]
 		[pallet_multisig, Multisig]
 		[pallet_nomination_pools, NominationPoolsBench::<Runtime>]
 		[pallet_offences, OffencesBench::<Runtime>]
+		[pallet_para_onboarding, ParaOnboarding]
+		[pallet_parameters, Parameters]
 		[pallet_preimage, Preimage]
 		[pallet_proxy, Proxy]
 		[pallet_scheduler, Scheduler]
@@ -2241,6 +2582,9 @@ mod benches {
 }
 
 impl Runtime {
+	/// The duration of an era used for the estimates, we assume un-delayed 24h eras.
+	const ERA_DURATION: Moment = 24 * (HOURS as Moment) * MILLISECS_PER_BLOCK;
+
 	fn impl_experimental_inflation_info() -> InflationInfo {
 		use pallet_staking::{ActiveEra, EraPayout, ErasTotalStake};
 		let (staked, _start) = ActiveEra::<Runtime>::get()
@@ -2248,8 +2592,7 @@ impl Runtime {
 			.unwrap_or((0, 0));
 		let stake_able_issuance = Balances::total_issuance();
 
-		// We assume un-delayed 24h eras.
-		let era_duration = 24 * (HOURS as Moment) * MILLISECS_PER_BLOCK;
+		let era_duration = Self::ERA_DURATION;
 		let next_mint = <Self as pallet_staking::Config>::EraPayout::era_payout(
 			staked,
 			stake_able_issuance,
@@ -2264,13 +2607,63 @@ impl Runtime {
 
 		InflationInfo { inflation, next_mint }
 	}
+
+	fn impl_experimental_inflation_forecast(
+		staking_rate: Perquintill,
+		eras: u32,
+	) -> Vec<(Balance, Balance)> {
+		let era_duration = Self::ERA_DURATION;
+		relay_common::forecast_era_payouts(
+			Balances::total_issuance(),
+			staking_rate,
+			eras,
+			|total_staked, total_stakable| {
+				EraPayout::payout(total_staked, total_stakable, era_duration)
+			},
+		)
+	}
 }
 
 sp_api::impl_runtime_apis! {
+	#[api_version(2)]
 	impl relay_common::apis::Inflation<Block> for Runtime {
 		fn experimental_inflation_prediction_info() -> InflationInfo {
 			Runtime::impl_experimental_inflation_info()
 		}
+
+		fn experimental_inflation_forecast(
+			staking_rate: Perquintill,
+			eras: u32,
+		) -> Vec<(Balance, Balance)> {
+			Runtime::impl_experimental_inflation_forecast(staking_rate, eras)
+		}
+	}
+
+	impl relay_common::apis::StakingRewards<Block, AccountId> for Runtime {
+		fn estimate_stash_rewards(stash: AccountId) -> Option<RewardEstimate> {
+			relay_common::staking_rewards::estimate_stash_rewards::<Runtime>(
+				&stash,
+				Runtime::ERA_DURATION,
+			)
+		}
+
+		fn estimate_nomination_rewards(
+			targets: Vec<AccountId>,
+			stake: Balance,
+		) -> RewardEstimate {
+			relay_common::staking_rewards::estimate_nomination_rewards::<Runtime>(
+				targets,
+				stake,
+				Runtime::ERA_DURATION,
+			)
+		}
+
+		fn estimate_pool_rewards(pool_id: u32) -> Option<RewardEstimate> {
+			relay_common::staking_rewards::estimate_pool_rewards::<Runtime>(
+				pool_id,
+				Runtime::ERA_DURATION,
+			)
+		}
 	}
 
 	impl sp_api::Core<Block> for Runtime {
@@ -2823,6 +3216,53 @@ sp_api::impl_runtime_apis! {
 		}
 	}
 
+	impl referenda_tracks_api::ReferendaTracksApi<Block, u16, Balance, BlockNumber> for Runtime {
+		fn tracks(
+			pallet_index: u8,
+		) -> Vec<(u16, referenda_tracks_api::TrackDetails<Balance, BlockNumber>)> {
+			match pallet_index {
+				i if i == Referenda::index() as u8 => referenda_tracks_api::tracks::<Runtime, ()>(),
+				_ => Vec::new(),
+			}
+		}
+
+		fn track_thresholds(
+			pallet_index: u8,
+			track: u16,
+			elapsed: BlockNumber,
+		) -> Option<referenda_tracks_api::Thresholds> {
+			match pallet_index {
+				i if i == Referenda::index() as u8 =>
+					referenda_tracks_api::track_thresholds::<Runtime, ()>(track, elapsed),
+				_ => None,
+			}
+		}
+
+		fn referendum_thresholds(
+			pallet_index: u8,
+			index: pallet_referenda::ReferendumIndex,
+		) -> Option<referenda_tracks_api::ReferendumThresholds<u16, BlockNumber>> {
+			match pallet_index {
+				i if i == Referenda::index() as u8 =>
+					referenda_tracks_api::referendum_thresholds::<Runtime, ()>(index),
+				_ => None,
+			}
+		}
+	}
+
+	impl proxy_filter_api::ProxyFilterApi<Block, ProxyType> for Runtime {
+		fn proxy_types() -> Vec<proxy_filter_api::ProxyTypeInfo<ProxyType>> {
+			proxy_filter_api::proxy_types::<Runtime>()
+		}
+
+		fn is_call_allowed(
+			proxy_type: ProxyType,
+			call: Vec<u8>,
+		) -> Result<bool, proxy_filter_api::ProxyFilterError> {
+			proxy_filter_api::is_call_allowed::<Runtime>(&proxy_type, &call)
+		}
+	}
+
 	impl xcm_runtime_apis::fees::XcmPaymentApi<Block> for Runtime {
 		fn query_acceptable_payment_assets(xcm_version: xcm::Version) -> Result<Vec<VersionedAssetId>, XcmPaymentApiError> {
 			let acceptable_assets = vec![AssetId(xcm_config::TokenLocation::get())];
@@ -2865,6 +3305,12 @@ sp_api::impl_runtime_apis! {
 		}
 	}
 
+	impl xcm_dry_run_api::DetailedDryRunApi<Block, RuntimeCall, RuntimeEvent, OriginCaller> for Runtime {
+		fn detailed_dry_run_call(origin: OriginCaller, call: RuntimeCall) -> Result<xcm_dry_run_api::DetailedCallDryRunEffects<RuntimeEvent>, XcmDryRunApiError> {
+			xcm_dry_run_api::detailed_dry_run_call::<Runtime, xcm_config::XcmRouter, OriginCaller, xcm_config::XcmConfig>(origin, call)
+		}
+	}
+
 	impl xcm_runtime_apis::conversions::LocationToAccountApi<Block, AccountId> for Runtime {
 		fn convert_location(location: VersionedLocation) -> Result<
 			AccountId,
@@ -3119,8 +3565,22 @@ sp_api::impl_runtime_apis! {
 				}
 
 				fn unlockable_asset() -> Result<(Location, Location, Asset), BenchmarkError> {
-					// Polkadot doesn't support asset locking
-					Err(BenchmarkError::Skip)
+					use xcm_executor::traits::AssetLock;
+
+					// DOT of an account locked in our favour on Asset Hub. Polkadot doesn't lock
+					// its own assets, so only `note_unlockable` and `request_unlock` are
+					// supported. The lock is noted already, as `request_unlock` expects.
+					let owner: AccountId = frame_benchmarking::account("owner", 0, 0);
+					let amount = ExistentialDeposit::get() * 1000;
+					let _ = <Balances as frame_support::traits::Currency<_>>::make_free_balance_be(
+						&owner,
+						amount * 2,
+					);
+					let owner: Location = AccountId32 { network: None, id: owner.into() }.into();
+					let asset: Asset = (TokenLocation::get(), amount).into();
+					XcmPallet::note_unlockable(AssetHubLocation::get(), asset.clone(), owner.clone())
+						.map_err(|_| BenchmarkError::Stop("the lock of Asset Hub is not trusted"))?;
+					Ok((AssetHubLocation::get(), owner, asset))
 				}
 
 				fn export_message_origin_and_destination(
@@ -3130,8 +3590,12 @@ sp_api::impl_runtime_apis! {
 				}
 
 				fn alias_origin() -> Result<(Location, Location), BenchmarkError> {
-					// The XCM executor of Polkadot doesn't have a configured `Aliasers`
-					Err(BenchmarkError::Skip)
+					// An account of Asset Hub aliasing into the same account here.
+					let account = AccountId32 { network: None, id: [1; 32] };
+					Ok((
+						Location::new(0, [Parachain(system_parachain::ASSET_HUB_ID), account]),
+						Location::new(0, [account]),
+					))
 				}
 			}
 
@@ -3355,6 +3819,507 @@ mod test {
 		// Ensure that the name doesn't include `staging` (from the pallet name)
 		assert_eq!(vec!["xcm", "VersionedXcm"], path.segments);
 	}
+
+	#[test]
+	fn referenda_tracks_api_works() {
+		use referenda_tracks_api::{assert_api_works, track_thresholds, tracks};
+
+		assert_api_works::<Runtime, ()>();
+
+		let (root, root_info) = &tracks::<Runtime, ()>()[0];
+		assert_eq!((*root, root_info.name.as_str()), (0, "root"));
+		assert_eq!(
+			track_thresholds::<Runtime, ()>(0, 0).unwrap().support,
+			Perbill::from_percent(50)
+		);
+		assert_eq!(track_thresholds::<Runtime, ()>(u16::MAX, 0), None);
+	}
+
+	migration_test_utils::impl_migration_tests!(crate, "development");
+
+	#[test]
+	fn clear_judgement_proxies_migrates_one_account_per_step() {
+		use clear_judgement_proxies::{account_weight, Migration, PrevProxyType, Proxies};
+		use frame_support::{
+			migrations::{SteppedMigration, SteppedMigrationError},
+			traits::{Currency, ReservableCurrency},
+			weights::WeightMeter,
+		};
+		use pallet_proxy::ProxyDefinition;
+		use sp_runtime::BoundedVec;
+
+		let proxy = |proxy_type| ProxyDefinition {
+			delegate: AccountId::from([0; 32]),
+			proxy_type,
+			delay: 0u32,
+		};
+		let deposit = |proxies: u32| pallet_proxy::Pallet::<Runtime>::deposit(proxies);
+		let [with_judgement, only_judgement, without_judgement] =
+			[1, 2, 3].map(|i| AccountId::from([i; 32]));
+
+		sp_io::TestExternalities::default().execute_with(|| {
+			for (who, proxies) in [
+				(&with_judgement, vec![PrevProxyType::Any, PrevProxyType::IdentityJudgement]),
+				(&only_judgement, vec![PrevProxyType::IdentityJudgement]),
+				(&without_judgement, vec![PrevProxyType::Staking]),
+			] {
+				let proxy_deposit = deposit(proxies.len() as u32);
+				Balances::make_free_balance_be(who, 100 * UNITS);
+				Balances::reserve(who, proxy_deposit).unwrap();
+				let proxies = proxies.into_iter().map(proxy).collect::<Vec<_>>();
+				Proxies::<Runtime>::insert(
+					who,
+					(BoundedVec::truncate_from(proxies), proxy_deposit),
+				);
+			}
+
+			let one_account = account_weight();
+			assert!(one_account.proof_size() > 0);
+			for limit in [one_account / 2, one_account.set_proof_size(one_account.proof_size() - 1)]
+			{
+				assert!(matches!(
+					Migration::step(None, &mut WeightMeter::with_limit(limit)),
+					Err(SteppedMigrationError::InsufficientWeight { required }) if required == one_account
+				));
+			}
+
+			let mut cursor = None;
+			let mut steps = 0;
+			loop {
+				cursor =
+					Migration::step(cursor, &mut WeightMeter::with_limit(one_account)).unwrap();
+				steps += 1;
+				if cursor.is_none() {
+					break;
+				}
+			}
+			// One step per account and a last one finding no more accounts.
+			assert_eq!(steps, 4);
+
+			assert_eq!(
+				Proxies::<Runtime>::get(&with_judgement),
+				(BoundedVec::truncate_from(vec![proxy(PrevProxyType::Any)]), deposit(1))
+			);
+			assert_eq!(Balances::reserved_balance(&with_judgement), deposit(1));
+			assert!(!Proxies::<Runtime>::contains_key(&only_judgement));
+			assert_eq!(Balances::reserved_balance(&only_judgement), 0);
+			assert_eq!(
+				Proxies::<Runtime>::get(&without_judgement),
+				(BoundedVec::truncate_from(vec![proxy(PrevProxyType::Staking)]), deposit(1))
+			);
+			assert_eq!(Balances::reserved_balance(&without_judgement), deposit(1));
+		});
+	}
+
+	#[test]
+	#[cfg(not(feature = "fast-runtime"))]
+	fn governance_periods_are_not_scaled_without_fast_runtime() {
+		use pallet_referenda::TracksInfo as _;
+
+		let (_, root) = &<Runtime as pallet_referenda::Config>::Tracks::tracks()[0];
+		assert_eq!(root.name, "root");
+		assert_eq!(
+			(root.prepare_period, root.decision_period, root.confirm_period),
+			(2 * HOURS, 28 * DAYS, 24 * HOURS)
+		);
+		assert_eq!(root.min_enactment_period, 24 * HOURS);
+		assert_eq!(<Runtime as pallet_referenda::Config>::UndecidingTimeout::get(), 14 * DAYS);
+		assert_eq!(
+			<Runtime as pallet_conviction_voting::Config>::VoteLockingPeriod::get(),
+			7 * DAYS
+		);
+	}
+
+	#[test]
+	#[cfg(feature = "fast-runtime")]
+	fn governance_periods_are_scaled_with_fast_runtime() {
+		use pallet_referenda::TracksInfo as _;
+
+		let (_, root) = &<Runtime as pallet_referenda::Config>::Tracks::tracks()[0];
+		assert_eq!(
+			(root.prepare_period, root.decision_period, root.confirm_period),
+			(1, 28 * MINUTES, MINUTES)
+		);
+		assert_eq!(root.min_enactment_period, MINUTES);
+		assert_eq!(<Runtime as pallet_referenda::Config>::UndecidingTimeout::get(), 14 * MINUTES);
+		assert_eq!(<Runtime as pallet_conviction_voting::Config>::VoteLockingPeriod::get(), 1);
+		// The shared constants, e.g. of the epochs and coretime timeslices, are not shortened.
+		assert_eq!(
+			(EPOCH_DURATION_IN_SLOTS, system_parachain::coretime::TIMESLICE_PERIOD),
+			(4 * HOURS, 80)
+		);
+	}
+
+	#[test]
+	fn unlockable_notes_are_only_executed_for_free_alone_and_from_asset_hub() {
+		use xcm::latest::{Instruction, Junction::*, Location, Weight as XcmWeight};
+		use xcm_config::{AssetHubLocation, Barrier, TokenLocation};
+		use xcm_executor::traits::{Properties, ShouldExecute};
+
+		let note = Instruction::<RuntimeCall>::NoteUnlockable {
+			asset: (TokenLocation::get(), UNITS).into(),
+			owner: AccountId32 { network: None, id: [1; 32] }.into(),
+		};
+		let should_execute = |origin: Location, mut message: Vec<Instruction<RuntimeCall>>| {
+			Barrier::should_execute(
+				&origin,
+				&mut message,
+				XcmWeight::from_parts(1_000_000, 1_000),
+				&mut Properties { weight_credit: XcmWeight::zero(), message_id: None },
+			)
+		};
+
+		assert_eq!(should_execute(AssetHubLocation::get(), vec![note.clone()]), Ok(()));
+		// Nothing but the note is executed for free.
+		for message in [
+			vec![note.clone(), Instruction::ClearOrigin],
+			vec![Instruction::ClearOrigin, note.clone()],
+			vec![note.clone(), note.clone()],
+			vec![
+				note.clone(),
+				Instruction::Transact {
+					origin_kind: xcm::latest::OriginKind::Superuser,
+					require_weight_at_most: XcmWeight::from_parts(1_000_000, 1_000),
+					call: RuntimeCall::System(frame_system::Call::remark { remark: vec![] })
+						.encode()
+						.into(),
+				},
+			],
+		] {
+			assert!(should_execute(AssetHubLocation::get(), message).is_err());
+		}
+		// Only Asset Hub itself may send it.
+		assert!(should_execute(Location::new(0, [Parachain(2000)]), vec![note.clone()]).is_err());
+		assert!(should_execute(
+			Location::new(0, [Parachain(1000), AccountId32 { network: None, id: [1; 32] }]),
+			vec![note]
+		)
+		.is_err());
+	}
+
+	/// Externalities with the paras 2000 and 2001 onboarded and funded, under a configuration
+	/// admitting channels between them.
+	fn para_channels_ext() -> sp_io::TestExternalities {
+		use frame_support::{
+			storage::{storage_prefix, unhashed},
+			traits::fungible::Mutate,
+			StorageHasher, Twox64Concat,
+		};
+		use parachains_configuration::{ActiveConfig, HostConfiguration};
+		use sp_runtime::traits::AccountIdConversion;
+
+		let mut ext = sp_io::TestExternalities::default();
+		ext.execute_with(|| {
+			System::set_block_number(1);
+			ActiveConfig::<Runtime>::put(HostConfiguration {
+				hrmp_channel_max_capacity: 8,
+				hrmp_channel_max_message_size: 1024,
+				hrmp_max_parachain_outbound_channels: 4,
+				hrmp_max_parachain_inbound_channels: 4,
+				hrmp_sender_deposit: UNITS,
+				hrmp_recipient_deposit: UNITS,
+				..Default::default()
+			});
+			for para in [ParaId::from(2000), ParaId::from(2001)] {
+				// Onboarding takes sessions, so the paras are set as parachains directly.
+				let key = [
+					storage_prefix(b"Paras", b"ParaLifecycles").as_slice(),
+					&Twox64Concat::hash(&para.encode()),
+				]
+				.concat();
+				unhashed::put(&key, &parachains_paras::ParaLifecycle::Parachain);
+				Balances::set_balance(&para.into_account_truncating(), 10 * UNITS);
+			}
+		});
+		ext.commit_all().expect("the setup is committed");
+		ext
+	}
+
+	fn para_origin(para: u32) -> RuntimeOrigin {
+		parachains_origin::Origin::Parachain(para.into()).into()
+	}
+
+	#[test]
+	fn only_asset_hub_operates_the_channels_of_other_paras() {
+		use frame_support::{assert_noop, assert_ok};
+		use parachains_hrmp::HrmpOpenChannelRequests;
+		use polkadot_primitives::HrmpChannelId;
+		use sp_runtime::{traits::AccountIdConversion, DispatchError::BadOrigin};
+
+		let asset_hub = system_parachain::ASSET_HUB_ID;
+		let (sender, recipient) = (ParaId::from(2000), ParaId::from(2001));
+		let channel = HrmpChannelId { sender, recipient };
+		let reserved = |para: ParaId| {
+			let account: AccountId = para.into_account_truncating();
+			Balances::reserved_balance(account)
+		};
+
+		para_channels_ext().execute_with(|| {
+			// Neither other system parachains, the paras themselves nor accounts operate channels.
+			for origin in [
+				para_origin(system_parachain::BRIDGE_HUB_ID),
+				para_origin(2000),
+				para_origin(2001),
+				RuntimeOrigin::signed(AccountId::from([1; 32])),
+				RuntimeOrigin::root(),
+			] {
+				assert_noop!(
+					ParaChannels::init_open_channel(origin.clone(), sender, recipient, 8, 1024),
+					BadOrigin
+				);
+				assert_noop!(
+					ParaChannels::accept_open_channel(origin, recipient, sender),
+					BadOrigin
+				);
+			}
+			// Asset Hub operates its own channels through `Hrmp`.
+			assert_noop!(
+				ParaChannels::init_open_channel(
+					para_origin(asset_hub),
+					asset_hub.into(),
+					recipient,
+					8,
+					1024
+				),
+				BadOrigin
+			);
+			assert_noop!(
+				ParaChannels::accept_open_channel(para_origin(asset_hub), asset_hub.into(), sender),
+				BadOrigin
+			);
+
+			assert_ok!(ParaChannels::init_open_channel(
+				para_origin(asset_hub),
+				sender,
+				recipient,
+				8,
+				1024
+			));
+			assert!(
+				!HrmpOpenChannelRequests::<Runtime>::get(channel.clone())
+					.expect("the channel is requested")
+					.confirmed
+			);
+			assert_eq!((reserved(sender), reserved(recipient)), (UNITS, 0));
+
+			assert_ok!(ParaChannels::accept_open_channel(
+				para_origin(asset_hub),
+				recipient,
+				sender
+			));
+			assert!(
+				HrmpOpenChannelRequests::<Runtime>::get(channel)
+					.expect("the channel is requested")
+					.confirmed
+			);
+			assert_eq!((reserved(sender), reserved(recipient)), (UNITS, UNITS));
+		});
+	}
+
+	#[test]
+	fn para_channels_weigh_as_much_as_the_hrmp_calls() {
+		use frame_support::{assert_ok, dispatch::GetDispatchInfo};
+
+		let asset_hub = system_parachain::ASSET_HUB_ID;
+		let (sender, recipient) = (ParaId::from(2000), ParaId::from(2001));
+		let requested = || {
+			let mut ext = para_channels_ext();
+			ext.execute_with(|| {
+				assert_ok!(Hrmp::hrmp_init_open_channel(para_origin(2000), recipient, 8, 1024));
+			});
+			ext.commit_all().expect("the request is committed");
+			ext
+		};
+
+		// The calls reuse the weights of the `Hrmp` calls they make on behalf of the paras, ...
+		assert_eq!(
+			RuntimeCall::ParaChannels(pallet_para_channels::Call::init_open_channel {
+				para: sender,
+				recipient,
+				proposed_max_capacity: 8,
+				proposed_max_message_size: 1024,
+			})
+			.get_dispatch_info()
+			.weight,
+			RuntimeCall::Hrmp(parachains_hrmp::Call::hrmp_init_open_channel {
+				recipient,
+				proposed_max_capacity: 8,
+				proposed_max_message_size: 1024,
+			})
+			.get_dispatch_info()
+			.weight,
+		);
+		assert_eq!(
+			RuntimeCall::ParaChannels(pallet_para_channels::Call::accept_open_channel {
+				para: recipient,
+				sender,
+			})
+			.get_dispatch_info()
+			.weight,
+			RuntimeCall::Hrmp(parachains_hrmp::Call::hrmp_accept_open_channel { sender })
+				.get_dispatch_info()
+				.weight,
+		);
+
+		// ... as checking the operator reads no storage on top of them.
+		let (_, operated) = para_channels_ext().execute_and_prove(|| {
+			assert_ok!(ParaChannels::init_open_channel(
+				para_origin(asset_hub),
+				sender,
+				recipient,
+				8,
+				1024
+			));
+		});
+		let (_, direct) = para_channels_ext().execute_and_prove(|| {
+			assert_ok!(Hrmp::hrmp_init_open_channel(para_origin(2000), recipient, 8, 1024));
+		});
+		assert_eq!(operated, direct);
+
+		let (_, operated) = requested().execute_and_prove(|| {
+			assert_ok!(ParaChannels::accept_open_channel(
+				para_origin(asset_hub),
+				recipient,
+				sender
+			));
+		});
+		let (_, direct) = requested().execute_and_prove(|| {
+			assert_ok!(Hrmp::hrmp_accept_open_channel(para_origin(2001), sender));
+		});
+		assert_eq!(operated, direct);
+	}
+
+	#[test]
+	fn system_parachains_may_alias_into_their_accounts_and_children() {
+		use frame_support::traits::ContainsPair;
+		use xcm::latest::{Junction::*, Location};
+		use xcm_config::TrustedAliasers;
+
+		let account = AccountId32 { network: None, id: [1; 32] };
+		let aliases = |origin: Location, target: Location| {
+			<TrustedAliasers as ContainsPair<Location, Location>>::contains(&origin, &target)
+		};
+
+		// An account of a system parachain aliases into the same account here.
+		assert!(aliases(Location::new(0, [Parachain(1000), account]), Location::new(0, [account])));
+		// But not into another one, nor from a non-system parachain.
+		assert!(!aliases(
+			Location::new(0, [Parachain(1000), account]),
+			Location::new(0, [AccountId32 { network: None, id: [2; 32] }])
+		));
+		assert!(!aliases(
+			Location::new(0, [Parachain(2000), account]),
+			Location::new(0, [account])
+		));
+		// Any location aliases into its own children, but not into its parent or siblings.
+		assert!(aliases(
+			Location::new(0, [Parachain(2000)]),
+			Location::new(0, [Parachain(2000), account])
+		));
+		assert!(!aliases(
+			Location::new(0, [Parachain(2000), account]),
+			Location::new(0, [Parachain(2000)])
+		));
+		assert!(!aliases(
+			Location::new(0, [Parachain(2000)]),
+			Location::new(0, [Parachain(2001), account])
+		));
+	}
+
+	#[test]
+	fn staking_reward_estimates_follow_exposures() {
+		use pallet_staking::{
+			ActiveEra, ActiveEraInfo, Bonded, EraPayout as _, ErasStakersOverview,
+			ErasStakersPaged, ErasTotalStake, ErasValidatorPrefs, Ledger, Nominations, Nominators,
+			StakingLedger, ValidatorPrefs,
+		};
+		use relay_common::staking_rewards::*;
+		use sp_staking::{ExposurePage, IndividualExposure, PagedExposureMetadata};
+
+		let (validator, other, nominator) =
+			(AccountId::from([1; 32]), AccountId::from([2; 32]), AccountId::from([3; 32]));
+		sp_io::TestExternalities::default().execute_with(|| {
+			pallet_balances::TotalIssuance::<Runtime>::put(1_600_000_000 * UNITS);
+			ActiveEra::<Runtime>::put(ActiveEraInfo { index: 1, start: None });
+			ErasTotalStake::<Runtime>::insert(1, 800_000_000 * UNITS);
+			let overview = |total, own, nominator_count| PagedExposureMetadata {
+				total,
+				own,
+				nominator_count,
+				page_count: nominator_count,
+			};
+			ErasStakersOverview::<Runtime>::insert(
+				1,
+				&validator,
+				overview(400_000_000 * UNITS, 100_000_000 * UNITS, 1),
+			);
+			ErasStakersPaged::<Runtime>::insert(
+				(1, &validator, 0),
+				ExposurePage {
+					page_total: 300_000_000 * UNITS,
+					others: vec![IndividualExposure {
+						who: nominator.clone(),
+						value: 300_000_000 * UNITS,
+					}],
+				},
+			);
+			ErasValidatorPrefs::<Runtime>::insert(
+				1,
+				&validator,
+				ValidatorPrefs { commission: Perbill::from_percent(10), blocked: false },
+			);
+			ErasStakersOverview::<Runtime>::insert(
+				1,
+				&other,
+				overview(400_000_000 * UNITS, 400_000_000 * UNITS, 0),
+			);
+			Bonded::<Runtime>::insert(&nominator, &nominator);
+			Ledger::<Runtime>::insert(
+				&nominator,
+				StakingLedger::<Runtime>::new(nominator.clone(), 300_000_000 * UNITS),
+			);
+			Nominators::<Runtime>::insert(
+				&nominator,
+				Nominations {
+					targets: vec![validator.clone()].try_into().unwrap(),
+					submitted_in: 0,
+					suppressed: false,
+				},
+			);
+
+			let (staking_payout, _rest) = EraPayout::era_payout(
+				800_000_000 * UNITS,
+				1_600_000_000 * UNITS,
+				Runtime::ERA_DURATION,
+			);
+			// Both validators are paid the same, the first one takes 10% of it as commission.
+			let validator_payout = staking_payout / 2;
+			let leftover = validator_payout - Perbill::from_percent(10) * validator_payout;
+
+			let stash = estimate_stash_rewards::<Runtime>(&nominator, Runtime::ERA_DURATION);
+			let stash = stash.unwrap();
+			assert_eq!(stash.stake, 300_000_000 * UNITS);
+			assert_eq!(stash.era_reward, Perbill::from_rational(3u32, 4) * leftover);
+			assert!(stash.annual_yield > Perquintill::zero());
+
+			// The stake is split between the active targets, the duplicate and inactive ones are
+			// ignored.
+			let targets = vec![other.clone(), other, AccountId::from([4; 32])];
+			let nomination = estimate_nomination_rewards::<Runtime>(
+				targets,
+				400_000_000 * UNITS,
+				Runtime::ERA_DURATION,
+			);
+			assert_eq!(nomination.era_reward, validator_payout / 2);
+			assert_eq!(
+				estimate_nomination_rewards::<Runtime>(vec![], 1, Runtime::ERA_DURATION).era_reward,
+				0
+			);
+
+			assert_eq!(estimate_stash_rewards::<Runtime>(&validator, Runtime::ERA_DURATION), None);
+			assert_eq!(estimate_pool_rewards::<Runtime>(1, Runtime::ERA_DURATION), None);
+		});
+	}
 }
 
 #[cfg(test)]
@@ -3399,9 +4364,111 @@ mod multiplier_tests {
 	use approx::assert_relative_eq;
 	const MILLISECONDS_PER_DAY: u64 = 24 * 60 * 60 * 1000;
 
+	/// The era payout with the inflation parameters of a fresh chain, i.e. the defaults.
+	fn era_payout(
+		total_staked: Balance,
+		total_issuance: Balance,
+		era_duration_millis: u64,
+	) -> (Balance, Balance) {
+		sp_io::TestExternalities::default().execute_with(|| {
+			super::EraPayout::era_payout(total_staked, total_issuance, era_duration_millis)
+		})
+	}
+
+	#[test]
+	fn era_payout_defaults_match_legacy_payout() {
+		for era_duration_millis in
+			[1, MILLISECONDS_PER_DAY / 4, MILLISECONDS_PER_DAY, (36525 * MILLISECONDS_PER_DAY) / 10]
+		{
+			assert_eq!(
+				era_payout(0, 0, era_duration_millis),
+				migrations::legacy_era_payout(era_duration_millis)
+			);
+		}
+	}
+
+	#[test]
+	fn era_payout_follows_inflation_parameters() {
+		sp_io::TestExternalities::default().execute_with(|| {
+			let set = |parameter| {
+				pallet_parameters::Pallet::<Runtime>::set_parameter(
+					RuntimeOrigin::root(),
+					RuntimeParameters::Inflation(parameter),
+				)
+				.unwrap();
+			};
+			set(dynamic_params::inflation::Parameters::AnnualInflation(
+				dynamic_params::inflation::AnnualInflation,
+				Some(Perquintill::from_percent(4)),
+			));
+			set(dynamic_params::inflation::Parameters::TreasuryShare(
+				dynamic_params::inflation::TreasuryShare,
+				Some(Perquintill::zero()),
+			));
+
+			// Half of the 120M DOT emitted with the default inflation, all to the stakers.
+			assert_eq!(
+				super::EraPayout::era_payout(0, 0, (36525 * MILLISECONDS_PER_DAY) / 100),
+				(600_466_295_622_650_093, 0)
+			);
+		});
+	}
+
+	#[test]
+	fn only_root_and_parameter_admin_set_parameters() {
+		use frame_support::traits::EnsureOriginWithArg;
+
+		let keys = [
+			RuntimeParametersKey::Inflation(
+				dynamic_params::inflation::ParametersKey::AnnualInflation(
+					dynamic_params::inflation::AnnualInflation,
+				),
+			),
+			RuntimeParametersKey::Treasury(dynamic_params::treasury::ParametersKey::BurnPortion(
+				dynamic_params::treasury::BurnPortion,
+			)),
+		];
+		let origin = |origin: pallet_custom_origins::Origin| -> RuntimeOrigin { origin.into() };
+
+		for key in keys {
+			assert!(DynamicParameterOrigin::try_origin(RuntimeOrigin::root(), &key).is_ok());
+			assert!(DynamicParameterOrigin::try_origin(
+				origin(pallet_custom_origins::Origin::ParameterAdmin),
+				&key
+			)
+			.is_ok());
+			assert!(DynamicParameterOrigin::try_origin(
+				origin(pallet_custom_origins::Origin::GeneralAdmin),
+				&key
+			)
+			.is_err());
+			assert!(DynamicParameterOrigin::try_origin(
+				origin(pallet_custom_origins::Origin::StakingAdmin),
+				&key
+			)
+			.is_err());
+		}
+	}
+
+	#[test]
+	fn era_payout_golden_values() {
+		// About 120M DOT per year, 15% of it to the treasury, independent of the stake.
+		let yearly = (1_020_792_702_558_505_159, 180_139_888_686_795_027);
+		assert_eq!(era_payout(0, 0, (36525 * MILLISECONDS_PER_DAY) / 100), yearly);
+		assert_eq!(era_payout(123, 456, (36525 * MILLISECONDS_PER_DAY) / 100), yearly);
+		assert_eq!(
+			era_payout(0, 0, (36525 * MILLISECONDS_PER_DAY) / 50),
+			(2_041_585_405_117_010_317, 360_279_777_373_590_055)
+		);
+		assert_eq!(
+			era_payout(0, 0, MILLISECONDS_PER_DAY),
+			(2_794_778_104_198_508, 493_196_136_035_030)
+		);
+	}
+
 	#[test]
 	fn staking_inflation_correct_single_era() {
-		let (to_stakers, to_treasury) = super::EraPayout::era_payout(
+		let (to_stakers, to_treasury) = era_payout(
 			123, // ignored
 			456, // ignored
 			MILLISECONDS_PER_DAY,
@@ -3421,7 +4488,7 @@ mod multiplier_tests {
 	#[test]
 	fn staking_inflation_correct_longer_era() {
 		// Twice the era duration means twice the emission:
-		let (to_stakers, to_treasury) = super::EraPayout::era_payout(
+		let (to_stakers, to_treasury) = era_payout(
 			123, // ignored
 			456, // ignored
 			2 * MILLISECONDS_PER_DAY,
@@ -3441,7 +4508,7 @@ mod multiplier_tests {
 
 	#[test]
 	fn staking_inflation_correct_whole_year() {
-		let (to_stakers, to_treasury) = super::EraPayout::era_payout(
+		let (to_stakers, to_treasury) = era_payout(
 			123,                                  // ignored
 			456,                                  // ignored
 			(36525 * MILLISECONDS_PER_DAY) / 100, // 1 year
@@ -3466,7 +4533,7 @@ mod multiplier_tests {
 	// 10 years into the future, our values do not overflow.
 	#[test]
 	fn staking_inflation_correct_not_overflow() {
-		let (to_stakers, to_treasury) = super::EraPayout::era_payout(
+		let (to_stakers, to_treasury) = era_payout(
 			123,                                 // ignored
 			456,                                 // ignored
 			(36525 * MILLISECONDS_PER_DAY) / 10, // 10 years
@@ -3485,7 +4552,7 @@ mod multiplier_tests {
 	// Print percent per year, just as convenience.
 	#[test]
 	fn staking_inflation_correct_print_percent() {
-		let (to_stakers, to_treasury) = super::EraPayout::era_payout(
+		let (to_stakers, to_treasury) = era_payout(
 			123,                                  // ignored
 			456,                                  // ignored
 			(36525 * MILLISECONDS_PER_DAY) / 100, // 1 year
@@ -3503,6 +4570,20 @@ mod multiplier_tests {
 		}
 	}
 
+	#[test]
+	fn inflation_forecast_is_flat() {
+		sp_io::TestExternalities::default().execute_with(|| {
+			pallet_balances::TotalIssuance::<Runtime>::put(1_600_000_000 * UNITS);
+			let forecast =
+				Runtime::impl_experimental_inflation_forecast(Perquintill::from_percent(50), 365);
+
+			// The emission is calculated from a fixed issuance, so it doesn't compound.
+			assert_eq!(forecast.len(), 365);
+			assert!(forecast.iter().all(|payout| *payout == forecast[0]));
+			assert_eq!(forecast[0], super::EraPayout::era_payout(0, 0, MILLISECONDS_PER_DAY));
+		});
+	}
+
 	#[test]
 	fn fast_unstake_estimate() {
 		use pallet_fast_unstake::WeightInfo;
@@ -3521,6 +4602,38 @@ mod multiplier_tests {
 		assert_eq!(TREASURY_PALLET_ID, <Treasury as PalletInfoAccess>::index() as u8);
 	}
 
+	#[test]
+	fn on_demand_pallet_index_is_correct() {
+		assert_eq!(
+			polkadot_runtime_constants::ON_DEMAND_PALLET_ID,
+			<OnDemand as PalletInfoAccess>::index() as u8
+		);
+	}
+
+	#[test]
+	fn para_channels_pallet_index_is_correct() {
+		assert_eq!(
+			polkadot_runtime_constants::PARA_CHANNELS_PALLET_ID,
+			<ParaChannels as PalletInfoAccess>::index() as u8
+		);
+	}
+
+	#[test]
+	fn registrar_pallet_index_is_correct() {
+		assert_eq!(
+			polkadot_runtime_constants::REGISTRAR_PALLET_ID,
+			<Registrar as PalletInfoAccess>::index() as u8
+		);
+	}
+
+	#[test]
+	fn para_onboarding_pallet_index_is_correct() {
+		assert_eq!(
+			polkadot_runtime_constants::PARA_ONBOARDING_PALLET_ID,
+			<ParaOnboarding as PalletInfoAccess>::index() as u8
+		);
+	}
+
 	#[test]
 	#[ignore]
 	fn multiplier_growth_simulator() {
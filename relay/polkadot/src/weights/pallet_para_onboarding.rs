@@ -0,0 +1,38 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Weights for `pallet_para_onboarding`.
+//!
+//! `register` reserves a para id and registers the para with the `reserve` and `register` of
+//! `paras_registrar`, after reading the preimage of the validation code and its request status.
+//! Until the benchmarks of `pallet_para_onboarding` are run for this runtime and replace this
+//! file, it is weighed as such.
+
+use core::marker::PhantomData;
+use frame_support::{traits::Get, weights::Weight};
+use polkadot_runtime_common::paras_registrar::WeightInfo as _;
+
+type RegistrarWeight<T> = super::polkadot_runtime_common_paras_registrar::WeightInfo<T>;
+
+/// Weight functions for `pallet_para_onboarding`.
+pub struct WeightInfo<T>(PhantomData<T>);
+impl<T: frame_system::Config> pallet_para_onboarding::WeightInfo for WeightInfo<T> {
+	fn register(c: u32) -> Weight {
+		RegistrarWeight::<T>::reserve()
+			.saturating_add(RegistrarWeight::<T>::register())
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(Weight::from_parts(0, c.into()))
+	}
+}
@@ -22,8 +22,8 @@ use super::{
 	TransactionByteFee, Treasurer, Treasury, WeightToFee, XcmPallet,
 };
 use frame_support::{
-	parameter_types,
-	traits::{Contains, Equals, Everything, Nothing},
+	ensure, parameter_types,
+	traits::{Contains, ContainsPair, Equals, Everything, Nothing, ProcessMessageError},
 };
 use frame_system::EnsureRoot;
 use pallet_xcm::XcmPassthrough;
@@ -34,17 +34,21 @@ use polkadot_runtime_common::{
 use polkadot_runtime_constants::{
 	currency::CENTS, system_parachain::*, xcm::body::FELLOWSHIP_ADMIN_INDEX,
 };
+use relay_common::AliasChildLocation;
 use sp_core::ConstU32;
+use sp_std::marker::PhantomData;
 use xcm::latest::prelude::*;
 use xcm_builder::{
-	AccountId32Aliases, AllowExplicitUnpaidExecutionFrom, AllowKnownQueryResponses,
-	AllowSubscriptionsFrom, AllowTopLevelPaidExecutionFrom, ChildParachainAsNative,
-	ChildParachainConvertsVia, DescribeAllTerminal, DescribeFamily, FrameTransactionalProcessor,
-	FungibleAdapter, HashedDescription, IsChildSystemParachain, IsConcrete, MintLocation,
-	OriginToPluralityVoice, SendXcmFeeToAccount, SignedAccountId32AsNative, SignedToAccountId32,
-	SovereignSignedViaLocation, TakeWeightCredit, TrailingSetTopicAsId, UsingComponents,
-	WeightInfoBounds, WithComputedOrigin, WithUniqueTopic, XcmFeeManagerFromComponents,
+	AccountId32Aliases, AliasForeignAccountId32, AllowExplicitUnpaidExecutionFrom,
+	AllowKnownQueryResponses, AllowSubscriptionsFrom, AllowTopLevelPaidExecutionFrom,
+	ChildParachainAsNative, ChildParachainConvertsVia, DescribeAllTerminal, DescribeFamily,
+	FrameTransactionalProcessor, FungibleAdapter, HashedDescription, IsChildSystemParachain,
+	IsConcrete, MintLocation, OriginToPluralityVoice, SendXcmFeeToAccount,
+	SignedAccountId32AsNative, SignedToAccountId32, SovereignSignedViaLocation, TakeWeightCredit,
+	TrailingSetTopicAsId, UsingComponents, WeightInfoBounds, WithComputedOrigin, WithUniqueTopic,
+	XcmFeeManagerFromComponents,
 };
+use xcm_executor::traits::{Properties, ShouldExecute};
 
 parameter_types! {
 	pub const RootLocation: Location = Here.into_location();
@@ -179,12 +183,43 @@ impl Contains<Location> for LocalPlurality {
 	}
 }
 
+/// Asset Hub may lock DOT in favour of this chain, which only keeps track of the locks until
+/// their owners request to release them.
+pub struct AssetHubLocksDot;
+impl ContainsPair<Location, Asset> for AssetHubLocksDot {
+	fn contains(locker: &Location, asset: &Asset) -> bool {
+		locker == &AssetHubLocation::get() && asset.id == AssetId(TokenLocation::get())
+	}
+}
+
+/// Allows the lone `NoteUnlockable` instruction which `pallet-xcm` sends to notify us of an asset
+/// locked in our favour, without any fee payment, from the given trusted lockers.
+///
+/// Whether the lock itself is trusted is still checked by `TrustedLockers` when executing it.
+pub struct AllowUnlockableNotesFrom<T>(PhantomData<T>);
+impl<T: Contains<Location>> ShouldExecute for AllowUnlockableNotesFrom<T> {
+	fn should_execute<RuntimeCall>(
+		origin: &Location,
+		instructions: &mut [Instruction<RuntimeCall>],
+		_max_weight: Weight,
+		_properties: &mut Properties,
+	) -> Result<(), ProcessMessageError> {
+		ensure!(T::contains(origin), ProcessMessageError::Unsupported);
+		match instructions {
+			[NoteUnlockable { .. }] => Ok(()),
+			_ => Err(ProcessMessageError::BadFormat),
+		}
+	}
+}
+
 /// The barriers one of which must be passed for an XCM message to be executed.
 pub type Barrier = TrailingSetTopicAsId<(
 	// Weight that is paid for may be consumed.
 	TakeWeightCredit,
 	// Expected responses are OK.
 	AllowKnownQueryResponses<XcmPallet>,
+	// Notifications of assets locked in our favour on Asset Hub are OK.
+	AllowUnlockableNotesFrom<Equals<AssetHubLocation>>,
 	WithComputedOrigin<
 		(
 			// If the message is one that immediately attempts to pay for execution, then allow it.
@@ -203,11 +238,17 @@ pub type Barrier = TrailingSetTopicAsId<(
 /// We only waive fees for system functions, which these locations represent.
 pub type WaivedLocations = (SystemParachains, Equals<RootLocation>, LocalPlurality);
 
+/// Any location may alias into its own child locations. Accounts of system parachains may alias
+/// into the same account on the relay chain, e.g. to act on the relay chain with funds held on
+/// Asset Hub.
+pub type TrustedAliasers =
+	(AliasChildLocation, AliasForeignAccountId32<IsChildSystemParachain<ParaId>>);
+
 pub struct XcmConfig;
 impl xcm_executor::Config for XcmConfig {
 	type RuntimeCall = RuntimeCall;
 	type XcmSender = XcmRouter;
-	type XcmRecorder = ();
+	type XcmRecorder = XcmPallet;
 	type AssetTransactor = LocalAssetTransactor;
 	type OriginConverter = LocalOriginConverter;
 	// Polkadot Relay recognises no chains which act as reserves.
@@ -225,7 +266,7 @@ impl xcm_executor::Config for XcmConfig {
 		UsingComponents<WeightToFee, TokenLocation, AccountId, Balances, ToAuthor<Runtime>>;
 	type ResponseHandler = XcmPallet;
 	type AssetTrap = XcmPallet;
-	type AssetLocker = ();
+	type AssetLocker = XcmPallet;
 	type AssetExchanger = ();
 	type AssetClaims = XcmPallet;
 	type SubscriptionService = XcmPallet;
@@ -240,7 +281,7 @@ impl xcm_executor::Config for XcmConfig {
 	type UniversalAliases = Nothing;
 	type CallDispatcher = RuntimeCall;
 	type SafeCallFilter = Everything;
-	type Aliasers = Nothing;
+	type Aliasers = TrustedAliasers;
 	type TransactionalProcessor = FrameTransactionalProcessor;
 	type HrmpNewChannelOpenRequestHandler = ();
 	type HrmpChannelAcceptedHandler = ();
@@ -320,10 +361,18 @@ impl pallet_xcm::Config for Runtime {
 	const VERSION_DISCOVERY_QUEUE_SIZE: u32 = 100;
 	type AdvertisedXcmVersion = pallet_xcm::CurrentXcmVersion;
 	type Currency = Balances;
+	// Only Asset Hub may lock DOT in our favour, we don't lock anything ourselves. The
+	// benchmarks of the lock instructions lock some DOT here first, so they do.
+	#[cfg(not(feature = "runtime-benchmarks"))]
 	type CurrencyMatcher = ();
-	type TrustedLockers = ();
+	#[cfg(feature = "runtime-benchmarks")]
+	type CurrencyMatcher = IsConcrete<TokenLocation>;
+	type TrustedLockers = AssetHubLocksDot;
 	type SovereignAccountOf = SovereignAccountOf;
 	type MaxLockers = ConstU32<8>;
+	// The locks noted in our favour are only tracked, `pallet-xcm` has no interface for other
+	// pallets to consume them. So they can't back e.g. conviction voting or staking yet, and their
+	// owner may request to unlock them at any time.
 	type MaxRemoteLockConsumers = ConstU32<0>;
 	type RemoteLockConsumerIdentifier = ();
 	type WeightInfo = crate::weights::pallet_xcm::WeightInfo<Runtime>;
@@ -25,11 +25,13 @@ use assets_common::{
 	TrustBackedAssetsAsLocation,
 };
 use frame_support::{
+	ensure,
 	pallet_prelude::Get,
 	parameter_types,
 	traits::{
 		tokens::imbalance::{ResolveAssetTo, ResolveTo},
-		ConstU32, Contains, ContainsPair, Equals, Everything, Nothing, PalletInfoAccess,
+		ConstU32, Contains, ContainsPair, Equals, Everything, PalletInfoAccess,
+		ProcessMessageError,
 	},
 };
 use frame_system::EnsureRoot;
@@ -41,23 +43,28 @@ use parachains_common::xcm_config::{
 use polkadot_parachain_primitives::primitives::Sibling;
 use snowbridge_router_primitives::inbound::GlobalConsensusEthereumConvertsFor;
 use sp_runtime::traits::{AccountIdConversion, ConvertInto};
-use system_parachains_constants::TREASURY_PALLET_ID;
+use system_parachains_constants::{AliasChildLocation, TREASURY_PALLET_ID};
 use xcm::latest::prelude::*;
 use xcm_builder::{
-	AccountId32Aliases, AllowExplicitUnpaidExecutionFrom, AllowKnownQueryResponses,
-	AllowSubscriptionsFrom, AllowTopLevelPaidExecutionFrom, DenyReserveTransferToRelayChain,
-	DenyThenTry, DescribeAllTerminal, DescribeFamily, EnsureXcmOrigin, FrameTransactionalProcessor,
-	FungibleAdapter, FungiblesAdapter, GlobalConsensusParachainConvertsFor, HashedDescription,
-	IsConcrete, LocalMint, NoChecking, ParentAsSuperuser, ParentIsPreset, RelayChainAsNative,
-	SendXcmFeeToAccount, SiblingParachainAsNative, SiblingParachainConvertsVia,
-	SignedAccountId32AsNative, SignedToAccountId32, SovereignSignedViaLocation, StartsWith,
-	StartsWithExplicitGlobalConsensus, TakeWeightCredit, TrailingSetTopicAsId, UsingComponents,
-	WeightInfoBounds, WithComputedOrigin, WithUniqueTopic, XcmFeeManagerFromComponents,
+	AccountId32Aliases, AliasForeignAccountId32, AllowExplicitUnpaidExecutionFrom,
+	AllowKnownQueryResponses, AllowSubscriptionsFrom, AllowTopLevelPaidExecutionFrom,
+	DenyReserveTransferToRelayChain, DenyThenTry, DescribeAllTerminal, DescribeFamily,
+	EnsureXcmOrigin, FrameTransactionalProcessor, FungibleAdapter, FungiblesAdapter,
+	GlobalConsensusParachainConvertsFor, HashedDescription, IsConcrete, LocalMint, NoChecking,
+	ParentAsSuperuser, ParentIsPreset, RelayChainAsNative, SendXcmFeeToAccount,
+	SiblingParachainAsNative, SiblingParachainConvertsVia, SignedAccountId32AsNative,
+	SignedToAccountId32, SovereignSignedViaLocation, StartsWith, StartsWithExplicitGlobalConsensus,
+	TakeWeightCredit, TrailingSetTopicAsId, UsingComponents, WeightInfoBounds, WithComputedOrigin,
+	WithUniqueTopic, XcmFeeManagerFromComponents,
+};
+use xcm_executor::{
+	traits::{ConvertLocation, Properties, ShouldExecute},
+	XcmExecutor,
 };
-use xcm_executor::{traits::ConvertLocation, XcmExecutor};
 
 parameter_types! {
 	pub const KsmLocation: Location = Location::parent();
+	pub const RelayChainLocation: Location = Location::parent();
 	pub const RelayNetwork: Option<NetworkId> = Some(NetworkId::Kusama);
 	pub RelayChainOrigin: RuntimeOrigin = cumulus_pallet_xcm::Origin::Relay.into();
 	pub UniversalLocation: InteriorLocation =
@@ -75,6 +82,13 @@ parameter_types! {
 	pub const GovernanceLocation: Location = Location::parent();
 	pub const FellowshipLocation: Location = Location::parent();
 	pub RelayTreasuryLocation: Location = (Parent, PalletInstance(kusama_runtime_constants::TREASURY_PALLET_ID)).into();
+	/// The NIS counterpart token of the Relay Chain, which is its reserve.
+	pub NisCounterpartLocation: Location =
+		(Parent, PalletInstance(kusama_runtime_constants::NIS_COUNTERPART_PALLET_ID)).into();
+	pub NisCounterpartFromRelay: (AssetFilter, Location) = (
+		Wild(AllOf { fun: WildFungible, id: AssetId(NisCounterpartLocation::get()) }),
+		RelayChainLocation::get(),
+	);
 	pub TreasuryAccount: AccountId = TREASURY_PALLET_ID.into_account_truncating();
 	pub StakingPot: AccountId = CollatorSelection::account_id();
 	// Test [`crate::tests::treasury_pallet_account_not_none`] ensures that the result of location
@@ -235,6 +249,26 @@ impl Contains<Location> for ParentOrParentsPlurality {
 	}
 }
 
+/// Allows the lone `UnlockAsset` instruction which `pallet-xcm` sends to release an asset locked
+/// in favour of a remote chain, without any fee payment, from the given unlockers.
+///
+/// Whether the origin may unlock the asset is still checked against the lock when executing it.
+pub struct AllowUnlocksFrom<T>(sp_std::marker::PhantomData<T>);
+impl<T: Contains<Location>> ShouldExecute for AllowUnlocksFrom<T> {
+	fn should_execute<RuntimeCall>(
+		origin: &Location,
+		instructions: &mut [Instruction<RuntimeCall>],
+		_max_weight: Weight,
+		_properties: &mut Properties,
+	) -> Result<(), ProcessMessageError> {
+		ensure!(T::contains(origin), ProcessMessageError::Unsupported);
+		match instructions {
+			[UnlockAsset { .. }] => Ok(()),
+			_ => Err(ProcessMessageError::BadFormat),
+		}
+	}
+}
+
 pub type Barrier = TrailingSetTopicAsId<
 	DenyThenTry<
 		DenyReserveTransferToRelayChain,
@@ -242,6 +276,8 @@ pub type Barrier = TrailingSetTopicAsId<
 			TakeWeightCredit,
 			// Expected responses are OK.
 			AllowKnownQueryResponses<PolkadotXcm>,
+			// The relay chain may release the KSM locked in its favour.
+			AllowUnlocksFrom<Equals<RelayChainLocation>>,
 			// Allow XCMs with some computed origins to pass through.
 			WithComputedOrigin<
 				(
@@ -298,18 +334,29 @@ pub type ForeignAssetFeeAsExistentialDepositMultiplierFeeCharger =
 		ForeignAssetsInstance,
 	>;
 
+/// Any location may alias into its own child locations. Accounts of the relay chain and of
+/// sibling system parachains may alias into the same account on this chain.
+pub type TrustedAliasers = (
+	AliasChildLocation,
+	AliasForeignAccountId32<(Equals<RelayChainLocation>, AllSiblingSystemParachains)>,
+);
+
 pub struct XcmConfig;
 impl xcm_executor::Config for XcmConfig {
 	type RuntimeCall = RuntimeCall;
 	type XcmSender = XcmRouter;
-	type XcmRecorder = ();
+	type XcmRecorder = PolkadotXcm;
 	type AssetTransactor = AssetTransactors;
 	type OriginConverter = XcmOriginToTransactDispatchOrigin;
 	// Asset Hub trusts only particular, pre-configured bridged locations from a different consensus
 	// as reserve locations (we trust the Bridge Hub to relay the message that a reserve is being
 	// held). On Kusama Asset Hub, we allow Polkadot Asset Hub to act as reserve for any asset
-	// native to the Polkadot or Ethereum ecosystems.
-	type IsReserve = (bridging::to_polkadot::PolkadotOrEthereumAssetFromAssetHubPolkadot,);
+	// native to the Polkadot or Ethereum ecosystems. The Relay Chain is the reserve of its NIS
+	// counterpart token, which is held here as a foreign asset.
+	type IsReserve = (
+		bridging::to_polkadot::PolkadotOrEthereumAssetFromAssetHubPolkadot,
+		xcm_builder::Case<NisCounterpartFromRelay>,
+	);
 	type IsTeleporter = TrustedTeleporters;
 	type UniversalLocation = UniversalLocation;
 	type Barrier = Barrier;
@@ -377,7 +424,7 @@ impl xcm_executor::Config for XcmConfig {
 	type SubscriptionService = PolkadotXcm;
 	type PalletInstancesInfo = AllPalletsWithSystem;
 	type MaxAssetsIntoHolding = MaxAssetsIntoHolding;
-	type AssetLocker = ();
+	type AssetLocker = PolkadotXcm;
 	type AssetExchanger = ();
 	type FeeManager = XcmFeeManagerFromComponents<
 		WaivedLocations,
@@ -387,7 +434,7 @@ impl xcm_executor::Config for XcmConfig {
 	type UniversalAliases = (bridging::to_polkadot::UniversalAliases,);
 	type CallDispatcher = RuntimeCall;
 	type SafeCallFilter = Everything;
-	type Aliasers = Nothing;
+	type Aliasers = TrustedAliasers;
 	type TransactionalProcessor = FrameTransactionalProcessor;
 	type HrmpNewChannelOpenRequestHandler = ();
 	type HrmpChannelAcceptedHandler = ();
@@ -439,7 +486,8 @@ impl pallet_xcm::Config for Runtime {
 	const VERSION_DISCOVERY_QUEUE_SIZE: u32 = 100;
 	type AdvertisedXcmVersion = pallet_xcm::CurrentXcmVersion;
 	type Currency = Balances;
-	type CurrencyMatcher = ();
+	// KSM may be locked in favour of the relay chain, which only keeps track of the locks.
+	type CurrencyMatcher = IsConcrete<KsmLocation>;
 	type TrustedLockers = ();
 	type SovereignAccountOf = LocationToAccountId;
 	type MaxLockers = ConstU32<8>;
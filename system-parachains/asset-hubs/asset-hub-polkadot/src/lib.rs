@@ -103,7 +103,10 @@ use frame_support::{
 		ConstU32, ConstU64, ConstU8, EitherOfDiverse, Equals, InstanceFilter, NeverEnsureOrigin,
 		TransformOrigin, WithdrawReasons,
 	},
-	weights::{ConstantMultiplier, Weight, WeightToFee as _},
+	weights::{
+		constants::{WEIGHT_PROOF_SIZE_PER_KB, WEIGHT_REF_TIME_PER_MICROS},
+		ConstantMultiplier, Weight, WeightToFee as _,
+	},
 	PalletId,
 };
 use frame_system::{
@@ -220,12 +223,33 @@ impl frame_system::Config for Runtime {
 	type OnSetCode = cumulus_pallet_parachain_system::ParachainSetCode<Self>;
 	type MaxConsumers = frame_support::traits::ConstU32<64>;
 	type SingleBlockMigrations = ();
-	type MultiBlockMigrator = ();
+	type MultiBlockMigrator = MultiBlockMigrations;
 	type PreInherents = ();
 	type PostInherents = ();
 	type PostTransactions = ();
 }
 
+parameter_types! {
+	pub MbmServiceWeight: Weight = Perbill::from_percent(80) * RuntimeBlockWeights::get().max_block;
+}
+
+impl pallet_migrations::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	#[cfg(not(feature = "runtime-benchmarks"))]
+	type Migrations = ();
+	// Benchmarks need mocked migrations to guarantee that they succeed.
+	#[cfg(feature = "runtime-benchmarks")]
+	type Migrations = pallet_migrations::mock_helpers::MockedMigrations;
+	type CursorMaxLen = ConstU32<65_536>;
+	type IdentifierMaxLen = ConstU32<256>;
+	type MigrationStatusHandler = ();
+	type FailedMigrationHandler = frame_support::migrations::FreezeChainOnFailedMigration;
+	type MaxServiceWeight = MbmServiceWeight;
+
+	// Use same weights as substrate ones.
+	type WeightInfo = pallet_migrations::weights::SubstrateWeight<Runtime>;
+}
+
 impl pallet_timestamp::Config for Runtime {
 	/// A timestamp: milliseconds since the unix epoch.
 	type Moment = u64;
@@ -445,9 +469,11 @@ parameter_types! {
 	RuntimeDebug,
 	MaxEncodedLen,
 	scale_info::TypeInfo,
+	Default,
 )]
 pub enum ProxyType {
 	/// Fully permissioned proxy. Can execute any call on behalf of _proxied_.
+	#[default]
 	Any,
 	/// Can execute any call that does not transfer funds or assets.
 	NonTransfer,
@@ -462,12 +488,6 @@ pub enum ProxyType {
 	/// Collator selection proxy. Can execute calls related to collator selection mechanism.
 	Collator,
 }
-impl Default for ProxyType {
-	fn default() -> Self {
-		Self::Any
-	}
-}
-
 impl InstanceFilter<RuntimeCall> for ProxyType {
 	fn filter(&self, c: &RuntimeCall) -> bool {
 		match self {
@@ -478,6 +498,7 @@ impl InstanceFilter<RuntimeCall> for ProxyType {
 					RuntimeCall::Assets { .. } |
 					RuntimeCall::Nfts { .. } |
 					RuntimeCall::Uniques { .. } |
+					RuntimeCall::RelayCalls { .. } |
 					// We allow calling `vest` and merging vesting schedules, but obviously not
 					// vested transfers.
 					RuntimeCall::Vesting(pallet_vesting::Call::vested_transfer { .. })
@@ -957,6 +978,61 @@ impl pallet_asset_conversion::Config for Runtime {
 	>;
 }
 
+parameter_types! {
+	pub const OnDemandPalletIndex: u8 = polkadot_runtime_constants::ON_DEMAND_PALLET_ID;
+	// Weight for `place_order_allow_death` from Polkadot runtime benchmarks, for the longest queue:
+	// `ref_time`, `proof_size`, reads, writes
+	// 223_473_038, 83_587, 5, 4
+	// Add 30% to each component with a healthy round up.
+	pub const OnDemandOrderWeight: Weight =
+		Weight::from_parts(1000 * WEIGHT_REF_TIME_PER_MICROS, 110 * WEIGHT_PROOF_SIZE_PER_KB);
+	pub const RegistrarPalletIndex: u8 = polkadot_runtime_constants::REGISTRAR_PALLET_ID;
+	pub const ParaOnboardingPalletIndex: u8 = polkadot_runtime_constants::PARA_ONBOARDING_PALLET_ID;
+	// Weight for `reserve` and `register` from Polkadot runtime benchmarks, plus the reads of the
+	// preimage of the validation code:
+	// `ref_time`, `proof_size`, reads, writes
+	// 7_433_769_000, 7417, 12, 10
+	// Add 30% to each component with a healthy round up.
+	pub const RegisterParaWeight: Weight =
+		Weight::from_parts(12_000 * WEIGHT_REF_TIME_PER_MICROS, 10 * WEIGHT_PROOF_SIZE_PER_KB);
+	// Weight for `deregister` from Polkadot runtime benchmarks:
+	// `ref_time`, `proof_size`, reads, writes
+	// 102_481_000, 3969, 6, 4
+	// Add 30% to each component with a healthy round up.
+	pub const DeregisterParaWeight: Weight =
+		Weight::from_parts(1000 * WEIGHT_REF_TIME_PER_MICROS, 6 * WEIGHT_PROOF_SIZE_PER_KB);
+	pub const ParaChannelsPalletIndex: u8 = polkadot_runtime_constants::PARA_CHANNELS_PALLET_ID;
+	// Weight for `hrmp_init_open_channel` from Polkadot runtime benchmarks:
+	// `ref_time`, `proof_size`, reads, writes
+	// 56_350_000, 3886, 9, 5
+	// Add 30% to each component with a healthy round up.
+	pub const InitOpenHrmpChannelWeight: Weight =
+		Weight::from_parts(1100 * WEIGHT_REF_TIME_PER_MICROS, 6 * WEIGHT_PROOF_SIZE_PER_KB);
+	// Weight for `hrmp_accept_open_channel` from Polkadot runtime benchmarks:
+	// `ref_time`, `proof_size`, reads, writes
+	// 55_021_000, 3876, 6, 4
+	// Add 30% to each component with a healthy round up.
+	pub const AcceptOpenHrmpChannelWeight: Weight =
+		Weight::from_parts(1000 * WEIGHT_REF_TIME_PER_MICROS, 6 * WEIGHT_PROOF_SIZE_PER_KB);
+}
+
+impl pallet_relay_calls::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type AssetTransactor = xcm_config::AssetTransactors;
+	type OnDemandPalletIndex = OnDemandPalletIndex;
+	type OnDemandOrderWeight = OnDemandOrderWeight;
+	type RegistrarPalletIndex = RegistrarPalletIndex;
+	type ParaOnboardingPalletIndex = ParaOnboardingPalletIndex;
+	type RegisterParaWeight = RegisterParaWeight;
+	type DeregisterParaWeight = DeregisterParaWeight;
+	type ExistentialDeposit = ExistentialDeposit;
+	type SiblingParaOrigin = EnsureXcm<frame_support::traits::Everything>;
+	type ParaChannelsPalletIndex = ParaChannelsPalletIndex;
+	type InitOpenHrmpChannelWeight = InitOpenHrmpChannelWeight;
+	type AcceptOpenHrmpChannelWeight = AcceptOpenHrmpChannelWeight;
+	type WeightInfo = weights::pallet_relay_calls::WeightInfo<Runtime>;
+}
+
 // Create the runtime by composing the FRAME pallets that were previously configured.
 construct_runtime!(
 	pub enum Runtime
@@ -967,6 +1043,8 @@ construct_runtime!(
 		// RandomnessCollectiveFlip = 2 removed
 		Timestamp: pallet_timestamp = 3,
 		ParachainInfo: parachain_info = 4,
+		// Multi-block migrations, transactions are paused while they run.
+		MultiBlockMigrations: pallet_migrations = 5,
 
 		// Monetary stuff.
 		Balances: pallet_balances = 10,
@@ -1001,6 +1079,9 @@ construct_runtime!(
 		ForeignAssets: pallet_assets::<Instance2> = 53,
 		PoolAssets: pallet_assets::<Instance3> = 54,
 		AssetConversion: pallet_asset_conversion = 55,
+
+		// Operations on the Relay Chain.
+		RelayCalls: pallet_relay_calls = 60,
 	}
 );
 
@@ -1029,17 +1110,25 @@ pub type UncheckedExtrinsic =
 	generic::UncheckedExtrinsic<Address, RuntimeCall, Signature, SignedExtra>;
 
 /// Migrations to apply on runtime upgrade.
-pub type Migrations = (
-	// unreleased and/or un-applied
-	cumulus_pallet_xcmp_queue::migration::v5::MigrateV4ToV5<Runtime>,
-	pallet_assets::migration::next_asset_id::SetNextAssetId<
-		ConstU32<50_000_000>,
-		Runtime,
-		TrustBackedAssetsInstance,
-	>,
-	// permanent
-	pallet_xcm::migration::MigrateToLatestXcmVersion<Runtime>,
-);
+pub type Migrations = (migrations::Unreleased, migrations::Permanent);
+
+/// The runtime migrations per release.
+pub mod migrations {
+	use super::*;
+
+	/// Unreleased and/or un-applied migrations. Add new ones here:
+	pub type Unreleased = (
+		cumulus_pallet_xcmp_queue::migration::v5::MigrateV4ToV5<Runtime>,
+		pallet_assets::migration::next_asset_id::SetNextAssetId<
+			ConstU32<50_000_000>,
+			Runtime,
+			TrustBackedAssetsInstance,
+		>,
+	);
+
+	/// Migrations/checks that do not need to be versioned and can run on every update.
+	pub type Permanent = (pallet_xcm::migration::MigrateToLatestXcmVersion<Runtime>,);
+}
 
 /// Executive: handles dispatch to the various modules.
 pub type Executive = frame_executive::Executive<
@@ -1061,9 +1150,11 @@ mod benches {
 		[pallet_asset_conversion, AssetConversion]
 		[pallet_balances, Balances]
 		[pallet_message_queue, MessageQueue]
+		[pallet_migrations, MultiBlockMigrations]
 		[pallet_multisig, Multisig]
 		[pallet_nfts, Nfts]
 		[pallet_proxy, Proxy]
+		[pallet_relay_calls, RelayCallsBench::<Runtime>]
 		[pallet_session, SessionBench::<Runtime>]
 		[pallet_uniques, Uniques]
 		[pallet_utility, Utility]
@@ -1229,6 +1320,19 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl proxy_filter_api::ProxyFilterApi<Block, ProxyType> for Runtime {
+		fn proxy_types() -> Vec<proxy_filter_api::ProxyTypeInfo<ProxyType>> {
+			proxy_filter_api::proxy_types::<Runtime>()
+		}
+
+		fn is_call_allowed(
+			proxy_type: ProxyType,
+			call: Vec<u8>,
+		) -> Result<bool, proxy_filter_api::ProxyFilterError> {
+			proxy_filter_api::is_call_allowed::<Runtime>(&proxy_type, &call)
+		}
+	}
+
 	impl xcm_runtime_apis::fees::XcmPaymentApi<Block> for Runtime {
 		fn query_acceptable_payment_assets(xcm_version: xcm::Version) -> Result<Vec<VersionedAssetId>, XcmPaymentApiError> {
 			let acceptable_assets = vec![AssetId(xcm_config::DotLocation::get())];
@@ -1271,6 +1375,12 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl xcm_dry_run_api::DetailedDryRunApi<Block, RuntimeCall, RuntimeEvent, OriginCaller> for Runtime {
+		fn detailed_dry_run_call(origin: OriginCaller, call: RuntimeCall) -> Result<xcm_dry_run_api::DetailedCallDryRunEffects<RuntimeEvent>, XcmDryRunApiError> {
+			xcm_dry_run_api::detailed_dry_run_call::<Runtime, xcm_config::XcmRouter, OriginCaller, xcm_config::XcmConfig>(origin, call)
+		}
+	}
+
 	impl xcm_runtime_apis::conversions::LocationToAccountApi<Block, AccountId> for Runtime {
 		fn convert_location(location: VersionedLocation) -> Result<
 			AccountId,
@@ -1409,6 +1519,7 @@ impl_runtime_apis! {
 			use frame_support::traits::StorageInfoTrait;
 			use pallet_xcm::benchmarking::Pallet as PalletXcmExtrinsiscsBenchmark;
 			use frame_system_benchmarking::Pallet as SystemBench;
+			use pallet_relay_calls::benchmarking::Pallet as RelayCallsBench;
 			use cumulus_pallet_session_benchmarking::Pallet as SessionBench;
 			use pallet_xcm_bridge_hub_router::benchmarking::Pallet as XcmBridgeHubRouterBench;
 
@@ -1572,6 +1683,20 @@ impl_runtime_apis! {
 				}
 			}
 
+			use pallet_relay_calls::benchmarking::Pallet as RelayCallsBench;
+			impl pallet_relay_calls::benchmarking::Config for Runtime {
+				type DeliveryHelper = cumulus_primitives_utility::ToParentDeliveryHelper<
+					xcm_config::XcmConfig,
+					ExistentialDepositAsset,
+					PriceForParentDelivery,
+				>;
+
+				fn sibling_para_origin() -> Option<RuntimeOrigin> {
+					let sibling = ParentThen(Parachain(RandomParaId::get().into()).into());
+					Some(pallet_xcm::Origin::Xcm(sibling.into()).into())
+				}
+			}
+
 			impl pallet_xcm_benchmarks::Config for Runtime {
 				type XcmConfig = xcm_config::XcmConfig;
 				type AccountIdConverter = xcm_config::LocationToAccountId;
@@ -1679,7 +1804,20 @@ impl_runtime_apis! {
 				}
 
 				fn unlockable_asset() -> Result<(Location, Location, Asset), BenchmarkError> {
-					Err(BenchmarkError::Skip)
+					// DOT of an account locked in favour of the relay chain. Asset Hub doesn't note
+					// the locks of other chains, so only `lock_asset` and `unlock_asset` are
+					// supported.
+					let owner: AccountId = frame_benchmarking::account("owner", 0, 0);
+					let amount = ExistentialDeposit::get() * 1000;
+					let _ = <Balances as frame_support::traits::Currency<_>>::make_free_balance_be(
+						&owner,
+						amount * 2,
+					);
+					Ok((
+						DotLocation::get(),
+						AccountId32 { network: None, id: owner.into() }.into(),
+						(DotLocation::get(), amount).into(),
+					))
 				}
 
 				fn export_message_origin_and_destination(
@@ -1688,7 +1826,9 @@ impl_runtime_apis! {
 				}
 
 				fn alias_origin() -> Result<(Location, Location), BenchmarkError> {
-					Err(BenchmarkError::Skip)
+					// An account of the relay chain aliasing into the same account here.
+					let account = AccountId32 { network: None, id: [1; 32] };
+					Ok((Location::new(1, [account]), Location::new(0, [account])))
 				}
 			}
 
@@ -0,0 +1,48 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Cumulus.
+
+// Cumulus is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Cumulus is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Cumulus.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Weights for `pallet_relay_calls`.
+//!
+//! The calls withdraw the Relay Chain token of the caller, check it out and send a message with it
+//! to the Relay Chain, as `teleport_assets` of `pallet_xcm` does. Until the benchmarks of
+//! `pallet_relay_calls` are run for this runtime and replace this file, they are weighed as
+//! the benchmarked `teleport_assets`.
+
+use core::marker::PhantomData;
+use frame_support::weights::Weight;
+use pallet_xcm::WeightInfo as _;
+
+type TeleportWeight<T> = super::pallet_xcm::WeightInfo<T>;
+
+/// Weight functions for `pallet_relay_calls`.
+pub struct WeightInfo<T>(PhantomData<T>);
+impl<T: frame_system::Config> pallet_relay_calls::WeightInfo for WeightInfo<T> {
+	fn place_on_demand_order() -> Weight {
+		TeleportWeight::<T>::teleport_assets()
+	}
+	fn register_para() -> Weight {
+		TeleportWeight::<T>::teleport_assets()
+	}
+	fn deregister_para() -> Weight {
+		TeleportWeight::<T>::teleport_assets()
+	}
+	fn init_open_hrmp_channel() -> Weight {
+		TeleportWeight::<T>::teleport_assets()
+	}
+	fn accept_open_hrmp_channel() -> Weight {
+		TeleportWeight::<T>::teleport_assets()
+	}
+}
@@ -26,8 +26,8 @@ use asset_hub_polkadot_runtime::{
 	},
 	AllPalletsWithoutSystem, AssetConversion, AssetDeposit, Assets, Balances, ExistentialDeposit,
 	ForeignAssets, ForeignAssetsInstance, MetadataDepositBase, MetadataDepositPerByte,
-	ParachainSystem, PolkadotXcm, Runtime, RuntimeCall, RuntimeEvent, RuntimeOrigin, SessionKeys,
-	ToKusamaXcmRouterInstance, TrustBackedAssetsInstance, XcmpQueue, SLOT_DURATION,
+	ParachainSystem, PolkadotXcm, ProxyType, Runtime, RuntimeCall, RuntimeEvent, RuntimeOrigin,
+	SessionKeys, ToKusamaXcmRouterInstance, TrustBackedAssetsInstance, XcmpQueue, SLOT_DURATION,
 };
 use asset_test_utils::{
 	test_cases_over_bridge::TestBridgingConfig, CollatorSessionKey, CollatorSessionKeys, ExtBuilder,
@@ -1424,3 +1424,91 @@ fn location_conversion_works() {
 		assert_eq!(got, expected, "{}", tc.description);
 	}
 }
+
+migration_test_utils::impl_migration_tests!(asset_hub_polkadot_runtime, "development");
+
+#[test]
+fn unlocks_are_only_executed_for_free_alone_and_from_the_relay_chain() {
+	use asset_hub_polkadot_runtime::xcm_config::Barrier;
+	use xcm_executor::traits::{Properties, ShouldExecute};
+
+	let unlock = UnlockAsset {
+		asset: (DotLocation::get(), 10 * ExistentialDeposit::get()).into(),
+		target: AccountId32 { network: None, id: ALICE }.into(),
+	};
+	let should_execute = |origin: Location, mut message: Vec<Instruction<RuntimeCall>>| {
+		Barrier::should_execute(
+			&origin,
+			&mut message,
+			Weight::from_parts(1_000_000, 1_000),
+			&mut Properties { weight_credit: Weight::zero(), message_id: None },
+		)
+	};
+
+	assert_eq!(should_execute(Location::parent(), vec![unlock.clone()]), Ok(()));
+	// Nothing but the unlock is executed for free.
+	for message in [
+		vec![unlock.clone(), ClearOrigin],
+		vec![ClearOrigin, unlock.clone()],
+		vec![unlock.clone(), unlock.clone()],
+		vec![
+			unlock.clone(),
+			Transact {
+				origin_kind: OriginKind::Superuser,
+				require_weight_at_most: Weight::from_parts(1_000_000, 1_000),
+				call: RuntimeCall::System(frame_system::Call::remark { remark: vec![] })
+					.encode()
+					.into(),
+			},
+		],
+	] {
+		assert!(should_execute(Location::parent(), message).is_err());
+	}
+	// Only the relay chain itself may send it.
+	assert!(should_execute(Location::new(1, [Parachain(2000)]), vec![unlock.clone()]).is_err());
+	assert!(should_execute(
+		Location::new(1, [AccountId32 { network: None, id: ALICE }]),
+		vec![unlock]
+	)
+	.is_err());
+}
+
+#[test]
+fn proxy_filter_api_works() {
+	let remark = RuntimeCall::System(frame_system::Call::remark { remark: vec![] });
+	let transfer = RuntimeCall::Balances(pallet_balances::Call::transfer_allow_death {
+		dest: AccountId::from([0u8; 32]).into(),
+		value: 1,
+	});
+	let reject_announcement = RuntimeCall::Proxy(pallet_proxy::Call::reject_announcement {
+		delegate: AccountId::from([0u8; 32]).into(),
+		call_hash: Default::default(),
+	});
+	let set_min_balance =
+		RuntimeCall::Assets(pallet_assets::Call::set_min_balance { id: 1.into(), min_balance: 1 });
+	let mint = RuntimeCall::Assets(pallet_assets::Call::mint {
+		id: 1.into(),
+		beneficiary: AccountId::from([0u8; 32]).into(),
+		amount: 1,
+	});
+	let leave_intent =
+		RuntimeCall::CollatorSelection(pallet_collator_selection::Call::leave_intent {});
+	proxy_filter_api::assert_api_works::<Runtime>(&[
+		(ProxyType::Any, transfer.clone(), true),
+		(ProxyType::NonTransfer, remark.clone(), true),
+		(ProxyType::NonTransfer, leave_intent.clone(), true),
+		(ProxyType::NonTransfer, transfer.clone(), false),
+		(ProxyType::NonTransfer, mint.clone(), false),
+		(ProxyType::CancelProxy, reject_announcement, true),
+		(ProxyType::CancelProxy, remark.clone(), false),
+		(ProxyType::Assets, set_min_balance.clone(), true),
+		(ProxyType::Assets, mint.clone(), true),
+		(ProxyType::Assets, transfer, false),
+		(ProxyType::AssetOwner, set_min_balance.clone(), true),
+		(ProxyType::AssetOwner, mint.clone(), false),
+		(ProxyType::AssetManager, mint, true),
+		(ProxyType::AssetManager, set_min_balance, false),
+		(ProxyType::Collator, leave_intent, true),
+		(ProxyType::Collator, remark, false),
+	]);
+}
@@ -537,3 +537,5 @@ fn location_conversion_works() {
 		assert_eq!(got, expected, "{}", tc.description);
 	}
 }
+
+migration_test_utils::impl_migration_tests!(bridge_hub_kusama_runtime, "development");
@@ -146,26 +146,34 @@ parameter_types! {
 }
 
 /// Migrations to apply on runtime upgrade.
-pub type Migrations = (
-	// unreleased and/or un-applied
-	cumulus_pallet_xcmp_queue::migration::v5::MigrateV4ToV5<Runtime>,
-	pallet_bridge_messages::migration::v1::MigrationToV1<
-		Runtime,
-		bridge_to_kusama_config::WithBridgeHubKusamaMessagesInstance,
-	>,
-	bridge_to_kusama_config::migration::StaticToDynamicLanes,
-	frame_support::migrations::RemoveStorage<
-		BridgeKusamaMessagesPalletName,
-		OutboundLanesCongestedSignalsKey,
-		RocksDbWeight,
-	>,
-	pallet_bridge_relayers::migration::v1::MigrationToV1<
-		Runtime,
-		bridge_to_kusama_config::RelayersForLegacyLaneIdsMessagesInstance,
-	>,
-	// permanent
-	pallet_xcm::migration::MigrateToLatestXcmVersion<Runtime>,
-);
+pub type Migrations = (migrations::Unreleased, migrations::Permanent);
+
+/// The runtime migrations per release.
+pub mod migrations {
+	use super::*;
+
+	/// Unreleased and/or un-applied migrations. Add new ones here:
+	pub type Unreleased = (
+		cumulus_pallet_xcmp_queue::migration::v5::MigrateV4ToV5<Runtime>,
+		pallet_bridge_messages::migration::v1::MigrationToV1<
+			Runtime,
+			bridge_to_kusama_config::WithBridgeHubKusamaMessagesInstance,
+		>,
+		bridge_to_kusama_config::migration::StaticToDynamicLanes,
+		frame_support::migrations::RemoveStorage<
+			BridgeKusamaMessagesPalletName,
+			OutboundLanesCongestedSignalsKey,
+			RocksDbWeight,
+		>,
+		pallet_bridge_relayers::migration::v1::MigrationToV1<
+			Runtime,
+			bridge_to_kusama_config::RelayersForLegacyLaneIdsMessagesInstance,
+		>,
+	);
+
+	/// Migrations/checks that do not need to be versioned and can run on every update.
+	pub type Permanent = (pallet_xcm::migration::MigrateToLatestXcmVersion<Runtime>,);
+}
 
 /// Executive: handles dispatch to the various modules.
 pub type Executive = frame_executive::Executive<
@@ -826,6 +834,12 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl xcm_dry_run_api::DetailedDryRunApi<Block, RuntimeCall, RuntimeEvent, OriginCaller> for Runtime {
+		fn detailed_dry_run_call(origin: OriginCaller, call: RuntimeCall) -> Result<xcm_dry_run_api::DetailedCallDryRunEffects<RuntimeEvent>, XcmDryRunApiError> {
+			xcm_dry_run_api::detailed_dry_run_call::<Runtime, xcm_config::XcmRouter, OriginCaller, xcm_config::XcmConfig>(origin, call)
+		}
+	}
+
 	impl xcm_runtime_apis::conversions::LocationToAccountApi<Block, AccountId> for Runtime {
 		fn convert_location(location: VersionedLocation) -> Result<
 			AccountId,
@@ -1199,7 +1213,9 @@ impl_runtime_apis! {
 				}
 
 				fn alias_origin() -> Result<(Location, Location), BenchmarkError> {
-					Err(BenchmarkError::Skip)
+					// An account of the relay chain aliasing into the same account here.
+					let account = AccountId32 { network: None, id: [1; 32] };
+					Ok((Location::new(1, [account]), Location::new(0, [account])))
 				}
 			}
 
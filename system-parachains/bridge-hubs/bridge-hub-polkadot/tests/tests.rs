@@ -538,3 +538,5 @@ fn location_conversion_works() {
 		assert_eq!(got, expected, "{}", tc.description);
 	}
 }
+
+migration_test_utils::impl_migration_tests!(bridge_hub_polkadot_runtime, "development");
@@ -46,6 +46,7 @@ use frame_system::EnsureRootWithSuccess;
 use origins::pallet_origins::{EnsureAmbassadorsFrom, HeadAmbassadors, Origin, SeniorAmbassadors};
 use pallet_ranked_collective::{MemberIndex, Rank, Votes};
 use polkadot_runtime_common::impls::{LocatableAssetConverter, VersionedLocationConverter};
+use referenda_tracks_api::governance_period;
 use sp_core::ConstU128;
 use sp_runtime::{
 	traits::{
@@ -160,7 +161,7 @@ impl MaybeConvert<Rank, MemberIndex> for AmbassadorMemberCount {
 parameter_types! {
 	pub const AlarmInterval: BlockNumber = 1;
 	pub const SubmissionDeposit: Balance = 0;
-	pub const UndecidingTimeout: BlockNumber = 7 * DAYS;
+	pub const UndecidingTimeout: BlockNumber = governance_period!(7 * DAYS);
 }
 
 pub type AmbassadorReferendaInstance = pallet_referenda::Instance2;
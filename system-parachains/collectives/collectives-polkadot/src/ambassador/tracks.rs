@@ -17,6 +17,7 @@
 
 use super::Origin;
 use crate::{Balance, BlockNumber, RuntimeOrigin, DAYS, DOLLARS, HOURS};
+use referenda_tracks_api::governance_period;
 use sp_runtime::Perbill;
 
 /// Referendum `TrackId` type.
@@ -49,10 +50,10 @@ impl pallet_referenda::TracksInfo<Balance, BlockNumber> for TracksInfo {
 					name: "ambassador",
 					max_deciding: 10,
 					decision_deposit: 5 * DOLLARS,
-					prepare_period: 24 * HOURS,
-					decision_period: 7 * DAYS,
-					confirm_period: 24 * HOURS,
-					min_enactment_period: HOURS,
+					prepare_period: governance_period!(24 * HOURS),
+					decision_period: governance_period!(7 * DAYS),
+					confirm_period: governance_period!(24 * HOURS),
+					min_enactment_period: governance_period!(HOURS),
 					min_approval: pallet_referenda::Curve::LinearDecreasing {
 						length: Perbill::from_percent(100),
 						floor: Perbill::from_percent(50),
@@ -71,10 +72,10 @@ impl pallet_referenda::TracksInfo<Balance, BlockNumber> for TracksInfo {
 					name: "senior ambassador",
 					max_deciding: 10,
 					decision_deposit: 5 * DOLLARS,
-					prepare_period: 24 * HOURS,
-					decision_period: 7 * DAYS,
-					confirm_period: 24 * HOURS,
-					min_enactment_period: HOURS,
+					prepare_period: governance_period!(24 * HOURS),
+					decision_period: governance_period!(7 * DAYS),
+					confirm_period: governance_period!(24 * HOURS),
+					min_enactment_period: governance_period!(HOURS),
 					min_approval: pallet_referenda::Curve::LinearDecreasing {
 						length: Perbill::from_percent(100),
 						floor: Perbill::from_percent(50),
@@ -93,10 +94,10 @@ impl pallet_referenda::TracksInfo<Balance, BlockNumber> for TracksInfo {
 					name: "head ambassador",
 					max_deciding: 10,
 					decision_deposit: 5 * DOLLARS,
-					prepare_period: 24 * HOURS,
-					decision_period: 7 * DAYS,
-					confirm_period: 24 * HOURS,
-					min_enactment_period: HOURS,
+					prepare_period: governance_period!(24 * HOURS),
+					decision_period: governance_period!(7 * DAYS),
+					confirm_period: governance_period!(24 * HOURS),
+					min_enactment_period: governance_period!(HOURS),
 					min_approval: pallet_referenda::Curve::LinearDecreasing {
 						length: Perbill::from_percent(100),
 						floor: Perbill::from_percent(50),
@@ -45,6 +45,7 @@ use polkadot_runtime_common::impls::{
 	LocatableAssetConverter, VersionedLocatableAsset, VersionedLocationConverter,
 };
 use polkadot_runtime_constants::{currency::GRAND, time::HOURS, xcm::body::FELLOWSHIP_ADMIN_INDEX};
+use referenda_tracks_api::governance_period;
 use sp_arithmetic::Permill;
 use sp_core::{ConstU128, ConstU32};
 use sp_runtime::traits::{
@@ -105,7 +106,7 @@ impl pallet_referenda::Config<FellowshipReferendaInstance> for Runtime {
 	type Tally = pallet_ranked_collective::TallyOf<Runtime, FellowshipCollectiveInstance>;
 	type SubmissionDeposit = ConstU128<0>;
 	type MaxQueued = ConstU32<100>;
-	type UndecidingTimeout = ConstU32<{ 7 * DAYS }>;
+	type UndecidingTimeout = ConstU32<{ governance_period!(7 * DAYS) }>;
 	type AlarmInterval = ConstU32<1>;
 	type Tracks = tracks::TracksInfo;
 	type Preimages = Preimage;
@@ -336,6 +337,7 @@ impl pallet_treasury::Config<FellowshipTreasuryInstance> for Runtime {
 #[cfg(test)]
 mod tests {
 	use super::*;
+	use frame_support::traits::Get;
 	use sp_runtime::traits::MaybeConvert;
 
 	type MaxMemberCount =
@@ -348,4 +350,41 @@ mod tests {
 			assert!(limit.is_none(), "Fellowship has no member limit");
 		}
 	}
+
+	#[test]
+	#[cfg(not(feature = "fast-runtime"))]
+	fn governance_periods_are_not_scaled_without_fast_runtime() {
+		use crate::MINUTES;
+		use pallet_referenda::TracksInfo as _;
+
+		type Tracks = <Runtime as pallet_referenda::Config<FellowshipReferendaInstance>>::Tracks;
+		type UndecidingTimeout =
+			<Runtime as pallet_referenda::Config<FellowshipReferendaInstance>>::UndecidingTimeout;
+		let (_, members) = &Tracks::tracks()[0];
+		assert_eq!(members.name, "members");
+		assert_eq!(
+			(members.prepare_period, members.decision_period, members.confirm_period),
+			(30 * MINUTES, 7 * DAYS, 30 * MINUTES)
+		);
+		assert_eq!(members.min_enactment_period, 5 * MINUTES);
+		assert_eq!(<UndecidingTimeout as Get<u32>>::get(), 7 * DAYS);
+	}
+
+	#[test]
+	#[cfg(feature = "fast-runtime")]
+	fn governance_periods_are_scaled_with_fast_runtime() {
+		use crate::MINUTES;
+		use pallet_referenda::TracksInfo as _;
+
+		type Tracks = <Runtime as pallet_referenda::Config<FellowshipReferendaInstance>>::Tracks;
+		type UndecidingTimeout =
+			<Runtime as pallet_referenda::Config<FellowshipReferendaInstance>>::UndecidingTimeout;
+		let (_, members) = &Tracks::tracks()[0];
+		assert_eq!(
+			(members.prepare_period, members.decision_period, members.confirm_period),
+			(1, 7 * MINUTES, 1)
+		);
+		assert_eq!(members.min_enactment_period, 1);
+		assert_eq!(UndecidingTimeout::get(), 7 * MINUTES);
+	}
 }
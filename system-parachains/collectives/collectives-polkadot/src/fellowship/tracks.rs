@@ -18,6 +18,7 @@
 
 use crate::{Balance, BlockNumber, RuntimeOrigin, DAYS, DOLLARS, HOURS, MINUTES};
 use pallet_ranked_collective::Rank;
+use referenda_tracks_api::governance_period;
 use sp_runtime::{traits::Convert, Perbill};
 
 /// Referendum `TrackId` type.
@@ -151,10 +152,10 @@ impl pallet_referenda::TracksInfo<Balance, BlockNumber> for TracksInfo {
 					name: "members",
 					max_deciding: 10,
 					decision_deposit: 5 * DOLLARS,
-					prepare_period: 30 * MINUTES,
-					decision_period: 7 * DAYS,
-					confirm_period: 30 * MINUTES,
-					min_enactment_period: 5 * MINUTES,
+					prepare_period: governance_period!(30 * MINUTES),
+					decision_period: governance_period!(7 * DAYS),
+					confirm_period: governance_period!(30 * MINUTES),
+					min_enactment_period: governance_period!(5 * MINUTES),
 					min_approval: pallet_referenda::Curve::LinearDecreasing {
 						length: Perbill::from_percent(100),
 						floor: Perbill::from_percent(50),
@@ -173,10 +174,10 @@ impl pallet_referenda::TracksInfo<Balance, BlockNumber> for TracksInfo {
 					name: "proficient members",
 					max_deciding: 10,
 					decision_deposit: 5 * DOLLARS,
-					prepare_period: 30 * MINUTES,
-					decision_period: 7 * DAYS,
-					confirm_period: 30 * MINUTES,
-					min_enactment_period: 5 * MINUTES,
+					prepare_period: governance_period!(30 * MINUTES),
+					decision_period: governance_period!(7 * DAYS),
+					confirm_period: governance_period!(30 * MINUTES),
+					min_enactment_period: governance_period!(5 * MINUTES),
 					min_approval: pallet_referenda::Curve::LinearDecreasing {
 						length: Perbill::from_percent(100),
 						floor: Perbill::from_percent(50),
@@ -195,10 +196,10 @@ impl pallet_referenda::TracksInfo<Balance, BlockNumber> for TracksInfo {
 					name: "fellows",
 					max_deciding: 10,
 					decision_deposit: 5 * DOLLARS,
-					prepare_period: 30 * MINUTES,
-					decision_period: 7 * DAYS,
-					confirm_period: 30 * MINUTES,
-					min_enactment_period: 5 * MINUTES,
+					prepare_period: governance_period!(30 * MINUTES),
+					decision_period: governance_period!(7 * DAYS),
+					confirm_period: governance_period!(30 * MINUTES),
+					min_enactment_period: governance_period!(5 * MINUTES),
 					min_approval: pallet_referenda::Curve::LinearDecreasing {
 						length: Perbill::from_percent(100),
 						floor: Perbill::from_percent(50),
@@ -217,10 +218,10 @@ impl pallet_referenda::TracksInfo<Balance, BlockNumber> for TracksInfo {
 					name: "architects",
 					max_deciding: 10,
 					decision_deposit: 5 * DOLLARS,
-					prepare_period: 30 * MINUTES,
-					decision_period: 7 * DAYS,
-					confirm_period: 30 * MINUTES,
-					min_enactment_period: 5 * MINUTES,
+					prepare_period: governance_period!(30 * MINUTES),
+					decision_period: governance_period!(7 * DAYS),
+					confirm_period: governance_period!(30 * MINUTES),
+					min_enactment_period: governance_period!(5 * MINUTES),
 					min_approval: pallet_referenda::Curve::LinearDecreasing {
 						length: Perbill::from_percent(100),
 						floor: Perbill::from_percent(50),
@@ -239,10 +240,10 @@ impl pallet_referenda::TracksInfo<Balance, BlockNumber> for TracksInfo {
 					name: "architects adept",
 					max_deciding: 10,
 					decision_deposit: 5 * DOLLARS,
-					prepare_period: 30 * MINUTES,
-					decision_period: 7 * DAYS,
-					confirm_period: 30 * MINUTES,
-					min_enactment_period: 5 * MINUTES,
+					prepare_period: governance_period!(30 * MINUTES),
+					decision_period: governance_period!(7 * DAYS),
+					confirm_period: governance_period!(30 * MINUTES),
+					min_enactment_period: governance_period!(5 * MINUTES),
 					min_approval: pallet_referenda::Curve::LinearDecreasing {
 						length: Perbill::from_percent(100),
 						floor: Perbill::from_percent(50),
@@ -261,10 +262,10 @@ impl pallet_referenda::TracksInfo<Balance, BlockNumber> for TracksInfo {
 					name: "grand architects",
 					max_deciding: 10,
 					decision_deposit: 5 * DOLLARS,
-					prepare_period: 30 * MINUTES,
-					decision_period: 7 * DAYS,
-					confirm_period: 30 * MINUTES,
-					min_enactment_period: 5 * MINUTES,
+					prepare_period: governance_period!(30 * MINUTES),
+					decision_period: governance_period!(7 * DAYS),
+					confirm_period: governance_period!(30 * MINUTES),
+					min_enactment_period: governance_period!(5 * MINUTES),
 					min_approval: pallet_referenda::Curve::LinearDecreasing {
 						length: Perbill::from_percent(100),
 						floor: Perbill::from_percent(50),
@@ -283,10 +284,10 @@ impl pallet_referenda::TracksInfo<Balance, BlockNumber> for TracksInfo {
 					name: "masters",
 					max_deciding: 10,
 					decision_deposit: 5 * DOLLARS,
-					prepare_period: 30 * MINUTES,
-					decision_period: 7 * DAYS,
-					confirm_period: 30 * MINUTES,
-					min_enactment_period: 5 * MINUTES,
+					prepare_period: governance_period!(30 * MINUTES),
+					decision_period: governance_period!(7 * DAYS),
+					confirm_period: governance_period!(30 * MINUTES),
+					min_enactment_period: governance_period!(5 * MINUTES),
 					min_approval: pallet_referenda::Curve::LinearDecreasing {
 						length: Perbill::from_percent(100),
 						floor: Perbill::from_percent(50),
@@ -305,10 +306,10 @@ impl pallet_referenda::TracksInfo<Balance, BlockNumber> for TracksInfo {
 					name: "masters constant",
 					max_deciding: 10,
 					decision_deposit: 5 * DOLLARS,
-					prepare_period: 30 * MINUTES,
-					decision_period: 7 * DAYS,
-					confirm_period: 30 * MINUTES,
-					min_enactment_period: 5 * MINUTES,
+					prepare_period: governance_period!(30 * MINUTES),
+					decision_period: governance_period!(7 * DAYS),
+					confirm_period: governance_period!(30 * MINUTES),
+					min_enactment_period: governance_period!(5 * MINUTES),
 					min_approval: pallet_referenda::Curve::LinearDecreasing {
 						length: Perbill::from_percent(100),
 						floor: Perbill::from_percent(50),
@@ -327,10 +328,10 @@ impl pallet_referenda::TracksInfo<Balance, BlockNumber> for TracksInfo {
 					name: "grand masters",
 					max_deciding: 10,
 					decision_deposit: 5 * DOLLARS,
-					prepare_period: 30 * MINUTES,
-					decision_period: 7 * DAYS,
-					confirm_period: 30 * MINUTES,
-					min_enactment_period: 5 * MINUTES,
+					prepare_period: governance_period!(30 * MINUTES),
+					decision_period: governance_period!(7 * DAYS),
+					confirm_period: governance_period!(30 * MINUTES),
+					min_enactment_period: governance_period!(5 * MINUTES),
 					min_approval: pallet_referenda::Curve::LinearDecreasing {
 						length: Perbill::from_percent(100),
 						floor: Perbill::from_percent(50),
@@ -349,10 +350,10 @@ impl pallet_referenda::TracksInfo<Balance, BlockNumber> for TracksInfo {
 					name: "retain at I Dan",
 					max_deciding: RETAIN_MAX_DECIDING,
 					decision_deposit: RETAIN_DECISION_DEPOSIT,
-					prepare_period: RETAIN_PREPARE_PERIOD,
-					decision_period: RETAIN_DECISION_PERIOD,
-					confirm_period: RETAIN_CONFIRM_PERIOD,
-					min_enactment_period: RETAIN_MIN_ENACTMENT_PERIOD,
+					prepare_period: governance_period!(RETAIN_PREPARE_PERIOD),
+					decision_period: governance_period!(RETAIN_DECISION_PERIOD),
+					confirm_period: governance_period!(RETAIN_CONFIRM_PERIOD),
+					min_enactment_period: governance_period!(RETAIN_MIN_ENACTMENT_PERIOD),
 					min_approval: RETAIN_MIN_APPROVAL,
 					min_support: RETAIN_MIN_SUPPORT,
 				},
@@ -363,10 +364,10 @@ impl pallet_referenda::TracksInfo<Balance, BlockNumber> for TracksInfo {
 					name: "retain at II Dan",
 					max_deciding: RETAIN_MAX_DECIDING,
 					decision_deposit: RETAIN_DECISION_DEPOSIT,
-					prepare_period: RETAIN_PREPARE_PERIOD,
-					decision_period: RETAIN_DECISION_PERIOD,
-					confirm_period: RETAIN_CONFIRM_PERIOD,
-					min_enactment_period: RETAIN_MIN_ENACTMENT_PERIOD,
+					prepare_period: governance_period!(RETAIN_PREPARE_PERIOD),
+					decision_period: governance_period!(RETAIN_DECISION_PERIOD),
+					confirm_period: governance_period!(RETAIN_CONFIRM_PERIOD),
+					min_enactment_period: governance_period!(RETAIN_MIN_ENACTMENT_PERIOD),
 					min_approval: RETAIN_MIN_APPROVAL,
 					min_support: RETAIN_MIN_SUPPORT,
 				},
@@ -377,10 +378,10 @@ impl pallet_referenda::TracksInfo<Balance, BlockNumber> for TracksInfo {
 					name: "retain at III Dan",
 					max_deciding: RETAIN_MAX_DECIDING,
 					decision_deposit: RETAIN_DECISION_DEPOSIT,
-					prepare_period: RETAIN_PREPARE_PERIOD,
-					decision_period: RETAIN_DECISION_PERIOD,
-					confirm_period: RETAIN_CONFIRM_PERIOD,
-					min_enactment_period: RETAIN_MIN_ENACTMENT_PERIOD,
+					prepare_period: governance_period!(RETAIN_PREPARE_PERIOD),
+					decision_period: governance_period!(RETAIN_DECISION_PERIOD),
+					confirm_period: governance_period!(RETAIN_CONFIRM_PERIOD),
+					min_enactment_period: governance_period!(RETAIN_MIN_ENACTMENT_PERIOD),
 					min_approval: RETAIN_MIN_APPROVAL,
 					min_support: RETAIN_MIN_SUPPORT,
 				},
@@ -391,10 +392,10 @@ impl pallet_referenda::TracksInfo<Balance, BlockNumber> for TracksInfo {
 					name: "retain at IV Dan",
 					max_deciding: RETAIN_MAX_DECIDING,
 					decision_deposit: RETAIN_DECISION_DEPOSIT,
-					prepare_period: RETAIN_PREPARE_PERIOD,
-					decision_period: RETAIN_DECISION_PERIOD,
-					confirm_period: RETAIN_CONFIRM_PERIOD,
-					min_enactment_period: RETAIN_MIN_ENACTMENT_PERIOD,
+					prepare_period: governance_period!(RETAIN_PREPARE_PERIOD),
+					decision_period: governance_period!(RETAIN_DECISION_PERIOD),
+					confirm_period: governance_period!(RETAIN_CONFIRM_PERIOD),
+					min_enactment_period: governance_period!(RETAIN_MIN_ENACTMENT_PERIOD),
 					min_approval: RETAIN_MIN_APPROVAL,
 					min_support: RETAIN_MIN_SUPPORT,
 				},
@@ -405,10 +406,10 @@ impl pallet_referenda::TracksInfo<Balance, BlockNumber> for TracksInfo {
 					name: "retain at V Dan",
 					max_deciding: RETAIN_MAX_DECIDING,
 					decision_deposit: RETAIN_DECISION_DEPOSIT,
-					prepare_period: RETAIN_PREPARE_PERIOD,
-					decision_period: RETAIN_DECISION_PERIOD,
-					confirm_period: RETAIN_CONFIRM_PERIOD,
-					min_enactment_period: RETAIN_MIN_ENACTMENT_PERIOD,
+					prepare_period: governance_period!(RETAIN_PREPARE_PERIOD),
+					decision_period: governance_period!(RETAIN_DECISION_PERIOD),
+					confirm_period: governance_period!(RETAIN_CONFIRM_PERIOD),
+					min_enactment_period: governance_period!(RETAIN_MIN_ENACTMENT_PERIOD),
 					min_approval: RETAIN_MIN_APPROVAL,
 					min_support: RETAIN_MIN_SUPPORT,
 				},
@@ -419,10 +420,10 @@ impl pallet_referenda::TracksInfo<Balance, BlockNumber> for TracksInfo {
 					name: "retain at VI Dan",
 					max_deciding: RETAIN_MAX_DECIDING,
 					decision_deposit: RETAIN_DECISION_DEPOSIT,
-					prepare_period: RETAIN_PREPARE_PERIOD,
-					decision_period: RETAIN_DECISION_PERIOD,
-					confirm_period: RETAIN_CONFIRM_PERIOD,
-					min_enactment_period: RETAIN_MIN_ENACTMENT_PERIOD,
+					prepare_period: governance_period!(RETAIN_PREPARE_PERIOD),
+					decision_period: governance_period!(RETAIN_DECISION_PERIOD),
+					confirm_period: governance_period!(RETAIN_CONFIRM_PERIOD),
+					min_enactment_period: governance_period!(RETAIN_MIN_ENACTMENT_PERIOD),
 					min_approval: RETAIN_MIN_APPROVAL,
 					min_support: RETAIN_MIN_SUPPORT,
 				},
@@ -433,10 +434,10 @@ impl pallet_referenda::TracksInfo<Balance, BlockNumber> for TracksInfo {
 					name: "promote to I Dan",
 					max_deciding: PROMOTE_MAX_DECIDING,
 					decision_deposit: PROMOTE_DECISION_DEPOSIT,
-					prepare_period: PROMOTE_PREPARE_PERIOD,
-					decision_period: PROMOTE_DECISION_PERIOD,
-					confirm_period: PROMOTE_CONFIRM_PERIOD,
-					min_enactment_period: PROMOTE_MIN_ENACTMENT_PERIOD,
+					prepare_period: governance_period!(PROMOTE_PREPARE_PERIOD),
+					decision_period: governance_period!(PROMOTE_DECISION_PERIOD),
+					confirm_period: governance_period!(PROMOTE_CONFIRM_PERIOD),
+					min_enactment_period: governance_period!(PROMOTE_MIN_ENACTMENT_PERIOD),
 					min_approval: PROMOTE_MIN_APPROVAL,
 					min_support: PROMOTE_MIN_SUPPORT,
 				},
@@ -447,10 +448,10 @@ impl pallet_referenda::TracksInfo<Balance, BlockNumber> for TracksInfo {
 					name: "promote to II Dan",
 					max_deciding: PROMOTE_MAX_DECIDING,
 					decision_deposit: PROMOTE_DECISION_DEPOSIT,
-					prepare_period: PROMOTE_PREPARE_PERIOD,
-					decision_period: PROMOTE_DECISION_PERIOD,
-					confirm_period: PROMOTE_CONFIRM_PERIOD,
-					min_enactment_period: PROMOTE_MIN_ENACTMENT_PERIOD,
+					prepare_period: governance_period!(PROMOTE_PREPARE_PERIOD),
+					decision_period: governance_period!(PROMOTE_DECISION_PERIOD),
+					confirm_period: governance_period!(PROMOTE_CONFIRM_PERIOD),
+					min_enactment_period: governance_period!(PROMOTE_MIN_ENACTMENT_PERIOD),
 					min_approval: PROMOTE_MIN_APPROVAL,
 					min_support: PROMOTE_MIN_SUPPORT,
 				},
@@ -461,10 +462,10 @@ impl pallet_referenda::TracksInfo<Balance, BlockNumber> for TracksInfo {
 					name: "promote to III Dan",
 					max_deciding: PROMOTE_MAX_DECIDING,
 					decision_deposit: PROMOTE_DECISION_DEPOSIT,
-					prepare_period: PROMOTE_PREPARE_PERIOD,
-					decision_period: PROMOTE_DECISION_PERIOD,
-					confirm_period: PROMOTE_CONFIRM_PERIOD,
-					min_enactment_period: PROMOTE_MIN_ENACTMENT_PERIOD,
+					prepare_period: governance_period!(PROMOTE_PREPARE_PERIOD),
+					decision_period: governance_period!(PROMOTE_DECISION_PERIOD),
+					confirm_period: governance_period!(PROMOTE_CONFIRM_PERIOD),
+					min_enactment_period: governance_period!(PROMOTE_MIN_ENACTMENT_PERIOD),
 					min_approval: PROMOTE_MIN_APPROVAL,
 					min_support: PROMOTE_MIN_SUPPORT,
 				},
@@ -475,10 +476,10 @@ impl pallet_referenda::TracksInfo<Balance, BlockNumber> for TracksInfo {
 					name: "promote to IV Dan",
 					max_deciding: PROMOTE_MAX_DECIDING,
 					decision_deposit: PROMOTE_DECISION_DEPOSIT,
-					prepare_period: PROMOTE_PREPARE_PERIOD,
-					decision_period: PROMOTE_DECISION_PERIOD,
-					confirm_period: PROMOTE_CONFIRM_PERIOD,
-					min_enactment_period: PROMOTE_MIN_ENACTMENT_PERIOD,
+					prepare_period: governance_period!(PROMOTE_PREPARE_PERIOD),
+					decision_period: governance_period!(PROMOTE_DECISION_PERIOD),
+					confirm_period: governance_period!(PROMOTE_CONFIRM_PERIOD),
+					min_enactment_period: governance_period!(PROMOTE_MIN_ENACTMENT_PERIOD),
 					min_approval: PROMOTE_MIN_APPROVAL,
 					min_support: PROMOTE_MIN_SUPPORT,
 				},
@@ -489,10 +490,10 @@ impl pallet_referenda::TracksInfo<Balance, BlockNumber> for TracksInfo {
 					name: "promote to V Dan",
 					max_deciding: PROMOTE_MAX_DECIDING,
 					decision_deposit: PROMOTE_DECISION_DEPOSIT,
-					prepare_period: PROMOTE_PREPARE_PERIOD,
-					decision_period: PROMOTE_DECISION_PERIOD,
-					confirm_period: PROMOTE_CONFIRM_PERIOD,
-					min_enactment_period: PROMOTE_MIN_ENACTMENT_PERIOD,
+					prepare_period: governance_period!(PROMOTE_PREPARE_PERIOD),
+					decision_period: governance_period!(PROMOTE_DECISION_PERIOD),
+					confirm_period: governance_period!(PROMOTE_CONFIRM_PERIOD),
+					min_enactment_period: governance_period!(PROMOTE_MIN_ENACTMENT_PERIOD),
 					min_approval: PROMOTE_MIN_APPROVAL,
 					min_support: PROMOTE_MIN_SUPPORT,
 				},
@@ -503,10 +504,10 @@ impl pallet_referenda::TracksInfo<Balance, BlockNumber> for TracksInfo {
 					name: "promote to VI Dan",
 					max_deciding: PROMOTE_MAX_DECIDING,
 					decision_deposit: PROMOTE_DECISION_DEPOSIT,
-					prepare_period: PROMOTE_PREPARE_PERIOD,
-					decision_period: PROMOTE_DECISION_PERIOD,
-					confirm_period: PROMOTE_CONFIRM_PERIOD,
-					min_enactment_period: PROMOTE_MIN_ENACTMENT_PERIOD,
+					prepare_period: governance_period!(PROMOTE_PREPARE_PERIOD),
+					decision_period: governance_period!(PROMOTE_DECISION_PERIOD),
+					confirm_period: governance_period!(PROMOTE_CONFIRM_PERIOD),
+					min_enactment_period: governance_period!(PROMOTE_MIN_ENACTMENT_PERIOD),
 					min_approval: PROMOTE_MIN_APPROVAL,
 					min_support: PROMOTE_MIN_SUPPORT,
 				},
@@ -517,10 +518,10 @@ impl pallet_referenda::TracksInfo<Balance, BlockNumber> for TracksInfo {
 					name: "fast promote to I Dan",
 					max_deciding: FAST_PROMOTE_MAX_DECIDING,
 					decision_deposit: FAST_PROMOTE_DECISION_DEPOSIT,
-					prepare_period: FAST_PROMOTE_PREPARE_PERIOD,
-					decision_period: FAST_PROMOTE_DECISION_PERIOD,
-					confirm_period: FAST_PROMOTE_CONFIRM_PERIOD,
-					min_enactment_period: FAST_PROMOTE_MIN_ENACTMENT_PERIOD,
+					prepare_period: governance_period!(FAST_PROMOTE_PREPARE_PERIOD),
+					decision_period: governance_period!(FAST_PROMOTE_DECISION_PERIOD),
+					confirm_period: governance_period!(FAST_PROMOTE_CONFIRM_PERIOD),
+					min_enactment_period: governance_period!(FAST_PROMOTE_MIN_ENACTMENT_PERIOD),
 					min_approval: FAST_PROMOTE_MIN_APPROVAL,
 					min_support: FAST_PROMOTE_MIN_SUPPORT,
 				},
@@ -531,10 +532,10 @@ impl pallet_referenda::TracksInfo<Balance, BlockNumber> for TracksInfo {
 					name: "fast promote to II Dan",
 					max_deciding: FAST_PROMOTE_MAX_DECIDING,
 					decision_deposit: FAST_PROMOTE_DECISION_DEPOSIT,
-					prepare_period: FAST_PROMOTE_PREPARE_PERIOD,
-					decision_period: FAST_PROMOTE_DECISION_PERIOD,
-					confirm_period: FAST_PROMOTE_CONFIRM_PERIOD,
-					min_enactment_period: FAST_PROMOTE_MIN_ENACTMENT_PERIOD,
+					prepare_period: governance_period!(FAST_PROMOTE_PREPARE_PERIOD),
+					decision_period: governance_period!(FAST_PROMOTE_DECISION_PERIOD),
+					confirm_period: governance_period!(FAST_PROMOTE_CONFIRM_PERIOD),
+					min_enactment_period: governance_period!(FAST_PROMOTE_MIN_ENACTMENT_PERIOD),
 					min_approval: FAST_PROMOTE_MIN_APPROVAL,
 					min_support: FAST_PROMOTE_MIN_SUPPORT,
 				},
@@ -545,10 +546,10 @@ impl pallet_referenda::TracksInfo<Balance, BlockNumber> for TracksInfo {
 					name: "fast promote to III Dan",
 					max_deciding: FAST_PROMOTE_MAX_DECIDING,
 					decision_deposit: FAST_PROMOTE_DECISION_DEPOSIT,
-					prepare_period: FAST_PROMOTE_PREPARE_PERIOD,
-					decision_period: FAST_PROMOTE_DECISION_PERIOD,
-					confirm_period: FAST_PROMOTE_CONFIRM_PERIOD,
-					min_enactment_period: FAST_PROMOTE_MIN_ENACTMENT_PERIOD,
+					prepare_period: governance_period!(FAST_PROMOTE_PREPARE_PERIOD),
+					decision_period: governance_period!(FAST_PROMOTE_DECISION_PERIOD),
+					confirm_period: governance_period!(FAST_PROMOTE_CONFIRM_PERIOD),
+					min_enactment_period: governance_period!(FAST_PROMOTE_MIN_ENACTMENT_PERIOD),
 					min_approval: FAST_PROMOTE_MIN_APPROVAL,
 					min_support: FAST_PROMOTE_MIN_SUPPORT,
 				},
@@ -46,9 +46,10 @@ pub mod xcm_config;
 pub mod fellowship;
 pub use ambassador::pallet_ambassador_origins;
 
+use ambassador::AmbassadorReferendaInstance;
 use cumulus_pallet_parachain_system::RelayNumberMonotonicallyIncreases;
 use cumulus_primitives_core::{AggregateMessageOrigin, ParaId};
-use fellowship::{pallet_fellowship_origins, Fellows};
+use fellowship::{pallet_fellowship_origins, Fellows, FellowshipReferendaInstance};
 use impls::{AllianceProposalProvider, EqualOrGreatestRootCmp, ToParentTreasury};
 use polkadot_runtime_common::impls::{
 	ContainsParts as ContainsLocationParts, VersionedLocatableAsset,
@@ -77,7 +78,7 @@ use frame_support::{
 		fungible::HoldConsideration,
 		tokens::{imbalance::ResolveTo, UnityOrOuterConversion},
 		ConstBool, ConstU16, ConstU32, ConstU64, ConstU8, EitherOfDiverse, FromContains,
-		InstanceFilter, LinearStoragePrice, TransformOrigin,
+		InstanceFilter, LinearStoragePrice, PalletInfoAccess, TransformOrigin,
 	},
 	weights::{ConstantMultiplier, Weight, WeightToFee as _},
 	PalletId,
@@ -298,9 +299,11 @@ parameter_types! {
 	RuntimeDebug,
 	MaxEncodedLen,
 	scale_info::TypeInfo,
+	Default,
 )]
 pub enum ProxyType {
 	/// Fully permissioned proxy. Can execute any call on behalf of _proxied_.
+	#[default]
 	Any,
 	/// Can execute any call that does not transfer funds.
 	NonTransfer,
@@ -315,11 +318,6 @@ pub enum ProxyType {
 	/// Ambassador proxy. Allows calls related to the Ambassador Program.
 	Ambassador,
 }
-impl Default for ProxyType {
-	fn default() -> Self {
-		Self::Any
-	}
-}
 impl InstanceFilter<RuntimeCall> for ProxyType {
 	fn filter(&self, c: &RuntimeCall) -> bool {
 		match self {
@@ -773,15 +771,29 @@ pub type UncheckedExtrinsic =
 
 /// All migrations executed on runtime upgrade as a nested tuple of types implementing
 /// `OnRuntimeUpgrade`. Included migrations must be idempotent.
-type Migrations = (
-	// unreleased and/or un-applied
-	cumulus_pallet_xcmp_queue::migration::v5::MigrateV4ToV5<Runtime>,
-	pallet_core_fellowship::migration::MigrateV0ToV1<Runtime, fellowship::FellowshipCoreInstance>,
-	pallet_core_fellowship::migration::MigrateV0ToV1<Runtime, ambassador::AmbassadorCoreInstance>,
-	ambassador::migrations::TruncateHeadAmbassadors,
-	// permanent
-	pallet_xcm::migration::MigrateToLatestXcmVersion<Runtime>,
-);
+type Migrations = (migrations::Unreleased, migrations::Permanent);
+
+/// The runtime migrations per release.
+pub mod migrations {
+	use super::*;
+
+	/// Unreleased and/or un-applied migrations. Add new ones here:
+	pub type Unreleased = (
+		cumulus_pallet_xcmp_queue::migration::v5::MigrateV4ToV5<Runtime>,
+		pallet_core_fellowship::migration::MigrateV0ToV1<
+			Runtime,
+			fellowship::FellowshipCoreInstance,
+		>,
+		pallet_core_fellowship::migration::MigrateV0ToV1<
+			Runtime,
+			ambassador::AmbassadorCoreInstance,
+		>,
+		ambassador::migrations::TruncateHeadAmbassadors,
+	);
+
+	/// Migrations/checks that do not need to be versioned and can run on every update.
+	pub type Permanent = (pallet_xcm::migration::MigrateToLatestXcmVersion<Runtime>,);
+}
 
 /// Executive: handles dispatch to the various modules.
 pub type Executive = frame_executive::Executive<
@@ -973,6 +985,70 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl referenda_tracks_api::ReferendaTracksApi<Block, u16, Balance, BlockNumber> for Runtime {
+		fn tracks(
+			pallet_index: u8,
+		) -> Vec<(u16, referenda_tracks_api::TrackDetails<Balance, BlockNumber>)> {
+			match pallet_index {
+				i if i == FellowshipReferenda::index() as u8 =>
+					referenda_tracks_api::tracks::<Runtime, FellowshipReferendaInstance>(),
+				i if i == AmbassadorReferenda::index() as u8 =>
+					referenda_tracks_api::tracks::<Runtime, AmbassadorReferendaInstance>(),
+				_ => Vec::new(),
+			}
+		}
+
+		fn track_thresholds(
+			pallet_index: u8,
+			track: u16,
+			elapsed: BlockNumber,
+		) -> Option<referenda_tracks_api::Thresholds> {
+			match pallet_index {
+				i if i == FellowshipReferenda::index() as u8 =>
+					referenda_tracks_api::track_thresholds::<Runtime, FellowshipReferendaInstance>(
+						track,
+						elapsed,
+					),
+				i if i == AmbassadorReferenda::index() as u8 =>
+					referenda_tracks_api::track_thresholds::<Runtime, AmbassadorReferendaInstance>(
+						track,
+						elapsed,
+					),
+				_ => None,
+			}
+		}
+
+		fn referendum_thresholds(
+			pallet_index: u8,
+			index: pallet_referenda::ReferendumIndex,
+		) -> Option<referenda_tracks_api::ReferendumThresholds<u16, BlockNumber>> {
+			match pallet_index {
+				i if i == FellowshipReferenda::index() as u8 =>
+					referenda_tracks_api::referendum_thresholds::<Runtime, FellowshipReferendaInstance>(
+						index,
+					),
+				i if i == AmbassadorReferenda::index() as u8 =>
+					referenda_tracks_api::referendum_thresholds::<Runtime, AmbassadorReferendaInstance>(
+						index,
+					),
+				_ => None,
+			}
+		}
+	}
+
+	impl proxy_filter_api::ProxyFilterApi<Block, ProxyType> for Runtime {
+		fn proxy_types() -> Vec<proxy_filter_api::ProxyTypeInfo<ProxyType>> {
+			proxy_filter_api::proxy_types::<Runtime>()
+		}
+
+		fn is_call_allowed(
+			proxy_type: ProxyType,
+			call: Vec<u8>,
+		) -> Result<bool, proxy_filter_api::ProxyFilterError> {
+			proxy_filter_api::is_call_allowed::<Runtime>(&proxy_type, &call)
+		}
+	}
+
 	impl xcm_runtime_apis::fees::XcmPaymentApi<Block> for Runtime {
 		fn query_acceptable_payment_assets(xcm_version: xcm::Version) -> Result<Vec<VersionedAssetId>, XcmPaymentApiError> {
 			let acceptable_assets = vec![AssetId(xcm_config::DotLocation::get())];
@@ -1015,6 +1091,12 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl xcm_dry_run_api::DetailedDryRunApi<Block, RuntimeCall, RuntimeEvent, OriginCaller> for Runtime {
+		fn detailed_dry_run_call(origin: OriginCaller, call: RuntimeCall) -> Result<xcm_dry_run_api::DetailedCallDryRunEffects<RuntimeEvent>, XcmDryRunApiError> {
+			xcm_dry_run_api::detailed_dry_run_call::<Runtime, xcm_config::XcmRouter, OriginCaller, xcm_config::XcmConfig>(origin, call)
+		}
+	}
+
 	impl xcm_runtime_apis::conversions::LocationToAccountApi<Block, AccountId> for Runtime {
 		fn convert_location(location: VersionedLocation) -> Result<
 			AccountId,
@@ -0,0 +1,64 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot. If not, see <http://www.gnu.org/licenses/>.
+
+//! Runtime API tests.
+
+use collectives_polkadot_runtime::{
+	ambassador::AmbassadorReferendaInstance, fellowship::FellowshipReferendaInstance, ProxyType,
+	Runtime, RuntimeCall,
+};
+use parachains_common::AccountId;
+
+#[test]
+fn proxy_filter_api_works() {
+	let remark = RuntimeCall::System(frame_system::Call::remark { remark: vec![] });
+	let transfer = RuntimeCall::Balances(pallet_balances::Call::transfer_allow_death {
+		dest: AccountId::from([0u8; 32]).into(),
+		value: 1,
+	});
+	let reject_announcement = RuntimeCall::Proxy(pallet_proxy::Call::reject_announcement {
+		delegate: AccountId::from([0u8; 32]).into(),
+		call_hash: Default::default(),
+	});
+	let leave_intent =
+		RuntimeCall::CollatorSelection(pallet_collator_selection::Call::leave_intent {});
+	let retire = RuntimeCall::Alliance(pallet_alliance::Call::retire {});
+	let bump_fellowship_salary = RuntimeCall::FellowshipSalary(pallet_salary::Call::bump {});
+	let bump_ambassador_salary = RuntimeCall::AmbassadorSalary(pallet_salary::Call::bump {});
+	proxy_filter_api::assert_api_works::<Runtime>(&[
+		(ProxyType::Any, transfer.clone(), true),
+		(ProxyType::NonTransfer, remark.clone(), true),
+		(ProxyType::NonTransfer, retire.clone(), true),
+		(ProxyType::NonTransfer, transfer, false),
+		(ProxyType::CancelProxy, reject_announcement, true),
+		(ProxyType::CancelProxy, remark.clone(), false),
+		(ProxyType::Collator, leave_intent.clone(), true),
+		(ProxyType::Collator, remark, false),
+		(ProxyType::Alliance, retire.clone(), true),
+		(ProxyType::Alliance, leave_intent, false),
+		(ProxyType::Fellowship, bump_fellowship_salary.clone(), true),
+		(ProxyType::Fellowship, bump_ambassador_salary.clone(), false),
+		(ProxyType::Ambassador, bump_ambassador_salary, true),
+		(ProxyType::Ambassador, bump_fellowship_salary, false),
+		(ProxyType::Ambassador, retire, false),
+	]);
+}
+
+#[test]
+fn referenda_tracks_api_works() {
+	referenda_tracks_api::assert_api_works::<Runtime, FellowshipReferendaInstance>();
+	referenda_tracks_api::assert_api_works::<Runtime, AmbassadorReferendaInstance>();
+}
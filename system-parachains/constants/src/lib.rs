@@ -21,11 +21,13 @@ pub mod kusama;
 pub mod polkadot;
 
 use frame_support::{
+	traits::ContainsPair,
 	weights::{constants::WEIGHT_REF_TIME_PER_SECOND, Weight},
 	PalletId,
 };
 pub use parachains_common::BlockNumber;
 use sp_runtime::Perbill;
+use xcm::latest::Location;
 
 /// This determines the average expected block time that we are targeting.
 ///
@@ -57,3 +59,13 @@ pub const MAXIMUM_BLOCK_WEIGHT: Weight = Weight::from_parts(
 
 /// Treasury pallet id of the local chain, used to convert into AccountId
 pub const TREASURY_PALLET_ID: PalletId = PalletId(*b"py/trsry");
+
+/// Alias a location to any of its interior locations, e.g. a parachain to one of its accounts.
+///
+/// TODO: replace with `xcm_builder::AliasChildLocation` once we pull a version containing it.
+pub struct AliasChildLocation;
+impl ContainsPair<Location, Location> for AliasChildLocation {
+	fn contains(origin: &Location, target: &Location) -> bool {
+		target.starts_with(origin)
+	}
+}
@@ -369,4 +369,11 @@ impl<T: frame_system::Config> WeightInfo<T> {
 		Weight::from_parts(960_000, 0)
 			.saturating_add(Weight::from_parts(0, 0))
 	}
+	pub(crate) fn alias_origin() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 2_049_000 picoseconds.
+		Weight::from_parts(2_739_000, 0)
+	}
 }
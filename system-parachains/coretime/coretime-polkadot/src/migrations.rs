@@ -14,11 +14,11 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-//! Migration to fix the coretime migration lease offset-related issues
+//! The runtime migrations per release.
 //!
-//! This fixes a problem with the leases where the relay migration did not take the lease offset
-//! into consideration, so the end of leases is 64 days short, in some cases leading to them being
-//! dropped completely.
+//! [`FixMigration`] fixes a problem with the leases where the relay migration did not take the
+//! lease offset into consideration, so the end of leases is 64 days short, in some cases leading to
+//! them being dropped completely.
 
 extern crate alloc;
 
@@ -78,6 +78,12 @@ impl Weights {
 	}
 }
 
+/// Unreleased and/or un-applied migrations. Add new ones here:
+pub type Unreleased = ();
+
+/// Migrations/checks that do not need to be versioned and can run on every update.
+pub type Permanent = (pallet_xcm::migration::MigrateToLatestXcmVersion<Runtime>, FixMigration);
+
 pub struct FixMigration;
 
 impl FixMigration {
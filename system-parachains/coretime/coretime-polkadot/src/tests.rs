@@ -214,3 +214,44 @@ fn location_conversion_works() {
 		assert_eq!(got, expected, "{}", tc.description);
 	}
 }
+
+#[test]
+fn proxy_filter_api_works() {
+	let remark = RuntimeCall::System(frame_system::Call::remark { remark: vec![] });
+	let transfer = RuntimeCall::Balances(pallet_balances::Call::transfer_allow_death {
+		dest: AccountId::from([0u8; 32]).into(),
+		value: 1,
+	});
+	let reject_announcement = RuntimeCall::Proxy(pallet_proxy::Call::reject_announcement {
+		delegate: AccountId::from([0u8; 32]).into(),
+		call_hash: Default::default(),
+	});
+	let leave_intent =
+		RuntimeCall::CollatorSelection(pallet_collator_selection::Call::leave_intent {});
+	let renew = RuntimeCall::Broker(pallet_broker::Call::renew { core: 0 });
+	let purchase_credit = RuntimeCall::Broker(pallet_broker::Call::purchase_credit {
+		amount: 1,
+		beneficiary: AccountId::from([0u8; 32]),
+	});
+	let drop_history = RuntimeCall::Broker(pallet_broker::Call::drop_history { when: 0 });
+	proxy_filter_api::assert_api_works::<Runtime>(&[
+		(ProxyType::Any, transfer.clone(), true),
+		(ProxyType::NonTransfer, remark.clone(), true),
+		(ProxyType::NonTransfer, drop_history.clone(), true),
+		(ProxyType::NonTransfer, transfer, false),
+		(ProxyType::NonTransfer, renew.clone(), false),
+		(ProxyType::CancelProxy, reject_announcement, true),
+		(ProxyType::CancelProxy, remark.clone(), false),
+		(ProxyType::Broker, renew.clone(), true),
+		(ProxyType::Broker, purchase_credit.clone(), true),
+		(ProxyType::Broker, drop_history.clone(), true),
+		(ProxyType::CoretimeRenewer, renew.clone(), true),
+		(ProxyType::CoretimeRenewer, purchase_credit.clone(), false),
+		(ProxyType::OnDemandPurchaser, purchase_credit, true),
+		(ProxyType::OnDemandPurchaser, renew, false),
+		(ProxyType::Collator, leave_intent, true),
+		(ProxyType::Collator, drop_history, false),
+	]);
+}
+
+migration_test_utils::impl_migration_tests!(crate, "development");
@@ -121,3 +121,49 @@ fn location_conversion_works() {
 		assert_eq!(got, expected, "{}", tc.description);
 	}
 }
+
+#[test]
+fn proxy_filter_api_works() {
+	use crate::{ProxyType, Runtime, RuntimeCall};
+
+	let remark = RuntimeCall::System(frame_system::Call::remark { remark: vec![] });
+	let transfer = RuntimeCall::Balances(pallet_balances::Call::transfer_allow_death {
+		dest: AccountId::from([0u8; 32]).into(),
+		value: 1,
+	});
+	let reject_announcement = RuntimeCall::Proxy(pallet_proxy::Call::reject_announcement {
+		delegate: AccountId::from([0u8; 32]).into(),
+		call_hash: Default::default(),
+	});
+	let leave_intent =
+		RuntimeCall::CollatorSelection(pallet_collator_selection::Call::leave_intent {});
+	let clear_identity = RuntimeCall::Identity(pallet_identity::Call::clear_identity {});
+	let add_sub = RuntimeCall::Identity(pallet_identity::Call::add_sub {
+		sub: AccountId::from([1u8; 32]).into(),
+		data: Default::default(),
+	});
+	let provide_judgement = RuntimeCall::Identity(pallet_identity::Call::provide_judgement {
+		reg_index: 0,
+		target: AccountId::from([1u8; 32]).into(),
+		judgement: pallet_identity::Judgement::Reasonable,
+		identity: Default::default(),
+	});
+	proxy_filter_api::assert_api_works::<Runtime>(&[
+		(ProxyType::Any, transfer.clone(), true),
+		(ProxyType::NonTransfer, remark.clone(), true),
+		(ProxyType::NonTransfer, clear_identity.clone(), true),
+		(ProxyType::NonTransfer, transfer, false),
+		(ProxyType::NonTransfer, add_sub.clone(), false),
+		(ProxyType::CancelProxy, reject_announcement, true),
+		(ProxyType::CancelProxy, remark.clone(), false),
+		(ProxyType::Identity, clear_identity.clone(), true),
+		(ProxyType::Identity, add_sub, true),
+		(ProxyType::Identity, provide_judgement.clone(), true),
+		(ProxyType::IdentityJudgement, provide_judgement, true),
+		(ProxyType::IdentityJudgement, clear_identity, false),
+		(ProxyType::Collator, leave_intent, true),
+		(ProxyType::Collator, remark, false),
+	]);
+}
+
+migration_test_utils::impl_migration_tests!(crate, "development");
@@ -349,4 +349,11 @@ impl<T: frame_system::Config> WeightInfo<T> {
 		Weight::from_parts(980_000, 0)
 			.saturating_add(Weight::from_parts(0, 0))
 	}
+	pub(crate) fn alias_origin() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 1_513_000 picoseconds.
+		Weight::from_parts(1_824_000, 0)
+	}
 }
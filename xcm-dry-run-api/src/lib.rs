@@ -0,0 +1,119 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Runtime API to dry-run a call with the details of the XCM program it executes.
+//!
+//! Extends the `DryRunApi` of `xcm-runtime-apis` with the weight of every instruction of the local
+//! XCM program, so that wallets can show what an `execute`-style extrinsic does and costs before
+//! signing it. The runtime must record the local XCM, i.e. `XcmRecorder = PolkadotXcm`.
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+use codec::{Decode, Encode};
+use frame_support::{
+	dispatch::{DispatchResultWithPostInfo, PostDispatchInfo},
+	weights::Weight,
+};
+use scale_info::TypeInfo;
+use sp_runtime::{traits::Dispatchable, RuntimeDebug};
+use xcm::prelude::{VersionedLocation, VersionedXcm, Xcm};
+use xcm_builder::InspectMessageQueues;
+use xcm_executor::traits::WeightBounds;
+pub use xcm_runtime_apis::dry_run::Error as XcmDryRunApiError;
+
+/// Effects of dry-running a call, with the details of its local XCM program.
+#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct DetailedCallDryRunEffects<Event> {
+	/// The result of executing the call.
+	pub execution_result: DispatchResultWithPostInfo,
+	/// The events emitted while executing the call.
+	pub emitted_events: Vec<Event>,
+	/// The local XCM program executed by the call, if any.
+	pub local_xcm: Option<VersionedXcm<()>>,
+	/// The weight of every instruction of `local_xcm`, in order.
+	///
+	/// `None` for the instructions the runtime can not weigh, which fail the execution.
+	pub local_xcm_weights: Vec<Option<Weight>>,
+	/// The XCM programs sent by the call, by destination.
+	pub forwarded_xcms: Vec<(VersionedLocation, Vec<VersionedXcm<()>>)>,
+}
+
+sp_api::decl_runtime_apis! {
+	/// Dry-running of calls, with the details of the XCM programs they execute.
+	pub trait DetailedDryRunApi<Call, Event, OriginCaller>
+	where
+		Call: Encode,
+		Event: Decode,
+		OriginCaller: Encode,
+	{
+		/// Dry-run `call` dispatched by `origin`, like `DryRunApi::dry_run_call`.
+		fn detailed_dry_run_call(
+			origin: OriginCaller,
+			call: Call,
+		) -> Result<DetailedCallDryRunEffects<Event>, XcmDryRunApiError>;
+	}
+}
+
+/// Dry-run `call` dispatched by `origin` on `Runtime`, weighing its local XCM program with the
+/// weigher of `XcmConfig`.
+pub fn detailed_dry_run_call<Runtime, Router, OriginCaller, XcmConfig>(
+	origin: OriginCaller,
+	call: XcmConfig::RuntimeCall,
+) -> Result<
+	DetailedCallDryRunEffects<<Runtime as frame_system::Config>::RuntimeEvent>,
+	XcmDryRunApiError,
+>
+where
+	Runtime: pallet_xcm::Config,
+	Router: InspectMessageQueues,
+	XcmConfig: xcm_executor::Config,
+	XcmConfig::RuntimeCall: Dispatchable<PostInfo = PostDispatchInfo>,
+	<XcmConfig::RuntimeCall as Dispatchable>::RuntimeOrigin: From<OriginCaller>,
+{
+	let effects = pallet_xcm::Pallet::<Runtime>::dry_run_call::<
+		Runtime,
+		Router,
+		OriginCaller,
+		XcmConfig::RuntimeCall,
+	>(origin, call)?;
+	let local_xcm_weights = match &effects.local_xcm {
+		Some(local_xcm) => instruction_weights::<XcmConfig>(local_xcm)?,
+		None => Vec::new(),
+	};
+
+	Ok(DetailedCallDryRunEffects {
+		execution_result: effects.execution_result,
+		emitted_events: effects.emitted_events,
+		local_xcm: effects.local_xcm,
+		local_xcm_weights,
+		forwarded_xcms: effects.forwarded_xcms,
+	})
+}
+
+/// The weight of every instruction of `xcm`, with the weigher of `XcmConfig`.
+pub fn instruction_weights<XcmConfig: xcm_executor::Config>(
+	xcm: &VersionedXcm<()>,
+) -> Result<Vec<Option<Weight>>, XcmDryRunApiError> {
+	let xcm = Xcm::<()>::try_from(xcm.clone())
+		.map_err(|_| XcmDryRunApiError::VersionedConversionFailed)?;
+	Ok(Xcm::<XcmConfig::RuntimeCall>::from(xcm)
+		.0
+		.iter_mut()
+		.map(|instruction| XcmConfig::Weigher::instr_weight(instruction).ok())
+		.collect())
+}